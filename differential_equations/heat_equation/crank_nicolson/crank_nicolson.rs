@@ -0,0 +1,205 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The Crank-Nicolson scheme for the 1D heat equation u_t = alpha u_xx.  *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Solves a tridiagonal system with the Thomas algorithm, Gaussian           *
+ *  elimination without pivoting specialized to three diagonals. Row i reads  *
+ *  sub[i] x[i-1] + diag[i] x[i] + sup[i] x[i+1] = rhs[i], with sub[0] and    *
+ *  sup[n-1] unused. Without pivoting this is only safe for matrices like     *
+ *  diagonally dominant ones, where no pivot can vanish. Returns None if a    *
+ *  zero pivot is met.                                                        */
+fn thomas(sub: &[f64], diag: &[f64],
+          sup: &[f64], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n: usize = diag.len();
+    let mut c: Vec<f64> = vec![0.0; n];
+    let mut d: Vec<f64> = vec![0.0; n];
+
+    /*  Forward sweep, normalizing each row so its diagonal entry is 1.       */
+    for i in 0 .. n {
+        let below: f64 = if i == 0 { 0.0 } else { sub[i] };
+        let previous_c: f64 = if i == 0 { 0.0 } else { c[i - 1] };
+        let previous_d: f64 = if i == 0 { 0.0 } else { d[i - 1] };
+        let pivot: f64 = diag[i] - below * previous_c;
+
+        if pivot == 0.0 {
+            return None;
+        }
+
+        c[i] = if i + 1 < n { sup[i] / pivot } else { 0.0 };
+        d[i] = (rhs[i] - below * previous_d) / pivot;
+    }
+
+    /*  Back substitution.                                                    */
+    let mut x: Vec<f64> = d;
+
+    for i in (0 .. n.saturating_sub(1)).rev() {
+        x[i] = x[i] - c[i] * x[i + 1];
+    }
+
+    return Some(x);
+}
+/*  End of thomas.                                                            */
+
+/*  One step of the explicit FTCS scheme, for comparison. It is only stable   *
+ *  for r = alpha dt / dx^2 <= 1/2. With fewer than three points there are no *
+ *  interior values and u is returned unchanged.                              */
+fn heat_step(u: &[f64], alpha: f64, dx: f64, dt: f64) -> Vec<f64> {
+    let r: f64 = alpha * dt / (dx * dx);
+    let n: usize = u.len();
+    let mut next: Vec<f64> = u.to_vec();
+
+    for i in 1 .. n.saturating_sub(1) {
+        next[i] = u[i] + r * (u[i - 1] - 2.0 * u[i] + u[i + 1]);
+    }
+
+    return next;
+}
+/*  End of heat_step.                                                         */
+
+/*  Solves u_t = alpha u_xx with the Crank-Nicolson scheme, starting from u0  *
+ *  and taking the given number of steps. The first and last values are held  *
+ *  fixed (Dirichlet conditions). Crank-Nicolson averages the explicit and    *
+ *  implicit second differences, centering the scheme at the half step. With  *
+ *  r = alpha dt / dx^2 and D the second difference u_{i-1} - 2 u_i +         *
+ *  u_{i+1}:                                                                  *
+ *                                                                            *
+ *                r                  r                                        *
+ *        (I  -  - D) u_new  = (I  + - D) u_old                               *
+ *                2                  2                                        *
+ *                                                                            *
+ *  The left side couples neighbors at the new time, so each step solves a    *
+ *  tridiagonal system, here with the Thomas algorithm, still O(n) per step.  *
+ *  A Fourier mode is multiplied by (1 - 2 r s) / (1 + 2 r s), s = sin^2(k dx *
+ *  / 2), which has absolute value at most 1 for every r > 0. The scheme is   *
+ *  unconditionally stable, so dt is limited only by accuracy, and the        *
+ *  centered time difference makes the error O(dt^2 + dx^2). For very large r *
+ *  the highest modes are multiplied by nearly -1, so they are not damped as  *
+ *  the true solution would, but they do not grow. With fewer than three      *
+ *  points there are no interior values, and u0 is returned unchanged.        */
+fn crank_nicolson_heat(u0: &[f64], alpha: f64,
+                       dx: f64, dt: f64, steps: u32) -> Vec<f64> {
+    let r: f64 = alpha * dt / (dx * dx);
+    let n: usize = u0.len();
+
+    if n < 3 {
+        return u0.to_vec();
+    }
+
+    let interior: usize = n - 2;
+    let mut u: Vec<f64> = u0.to_vec();
+
+    /*  The matrix I - (r/2) D is the same every step.                        */
+    let sub: Vec<f64> = vec![-0.5 * r; interior];
+    let diag: Vec<f64> = vec![1.0 + r; interior];
+    let sup: Vec<f64> = vec![-0.5 * r; interior];
+
+    for _ in 0 .. steps {
+
+        /*  The right-hand side (I + (r/2) D) u_old. The boundary values are  *
+         *  the same at both times, and their contribution to the new side is *
+         *  moved over as well.                                               */
+        let mut rhs: Vec<f64> = vec![0.0; interior];
+
+        for i in 1 .. n - 1 {
+            let second: f64 = u[i - 1] - 2.0 * u[i] + u[i + 1];
+            rhs[i - 1] = u[i] + 0.5 * r * second;
+        }
+
+        rhs[0] = rhs[0] + 0.5 * r * u[0];
+        rhs[interior - 1] = rhs[interior - 1] + 0.5 * r * u[n - 1];
+
+        let inner: Vec<f64> = thomas(&sub, &diag, &sup, &rhs).unwrap();
+        u[1 .. n - 1].copy_from_slice(&inner);
+    }
+
+    return u;
+}
+/*  End of crank_nicolson_heat.                                               */
+
+/*  Computes the largest absolute value in a vector.                          */
+fn max_abs(u: &[f64]) -> f64 {
+    return u.iter().fold(0.0, |m: f64, x| m.max(x.abs()));
+}
+/*  End of max_abs.                                                           */
+
+/*  Main routine comparing Crank-Nicolson with the explicit scheme.           */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+    let alpha: f64 = 1.0;
+    let n: usize = 51;
+    let dx: f64 = 1.0 / (n - 1) as f64;
+    let u0: Vec<f64> = (0 .. n).map(|i| (pi * i as f64 * dx).sin()).collect();
+
+    /*  A large step, r = 5, ten times the explicit limit. Integrate to t =   *
+     *  0.1 in 50 steps.                                                      */
+    let dt: f64 = 5.0 * dx * dx / alpha;
+    let steps: u32 = 50;
+    let t: f64 = steps as f64 * dt;
+    let exact: f64 = (-alpha * pi * pi * t).exp();
+
+    let mut explicit: Vec<f64> = u0.clone();
+
+    for _ in 0 .. steps {
+        explicit = heat_step(&explicit, alpha, dx, dt);
+    }
+
+    let implicit: Vec<f64> = crank_nicolson_heat(&u0, alpha, dx, dt, steps);
+
+    println!("r = 5, t = {}: exact amplitude = {:.6}", t, exact);
+    println!("    explicit:       max |u| = {:.3E}", max_abs(&explicit));
+    println!("    Crank-Nicolson: amplitude = {:.6}", implicit[n / 2]);
+    println!("    stable and within 1%: {}",
+             (implicit[n / 2] - exact).abs() < 0.01 * exact);
+
+    /*  Order in time. The sine is an exact eigenvector of D, so if the time  *
+     *  derivative were solved exactly it would decay like exp(-lambda t),    *
+     *  with lambda = 4 alpha sin^2(pi dx / 2) / dx^2. Comparing with that    *
+     *  isolates the time error, which should drop by 4 each time dt is       *
+     *  halved.                                                               */
+    let s: f64 = (0.5 * pi * dx).sin();
+    let lambda: f64 = 4.0 * alpha * s * s / (dx * dx);
+    let semi_discrete: f64 = (-lambda * 0.1).exp();
+    let mut previous: f64 = 0.0;
+
+    for &steps in [5, 10, 20, 40, 80].iter() {
+        let dt: f64 = 0.1 / steps as f64;
+        let u: Vec<f64> = crank_nicolson_heat(&u0, alpha, dx, dt, steps);
+        let error: f64 = (u[n / 2] - semi_discrete).abs();
+
+        if previous > 0.0 {
+            println!("dt = {:.5}: time error = {:.3E}, order = {:.3}",
+                     dt, error, (previous / error).log2());
+        } else {
+            println!("dt = {:.5}: time error = {:.3E}", dt, error);
+        }
+
+        previous = error;
+    }
+
+    /*  Two boundary points and nothing in between, there is nothing to       *
+     *  solve.                                                                */
+    println!("Two points: {:?}",
+             crank_nicolson_heat(&[1.0, 2.0], alpha, dx, dt, steps));
+}
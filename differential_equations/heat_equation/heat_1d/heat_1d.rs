@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The explicit FTCS scheme for the 1D heat equation u_t = alpha u_xx.   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Performs one step of the forward time, centered space (FTCS) scheme for   *
+ *  u_t = alpha u_xx. The time derivative is a forward difference and u_xx    *
+ *  the centered second difference, so with r = alpha dt / dx^2:              *
+ *                                                                            *
+ *        u_i <- u_i + r (u_{i-1} - 2 u_i + u_{i+1})                          *
+ *                                                                            *
+ *  The first and last values are boundary values and are left unchanged      *
+ *  (Dirichlet conditions). The scheme is explicit, each new value is a       *
+ *  formula in old ones, so a step costs O(n). With fewer than three points  *
+ *  there are no interior values and u is returned unchanged.                 *
+ *                                                                            *
+ *  It is only stable for r <= 1/2, the CFL condition for this scheme. A      *
+ *  Fourier mode sin(k x) is multiplied by 1 - 4 r sin^2(k dx / 2) each step. *
+ *  For the highest mode on the grid this is about 1 - 4r, which is less than *
+ *  -1 once r > 1/2. That mode then flips sign and grows every step, and      *
+ *  since rounding errors contain every mode, any solution eventually blows   *
+ *  up. Halving dx therefore forces dt to shrink by 4.                        */
+fn heat_step(u: &[f64], alpha: f64, dx: f64, dt: f64) -> Vec<f64> {
+    let r: f64 = alpha * dt / (dx * dx);
+    let n: usize = u.len();
+    let mut next: Vec<f64> = u.to_vec();
+
+    for i in 1 .. n.saturating_sub(1) {
+        next[i] = u[i] + r * (u[i - 1] - 2.0 * u[i] + u[i + 1]);
+    }
+
+    return next;
+}
+/*  End of heat_step.                                                         */
+
+/*  Marches the initial values u0 forward by the given number of steps.       */
+fn heat_solve(u0: &[f64], alpha: f64,
+              dx: f64, dt: f64, steps: u32) -> Vec<f64> {
+    let mut u: Vec<f64> = u0.to_vec();
+
+    for _ in 0 .. steps {
+        u = heat_step(&u, alpha, dx, dt);
+    }
+
+    return u;
+}
+/*  End of heat_solve.                                                        */
+
+/*  Computes the largest absolute value in a vector.                          */
+fn max_abs(u: &[f64]) -> f64 {
+    return u.iter().fold(0.0, |m: f64, x| m.max(x.abs()));
+}
+/*  End of max_abs.                                                           */
+
+/*  Main routine used for testing the explicit scheme.                        */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+    let alpha: f64 = 1.0;
+    let n: usize = 51;
+    let dx: f64 = 1.0 / (n - 1) as f64;
+
+    /*  u(x, 0) = sin(pi x) on [0, 1] with u = 0 at both ends. The exact      *
+     *  solution is exp(-alpha pi^2 t) sin(pi x), a sine decaying at the rate *
+     *  alpha pi^2.                                                           */
+    let u0: Vec<f64> = (0 .. n).map(|i| (pi * i as f64 * dx).sin()).collect();
+
+    /*  A stable step, r = 0.4.                                               */
+    let dt: f64 = 0.4 * dx * dx / alpha;
+    let steps: u32 = 500;
+    let t: f64 = steps as f64 * dt;
+    let u: Vec<f64> = heat_solve(&u0, alpha, dx, dt, steps);
+
+    /*  The observed decay rate, from the amplitude at the midpoint.          */
+    let rate: f64 = -u[n / 2].ln() / t;
+    println!("r = 0.4: t = {:.4}, amplitude = {:.6}, exact = {:.6}",
+             t, u[n / 2], (-alpha * pi * pi * t).exp());
+    println!("r = 0.4: decay rate = {:.5}, exact pi^2 = {:.5}", rate, pi * pi);
+    println!("rate within 0.1%: {}", (rate - pi * pi).abs() < 1.0E-3 * pi * pi);
+
+    /*  r = 0.6 violates the CFL condition. The sine is smooth, but rounding  *
+     *  errors in the highest mode grow by about |1 - 4r| = 1.4 each step     *
+     *  until they swamp the solution.                                        */
+    let dt: f64 = 0.6 * dx * dx / alpha;
+
+    for &steps in [50, 100, 150, 200].iter() {
+        let u: Vec<f64> = heat_solve(&u0, alpha, dx, dt, steps);
+        println!("r = 0.6: steps = {}, max |u| = {:.3E}", steps, max_abs(&u));
+    }
+
+    let u: Vec<f64> = heat_solve(&u0, alpha, dx, dt, 200);
+    println!("unstable for r > 1/2: {}", max_abs(&u) > 1.0);
+
+    /*  Grids too small to have interior points are left as they are.         */
+    let empty: Vec<f64> = heat_step(&[], alpha, dx, dt);
+    let pair: Vec<f64> = heat_step(&[1.0, 2.0], alpha, dx, dt);
+    println!("n = 0: {:?}, n = 2: {:?}", empty, pair);
+}
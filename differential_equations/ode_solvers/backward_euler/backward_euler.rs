@@ -0,0 +1,124 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves stiff ordinary differential equations with the backward Euler  *
+ *      method.                                                               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Type for the right-hand side of y' = f(t, y), and for its partial         *
+ *  derivative with respect to y.                                             */
+type OdeFunc = fn(f64, f64) -> f64;
+
+/*  Solves y' = f(t, y), y(t0) = y0, using forward Euler, returning y(t1).    *
+ *  Included for comparison. Each step is y_{n+1} = y_n + h f(t_n, y_n).      */
+fn forward_euler(f: OdeFunc, t0: f64, y0: f64, t1: f64, steps: u32) -> f64 {
+    let h: f64 = (t1 - t0) / (steps as f64);
+    let mut y: f64 = y0;
+
+    for n in 0 .. steps {
+        let t: f64 = t0 + (n as f64) * h;
+        y = y + h * f(t, y);
+    }
+
+    return y;
+}
+/*  End of forward_euler.                                                     */
+
+/*  Solves y' = f(t, y), y(t0) = y0, using backward Euler, returning y(t1).   *
+ *  fy is the partial derivative of f with respect to y.                      *
+ *                                                                            *
+ *  Each step is y_{n+1} = y_n + h f(t_{n+1}, y_{n+1}). The unknown appears   *
+ *  on both sides, so every step solves the equation g(z) = z - y_n - h       *
+ *  f(t_{n+1}, z) = 0 with Newton's method, using g'(z) = 1 - h fy(t_{n+1},   *
+ *  z).                                                                       */
+fn backward_euler(f: OdeFunc,
+                  fy: OdeFunc,
+                  t0: f64,
+                  y0: f64,
+                  t1: f64,
+                  steps: u32) -> f64 {
+
+    /*  Newton's method starts very close to the answer (the previous value), *
+     *  so a handful of iterations is enough.                                 */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+
+    /*  The maximum allowed error. This is 4x double precision epsilon.       */
+    const EPSILON: f64 = 8.881784197001252E-16;
+
+    let h: f64 = (t1 - t0) / (steps as f64);
+    let mut y: f64 = y0;
+
+    for n in 0 .. steps {
+        let t_next: f64 = t0 + ((n + 1) as f64) * h;
+        let mut z: f64 = y;
+
+        for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+            let g: f64 = z - y - h * f(t_next, z);
+            let g_prime: f64 = 1.0 - h * fy(t_next, z);
+            let step: f64 = g / g_prime;
+            z = z - step;
+
+            if step.abs() <= EPSILON * z.abs().max(1.0) {
+                break;
+            }
+        }
+
+        y = z;
+    }
+
+    return y;
+}
+/*  End of backward_euler.                                                    */
+
+/*  The stiff equation y' = -50 y. The solution is y(t) = y(0) e^{-50 t}.     */
+fn f(_t: f64, y: f64) -> f64 {
+    return -50.0 * y;
+}
+
+/*  The partial derivative of f with respect to y.                            */
+fn fy(_t: f64, _y: f64) -> f64 {
+    return -50.0;
+}
+
+/*  Main routine used for comparing forward and backward Euler.               */
+fn main() {
+
+    /*  For y' = -50 y forward Euler multiplies y by 1 - 50h at each step,    *
+     *  and this blows up once |1 - 50h| > 1, that is, h > 0.04. Backward     *
+     *  Euler multiplies by 1 / (1 + 50h), which is less than 1 for every h > *
+     *  0, so it is stable no matter the step size. Both methods are first    *
+     *  order and converge as h goes to zero, but only backward Euler decays, *
+     *  like the true solution, for every h.                                  */
+    const T1: f64 = 0.2;
+    let exact: f64 = (-50.0 * T1).exp();
+
+    println!("Exact: y({}) = {:.6E}", T1, exact);
+
+    for &steps in [4, 8, 40, 400, 4000].iter() {
+        let forward: f64 = forward_euler(f, 0.0, 1.0, T1, steps);
+        let backward: f64 = backward_euler(f, fy, 0.0, 1.0, T1, steps);
+
+        println!("h = {:.5}: forward = {:+.6E}, backward = {:+.6E}",
+                 T1 / (steps as f64), forward, backward);
+    }
+}
@@ -0,0 +1,143 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves systems of ordinary differential equations with the classical  *
+ *      fourth order Runge-Kutta method.                                      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Type for the right-hand side of the system y' = f(t, y), where y is a     *
+ *  vector of any length.                                                     */
+type SystemFunc = fn(f64, &[f64]) -> Vec<f64>;
+
+/*  Computes y + c k component-wise, used to form the intermediate states.    */
+fn add_scaled(y: &[f64], c: f64, k: &[f64]) -> Vec<f64> {
+    return y.iter().zip(k.iter()).map(|(a, b)| a + c * b).collect();
+}
+
+/*  Solves y' = f(t, y), y(t0) = y0, using RK4 with a fixed number of steps,  *
+ *  returning y(t1). This is the scalar RK4 method with every number replaced *
+ *  by a vector:                                                              *
+ *                                                                            *
+ *        k1 = f(t, y)                                                        *
+ *        k2 = f(t + h/2, y + h/2 k1)                                         *
+ *        k3 = f(t + h/2, y + h/2 k2)                                         *
+ *        k4 = f(t + h, y + h k3)                                             *
+ *        y  = y + h/6 (k1 + 2 k2 + 2 k3 + k4)                                *
+ *                                                                            *
+ *  Any higher order equation can be written as a first order system by       *
+ *  introducing the derivatives as new unknowns.                              */
+fn rk4_system(f: SystemFunc,
+              t0: f64,
+              y0: &[f64],
+              t1: f64,
+              steps: u32) -> Vec<f64> {
+    let h: f64 = (t1 - t0) / (steps as f64);
+    let mut y: Vec<f64> = y0.to_vec();
+
+    for n in 0 .. steps {
+        let t: f64 = t0 + (n as f64) * h;
+
+        let k1: Vec<f64> = f(t, &y);
+        let k2: Vec<f64> = f(t + 0.5 * h, &add_scaled(&y, 0.5 * h, &k1));
+        let k3: Vec<f64> = f(t + 0.5 * h, &add_scaled(&y, 0.5 * h, &k2));
+        let k4: Vec<f64> = f(t + h, &add_scaled(&y, h, &k3));
+
+        for i in 0 .. y.len() {
+            y[i] = y[i] + h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+        }
+    }
+
+    return y;
+}
+/*  End of rk4_system.                                                        */
+
+/*  The harmonic oscillator x'' = -x as a system. With y = (x, v) and v = x', *
+ *  we have x' = v and v' = -x.                                               */
+fn harmonic_oscillator(_t: f64, y: &[f64]) -> Vec<f64> {
+    return vec![y[1], -y[0]];
+}
+
+/*  Parameters for the Lotka-Volterra equations.                              */
+const ALPHA: f64 = 1.1;
+const BETA: f64 = 0.4;
+const DELTA: f64 = 0.1;
+const GAMMA: f64 = 0.4;
+
+/*  The Lotka-Volterra predator-prey model, with x the prey and y the         *
+ *  predators:                                                                *
+ *                                                                            *
+ *        x' = alpha x - beta x y                                             *
+ *        y' = delta x y - gamma y                                            *
+ *                                                                            */
+fn lotka_volterra(_t: f64, y: &[f64]) -> Vec<f64> {
+    let prey: f64 = y[0];
+    let predators: f64 = y[1];
+
+    return vec![
+        ALPHA * prey - BETA * prey * predators,
+        DELTA * prey * predators - GAMMA * predators
+    ];
+}
+
+/*  The Lotka-Volterra equations have a conserved quantity, which is constant *
+ *  along every solution:                                                     *
+ *                                                                            *
+ *        V = delta x - gamma ln(x) + beta y - alpha ln(y)                    *
+ *                                                                            */
+fn lotka_volterra_invariant(y: &[f64]) -> f64 {
+    return DELTA * y[0] - GAMMA * y[0].ln() + BETA * y[1] - ALPHA * y[1].ln();
+}
+
+/*  Main routine used for testing the system solver.                          */
+fn main() {
+
+    /*  Starting at x = 1, v = 0 the solution is x = cos(t), v = -sin(t).     *
+     *  After one full period, t = 2 pi, we should be back at (1, 0).         */
+    let two_pi: f64 = 2.0 * std::f64::consts::PI;
+
+    for &steps in [10, 100, 1000].iter() {
+        let y: Vec<f64> =
+            rk4_system(harmonic_oscillator, 0.0, &[1.0, 0.0], two_pi, steps);
+        let error: f64 = (y[0] - 1.0).abs().max(y[1].abs());
+        println!("Oscillator, {:>4} steps: ({:+.12}, {:+.12}), error = {:.3E}",
+                 steps, y[0], y[1], error);
+    }
+
+    /*  Lotka-Volterra has no closed form solution. Compare with a reference  *
+     *  computed with many more steps, and check that the invariant is        *
+     *  preserved.                                                            */
+    let start: [f64; 2] = [10.0, 5.0];
+    let reference: Vec<f64> =
+        rk4_system(lotka_volterra, 0.0, &start, 20.0, 200000);
+
+    let y: Vec<f64> = rk4_system(lotka_volterra, 0.0, &start, 20.0, 2000);
+    let prey_error: f64 = (y[0] - reference[0]).abs();
+    let predator_error: f64 = (y[1] - reference[1]).abs();
+    let error: f64 = prey_error.max(predator_error);
+
+    println!("Lotka-Volterra at t = 20: prey = {:.10}, predators = {:.10}",
+             y[0], y[1]);
+    println!("Difference from reference: {:.3E}", error);
+    println!("Invariant at start: {:.12}", lotka_volterra_invariant(&start));
+    println!("Invariant at end:   {:.12}", lotka_volterra_invariant(&y));
+}
@@ -0,0 +1,196 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves ordinary differential equations with the adaptive Runge-Kutta- *
+ *      Fehlberg (RKF45) method.                                              *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Type for the right-hand side of y' = f(t, y).                             */
+type OdeFunc = fn(f64, f64) -> f64;
+
+/*  Performs one Fehlberg step of size h. Returns the fifth order estimate of *
+ *  y(t + h) and the difference between the fourth and fifth order estimates, *
+ *  which estimates the local error.                                          */
+fn fehlberg_step(f: OdeFunc, t: f64, y: f64, h: f64) -> (f64, f64) {
+
+    /*  Six evaluations of f, shared by both estimates. This sharing is what  *
+     *  makes an embedded pair cheap, the error estimate costs nothing extra. */
+    let k1: f64 = h * f(t, y);
+    let k2: f64 = h * f(t + h / 4.0, y + k1 / 4.0);
+    let k3: f64 = h * f(t + 3.0 * h / 8.0, y + (3.0 * k1 + 9.0 * k2) / 32.0);
+    let k4: f64 = h * f(t + 12.0 * h / 13.0,
+                        y + (1932.0 * k1 - 7200.0 * k2 + 7296.0 * k3) / 2197.0);
+    let k5: f64 = h * f(t + h,
+                        y + 439.0 / 216.0 * k1 - 8.0 * k2
+                          + 3680.0 / 513.0 * k3 - 845.0 / 4104.0 * k4);
+    let k6: f64 = h * f(t + h / 2.0,
+                        y - 8.0 / 27.0 * k1 + 2.0 * k2
+                          - 3544.0 / 2565.0 * k3 + 1859.0 / 4104.0 * k4
+                          - 11.0 / 40.0 * k5);
+
+    /*  The fourth and fifth order combinations of the same evaluations.      */
+    let y4: f64 = y + 25.0 / 216.0 * k1 + 1408.0 / 2565.0 * k3
+                    + 2197.0 / 4104.0 * k4 - k5 / 5.0;
+
+    let y5: f64 = y + 16.0 / 135.0 * k1 + 6656.0 / 12825.0 * k3
+                    + 28561.0 / 56430.0 * k4 - 9.0 / 50.0 * k5
+                    + 2.0 / 55.0 * k6;
+
+    return (y5, (y5 - y4).abs());
+}
+/*  End of fehlberg_step.                                                     */
+
+/*  Solves y' = f(t, y), y(t0) = y0, returning y(t1) and the list of accepted *
+ *  step sizes. Each step is accepted if its local error estimate is below    *
+ *  tol, and the next step size is chosen so the error estimate lands near    *
+ *  tol: the error of the fourth order method scales like h^5, so the ideal   *
+ *  step is h (tol / error)^(1/5), with a safety factor of 0.9.               *
+ *                                                                            *
+ *  Only forward integration is supported. If t1 <= t0 no steps are taken and *
+ *  y0 is returned with an empty list. If the error estimate is ever NaN or   *
+ *  infinite, for example because f returned NaN or the solution overflowed,  *
+ *  NaN is returned along with the steps accepted so far.                     */
+fn rkf45_with_steps(f: OdeFunc,
+                    t0: f64,
+                    y0: f64,
+                    t1: f64,
+                    tol: f64) -> (f64, Vec<f64>) {
+
+    /*  Nothing to do for an empty or backwards interval. Without this check  *
+     *  the step size guards below would be negative.                         */
+    if t1 <= t0 {
+        return (y0, Vec::new());
+    }
+
+    /*  Guards on the step size. The step never grows past a tenth of the     *
+     *  interval, and below the minimum step it is accepted no matter what,   *
+     *  preventing an endless loop of rejections.                             */
+    let max_step: f64 = 0.1 * (t1 - t0);
+    let min_step: f64 = 1.0E-12 * (t1 - t0);
+
+    let mut t: f64 = t0;
+    let mut y: f64 = y0;
+    let mut h: f64 = 0.01 * (t1 - t0);
+    let mut steps: Vec<f64> = Vec::new();
+
+    while t < t1 {
+
+        /*  Do not step past the end of the interval.                         */
+        if t + h > t1 {
+            h = t1 - t;
+        }
+
+        let (next, error): (f64, f64) = fehlberg_step(f, t, y, h);
+
+        /*  A NaN error fails both error <= tol and error > 0, so the step    *
+         *  would be rejected while h grows to max_step, looping forever.     */
+        if !error.is_finite() {
+            return (f64::NAN, steps);
+        }
+
+        /*  Accept the step. We carry the fifth order value forward, which is *
+         *  more accurate than the fourth order value the error estimate is   *
+         *  for.                                                              */
+        if error <= tol || h <= min_step {
+            t = t + h;
+            y = next;
+            steps.push(h);
+        }
+
+        /*  Pick the next step size, limiting how quickly it may change.      */
+        let mut factor: f64 = 4.0;
+
+        if error > 0.0 {
+            factor = (0.9 * (tol / error).powf(0.2)).clamp(0.1, 4.0);
+        }
+
+        h = (h * factor).max(min_step).min(max_step);
+    }
+
+    return (y, steps);
+}
+/*  End of rkf45_with_steps.                                                  */
+
+/*  Solves y' = f(t, y), y(t0) = y0, returning y(t1).                         */
+fn rkf45(f: OdeFunc, t0: f64, y0: f64, t1: f64, tol: f64) -> f64 {
+    let (y, _): (f64, Vec<f64>) = rkf45_with_steps(f, t0, y0, t1, tol);
+    return y;
+}
+/*  End of rkf45.                                                             */
+
+/*  y' = y with y(0) = 1 has solution e^t.                                    */
+fn exponential(_t: f64, y: f64) -> f64 {
+    return y;
+}
+
+/*  y' = 1 / (1 + 100 (t - 1)^2), with solution y = arctan(10 (t - 1)) / 10 + *
+ *  C. The right-hand side has a sharp peak at t = 1 and is nearly flat away  *
+ *  from it.                                                                  */
+fn peaked(t: f64, _y: f64) -> f64 {
+    return 1.0 / (1.0 + 100.0 * (t - 1.0) * (t - 1.0));
+}
+
+/*  Main routine used for testing RKF45.                                      */
+fn main() {
+
+    /*  Integrate y' = y from 0 to 1. The result should be e to within        *
+     *  roughly the tolerance.                                                */
+    for &tol in [1.0E-6, 1.0E-9, 1.0E-12].iter() {
+        let (y, steps): (f64, Vec<f64>) =
+            rkf45_with_steps(exponential, 0.0, 1.0, 1.0, tol);
+
+        let error: f64 = (y - std::f64::consts::E).abs();
+
+        println!("tol = {:.0E}: y(1) = {:.16}, error = {:.3E}, steps = {}",
+                 tol, y, error, steps.len());
+    }
+
+    let y: f64 = rkf45(exponential, 0.0, 1.0, 1.0, 1.0E-9);
+    println!("rkf45(y' = y, tol = 1e-9) = {}", y);
+
+    /*  Where the solution changes rapidly the steps are small, and where it  *
+     *  is smooth they are large. Print the step sizes used near t = 0, near  *
+     *  the peak at t = 1, and near t = 2.                                    */
+    let (y, steps): (f64, Vec<f64>) =
+        rkf45_with_steps(peaked, 0.0, 0.0, 2.0, 1.0E-10);
+
+    let exact: f64 = 0.2 * 10.0f64.atan();
+    let mut t: f64 = 0.0;
+
+    println!("Peaked problem: {} steps, error = {:.3E}",
+             steps.len(), (y - exact).abs());
+
+    for h in steps.iter() {
+        if t < 0.001 || (t - 1.0).abs() < 0.02 || t + h > 1.999 {
+            println!("    t = {:.6}, h = {:.3E}", t, h);
+        }
+
+        t = t + h;
+    }
+
+    /*  A right-hand side that returns NaN gives NaN instead of hanging, and  *
+     *  a backwards interval returns y0 without taking any steps.             */
+    let y: f64 = rkf45(|_t, _y| f64::NAN, 0.0, 1.0, 1.0, 1.0E-9);
+    println!("f = NaN: y = {}", y);
+    println!("t1 < t0: y = {}", rkf45(exponential, 1.0, 3.0, 0.0, 1.0E-9));
+}
@@ -0,0 +1,155 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Finite differences for the 1D Poisson equation -u'' = f.              *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Solves a tridiagonal system with the Thomas algorithm, Gaussian           *
+ *  elimination without pivoting specialized to three diagonals. Row i reads  *
+ *  sub[i] x[i-1] + diag[i] x[i] + sup[i] x[i+1] = rhs[i], with sub[0] and    *
+ *  sup[n-1] unused. Without pivoting this is only safe for matrices like     *
+ *  diagonally dominant ones, where no pivot can vanish. Returns None if a    *
+ *  zero pivot is met.                                                        */
+fn thomas(sub: &[f64], diag: &[f64],
+          sup: &[f64], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n: usize = diag.len();
+    let mut c: Vec<f64> = vec![0.0; n];
+    let mut d: Vec<f64> = vec![0.0; n];
+
+    /*  Forward sweep, normalizing each row so its diagonal entry is 1.       */
+    for i in 0 .. n {
+        let below: f64 = if i == 0 { 0.0 } else { sub[i] };
+        let previous_c: f64 = if i == 0 { 0.0 } else { c[i - 1] };
+        let previous_d: f64 = if i == 0 { 0.0 } else { d[i - 1] };
+        let pivot: f64 = diag[i] - below * previous_c;
+
+        if pivot == 0.0 {
+            return None;
+        }
+
+        c[i] = if i + 1 < n { sup[i] / pivot } else { 0.0 };
+        d[i] = (rhs[i] - below * previous_d) / pivot;
+    }
+
+    /*  Back substitution.                                                    */
+    let mut x: Vec<f64> = d;
+
+    for i in (0 .. n.saturating_sub(1)).rev() {
+        x[i] = x[i] - c[i] * x[i + 1];
+    }
+
+    return Some(x);
+}
+/*  End of thomas.                                                            */
+
+/*  Solves -u'' = f on [a, b] with u(a) = ua and u(b) = ub. The interval is   *
+ *  split into n equal pieces of width h, with nodes x_i = a + i h, and u''   *
+ *  at each interior node is replaced by the centered second difference:      *
+ *                                                                            *
+ *                    u_{i-1} - 2 u_i + u_{i+1}                               *
+ *        u''(x_i) ~ --------------------------,   error O(h^2)               *
+ *                                2                                           *
+ *                               h                                            *
+ *                                                                            *
+ *  This gives n - 1 equations -u_{i-1} + 2 u_i - u_{i+1} = h^2 f(x_i). The   *
+ *  known boundary values u_0 = ua and u_n = ub are moved to the right-hand   *
+ *  side of the first and last equations. The matrix is tridiagonal and       *
+ *  diagonally dominant, so the Thomas algorithm solves it in O(n) without    *
+ *  pivoting. Returns the n + 1 values u_0, ..., u_n, including the boundary  *
+ *  values. The error is O(h^2), so doubling n should divide it by 4. With    *
+ *  fewer than two intervals there are no interior nodes, and the boundary    *
+ *  values [ua, ub] are returned as is.                                       */
+fn solve_poisson(f: RealFunc, a: f64, b: f64,
+                 n: usize, ua: f64, ub: f64) -> Vec<f64> {
+    if n < 2 {
+        return vec![ua, ub];
+    }
+
+    let h: f64 = (b - a) / n as f64;
+    let interior: usize = n - 1;
+
+    let sub: Vec<f64> = vec![-1.0; interior];
+    let diag: Vec<f64> = vec![2.0; interior];
+    let sup: Vec<f64> = vec![-1.0; interior];
+    let mut rhs: Vec<f64> =
+        (1 .. n).map(|i| h * h * f(a + i as f64 * h)).collect();
+
+    rhs[0] = rhs[0] + ua;
+    rhs[interior - 1] = rhs[interior - 1] + ub;
+
+    let inner: Vec<f64> = thomas(&sub, &diag, &sup, &rhs).unwrap();
+    let mut u: Vec<f64> = Vec::with_capacity(n + 1);
+    u.push(ua);
+    u.extend(inner);
+    u.push(ub);
+    return u;
+}
+/*  End of solve_poisson.                                                     */
+
+/*  A manufactured solution. Pick u(x) = exp(x) sin(3x) and compute f = -u''  *
+ *  by hand. The exact solution is then known, and the error can be measured. */
+fn manufactured(x: f64) -> f64 {
+    return x.exp() * (3.0 * x).sin();
+}
+
+/*  -u'' for u(x) = exp(x) sin(3x), which is exp(x) (8 sin(3x) - 6 cos(3x)).  */
+fn forcing(x: f64) -> f64 {
+    return x.exp() * (8.0 * (3.0 * x).sin() - 6.0 * (3.0 * x).cos());
+}
+
+/*  Main routine used for testing the convergence rate.                       */
+fn main() {
+    let (a, b): (f64, f64) = (0.0, 2.0);
+    let ua: f64 = manufactured(a);
+    let ub: f64 = manufactured(b);
+    let mut previous: f64 = 0.0;
+
+    for &n in [10, 20, 40, 80, 160, 320].iter() {
+        let u: Vec<f64> = solve_poisson(forcing, a, b, n, ua, ub);
+        let h: f64 = (b - a) / n as f64;
+        let mut error: f64 = 0.0;
+
+        for i in 0 .. n + 1 {
+            let exact: f64 = manufactured(a + i as f64 * h);
+            error = error.max((u[i] - exact).abs());
+        }
+
+        /*  The observed order is log2 of the ratio of successive errors.     */
+        if previous > 0.0 {
+            println!("n = {:3}: max error = {:.3E}, order = {:.3}",
+                     n, error, (previous / error).log2());
+        } else {
+            println!("n = {:3}: max error = {:.3E}", n, error);
+        }
+
+        previous = error;
+    }
+
+    /*  With fewer than two intervals there are no unknowns.                  */
+    println!("n = 0: {:?}", solve_poisson(forcing, a, b, 0, ua, ub));
+    println!("n = 1: {:?}", solve_poisson(forcing, a, b, 1, ua, ub));
+}
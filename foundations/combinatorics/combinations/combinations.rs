@@ -0,0 +1,106 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Generates every k element combination of a slice in lexicographic     *
+ *      order.                                                                *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the binomial coefficient n choose k with the multiplicative      *
+ *  formula. After step i the result is n choose i, an integer, so every      *
+ *  division is exact. Since n choose k = n choose (n - k), the shorter of    *
+ *  the two products is used.                                                 */
+fn binomial(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+
+    let mut result: u64 = 1;
+
+    for i in 1 .. k.min(n - k) + 1 {
+        result = result * (n - i + 1) / i;
+    }
+
+    return result;
+}
+/*  End of binomial.                                                          */
+
+/*  Returns all k element combinations of the items, in lexicographic order   *
+ *  of their indices. The current combination is held as increasing indices   *
+ *  i_0 < i_1 < ... < i_k-1, starting from 0, 1, ..., k - 1. To advance, find *
+ *  the rightmost index that can still move, i_j < n - k + j, increment it,   *
+ *  and reset every index to its right to follow it consecutively. When no    *
+ *  index can move the last combination, n - k, ..., n - 1, has been          *
+ *  produced. There is exactly one combination of zero items, the empty one,  *
+ *  and none at all if k > n.                                                 */
+fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    let n: usize = items.len();
+    let mut result: Vec<Vec<T>> = Vec::new();
+
+    if k > n {
+        return result;
+    }
+
+    let mut indices: Vec<usize> = (0 .. k).collect();
+
+    loop {
+        result.push(indices.iter().map(|&i| items[i].clone()).collect());
+
+        /*  Find the rightmost index that has not reached its maximum.        */
+        let mut j: usize = k;
+
+        while j > 0 && indices[j - 1] == n - k + j - 1 {
+            j = j - 1;
+        }
+
+        if j == 0 {
+            return result;
+        }
+
+        indices[j - 1] = indices[j - 1] + 1;
+
+        for m in j .. k {
+            indices[m] = indices[m - 1] + 1;
+        }
+    }
+}
+/*  End of combinations.                                                      */
+
+/*  Main routine used for testing the combination generator.                  */
+fn main() {
+    println!("[1, 2, 3, 4] choose 2: {:?}", combinations(&[1, 2, 3, 4], 2));
+    println!("k = 0: {:?}", combinations(&[1, 2, 3], 0));
+    println!("k > n: {:?}", combinations(&[1, 2, 3], 4));
+
+    let mut counts_match: bool = true;
+
+    for n in 0 .. 10 {
+        let items: Vec<u64> = (0 .. n).collect();
+
+        for k in 0 .. n + 2 {
+            let count: usize = combinations(&items, k as usize).len();
+            counts_match &= count as u64 == binomial(n, k);
+        }
+    }
+
+    println!("counts equal binomial(n, k) for n < 10: {}", counts_match);
+}
@@ -0,0 +1,67 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The reflected binary Gray code, where neighbors differ in a single    *
+ *      bit.                                                                  *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Returns the 2^bits codes of the reflected binary Gray code. The k-th code *
+ *  is k ^ (k >> 1). Going from k to k + 1 flips a run of trailing ones and   *
+ *  the zero above them, bits 0 to m say. Xoring each bit with the one above  *
+ *  it cancels all of those flips except one, at position m, so consecutive   *
+ *  codes differ in exactly one bit. The last code is 2^(bits - 1), a single  *
+ *  one bit, which also differs from the first code, zero, in exactly one     *
+ *  bit, so the sequence is cyclic. Since u32 is used, bits must be at most   *
+ *  31.                                                                       */
+fn gray_code(bits: u32) -> Vec<u32> {
+    assert!(bits < 32, "gray_code: bits must be at most 31");
+    return (0 .. 1u32 << bits).map(|k| k ^ (k >> 1)).collect();
+}
+/*  End of gray_code.                                                         */
+
+/*  Main routine used for testing the Gray code.                              */
+fn main() {
+    for code in gray_code(3).iter() {
+        print!("{:03b} ", code);
+    }
+
+    println!();
+
+    for bits in 1 .. 13 {
+        let codes: Vec<u32> = gray_code(bits);
+        let n: usize = codes.len();
+        let mut one_bit: bool = true;
+
+        /*  Compare every code with the next, wrapping around at the end.     */
+        for k in 0 .. n {
+            one_bit &= (codes[k] ^ codes[(k + 1) % n]).count_ones() == 1;
+        }
+
+        let mut sorted: Vec<u32> = codes.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        println!("bits = {:>2}: {} distinct of {}, one bit changes: {}",
+                 bits, sorted.len(), 1u32 << bits, one_bit);
+    }
+}
@@ -0,0 +1,148 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Steps through permutations in lexicographic order, one at a time.     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes n! = 1 * 2 * ... * n. This overflows a u64 for n > 20.           */
+fn factorial(n: u64) -> u64 {
+    return (1 .. n + 1).product();
+}
+
+/*  Returns all permutations of the items using Heap's algorithm. Each        *
+ *  permutation is obtained from the previous one by a single swap, so        *
+ *  producing each costs O(1) beyond the copy. The iterative form keeps a     *
+ *  counter c[i] for every position, playing the role of the loop index in    *
+ *  the recursive version: swap positions i and either 0 (i even) or c[i] (i  *
+ *  odd), then go back to the lowest level.                                   *
+ *                                                                            *
+ *  There are n! permutations, and this function stores all of them: 10 items *
+ *  already give 3628800 vectors, and 13 items more than six billion. Only    *
+ *  use this for small inputs.                                                */
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let n: usize = items.len();
+    let mut current: Vec<T> = items.to_vec();
+    let mut c: Vec<usize> = vec![0; n];
+    let mut result: Vec<Vec<T>> = vec![current.clone()];
+    let mut i: usize = 1;
+
+    while i < n {
+        if c[i] < i {
+            if i % 2 == 0 {
+                current.swap(0, i);
+            } else {
+                current.swap(c[i], i);
+            }
+
+            result.push(current.clone());
+            c[i] = c[i] + 1;
+            i = 1;
+        } else {
+            c[i] = 0;
+            i = i + 1;
+        }
+    }
+
+    return result;
+}
+/*  End of permutations.                                                      */
+
+/*  Rearranges the items into the next permutation in lexicographic order, in *
+ *  place, returning false if they were already in the last one. There are    *
+ *  three steps.                                                              *
+ *                                                                            *
+ *  Find the pivot, the rightmost i with items[i] < items[i + 1]. Everything  *
+ *  after it is in descending order, the last arrangement of that suffix. If  *
+ *  there is no pivot the whole slice is descending, the last permutation     *
+ *  overall.                                                                  *
+ *                                                                            *
+ *  Find the successor, the rightmost j with items[i] < items[j]. This is the *
+ *  smallest suffix entry larger than the pivot. Swap the two, the suffix is  *
+ *  still descending.                                                         *
+ *                                                                            *
+ *  Reverse the suffix, making it ascending, its first arrangement.           *
+ *                                                                            *
+ *  As in C++'s std::next_permutation, when false is returned the items are   *
+ *  put back in ascending order, the first permutation, so the cycle can      *
+ *  start again. Repeated values are handled correctly, each distinct         *
+ *  arrangement is visited once.                                              */
+fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let n: usize = items.len();
+
+    if n < 2 {
+        return false;
+    }
+
+    let mut i: usize = n - 1;
+
+    while i > 0 && items[i - 1] >= items[i] {
+        i = i - 1;
+    }
+
+    if i == 0 {
+        items.reverse();
+        return false;
+    }
+
+    let pivot: usize = i - 1;
+    let mut j: usize = n - 1;
+
+    while items[j] <= items[pivot] {
+        j = j - 1;
+    }
+
+    items.swap(pivot, j);
+    items[i ..].reverse();
+    return true;
+}
+/*  End of next_permutation.                                                  */
+
+/*  Main routine used for testing next_permutation.                           */
+fn main() {
+    let mut items: Vec<u32> = vec![1, 2, 3];
+    print!("{:?}", items);
+
+    while next_permutation(&mut items) {
+        print!(" {:?}", items);
+    }
+
+    println!();
+
+    /*  Starting from sorted order, the calls must visit n! permutations, the *
+     *  same set that Heap's algorithm produces, and then return false.       */
+    for n in 1 .. 8 {
+        let mut current: Vec<u64> = (0 .. n).collect();
+        let mut visited: Vec<Vec<u64>> = vec![current.clone()];
+
+        while next_permutation(&mut current) {
+            visited.push(current.clone());
+        }
+
+        let mut expected: Vec<Vec<u64>> = permutations(&current);
+        expected.sort();
+
+        /*  Lexicographic order means the visited list is already sorted.     */
+        println!("n = {}: {} visited, n! = {}, same set: {}", n,
+                 visited.len(), factorial(n), visited == expected);
+    }
+}
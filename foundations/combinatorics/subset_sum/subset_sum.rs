@@ -0,0 +1,94 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves the subset sum problem with dynamic programming.               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Finds indices of items summing to target, or None if no subset does. Let  *
+ *  reachable[i][s] be true if some subset of the first i items sums to s.    *
+ *  Then reachable[0][s] is true only for s = 0, and:                         *
+ *                                                                            *
+ *        reachable[i + 1][s] = reachable[i][s] or reachable[i][s - items[i]] *
+ *                                                                            *
+ *  with the second option only when items[i] <= s. To recover a subset, walk *
+ *  back from reachable[n][target]. If reachable[i][s] is already true, item  *
+ *  i is not needed. Otherwise it must have been used, so record i and        *
+ *  continue from s - items[i].                                               *
+ *                                                                            *
+ *  The table has (n + 1)(target + 1) entries, so time and memory are O(n     *
+ *  target). This is pseudo-polynomial: polynomial in the value of target,    *
+ *  but exponential in the number of bits needed to write it down. Subset sum *
+ *  is NP-complete, and this is only practical when target is modest.         */
+fn subset_sum(items: &[u64], target: u64) -> Option<Vec<usize>> {
+    let n: usize = items.len();
+    let width: usize = (target as usize) + 1;
+    let mut reachable: Vec<Vec<bool>> = vec![vec![false; width]; n + 1];
+    reachable[0][0] = true;
+
+    for i in 0 .. n {
+        let item: usize = items[i] as usize;
+
+        for s in 0 .. width {
+            reachable[i + 1][s] = reachable[i][s]
+                || (item <= s && reachable[i][s - item]);
+        }
+    }
+
+    if !reachable[n][width - 1] {
+        return None;
+    }
+
+    let mut subset: Vec<usize> = Vec::new();
+    let mut s: usize = width - 1;
+
+    for i in (0 .. n).rev() {
+        if !reachable[i][s] {
+            subset.push(i);
+            s = s - items[i] as usize;
+        }
+    }
+
+    subset.reverse();
+    return Some(subset);
+}
+/*  End of subset_sum.                                                        */
+
+/*  Main routine used for testing the subset sum solver.                      */
+fn main() {
+    let items: [u64; 7] = [34, 4, 12, 5, 2, 27, 19];
+
+    match subset_sum(&items, 50) {
+        Some(indices) => {
+            let values: Vec<u64> = indices.iter().map(|&i| items[i]).collect();
+            let total: u64 = values.iter().sum();
+            println!("target 50: indices {:?}, values {:?}, sum = {}",
+                     indices, values, total);
+        }
+        None => println!("target 50: no subset"),
+    }
+
+    /*  Every item is even, so no subset has an odd sum.                      */
+    let even: [u64; 4] = [2, 4, 10, 16];
+    println!("odd target with even items: {:?}", subset_sum(&even, 21));
+    println!("empty subset for target 0: {:?}", subset_sum(&even, 0));
+}
@@ -0,0 +1,110 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes pi with the Gauss-Legendre (Brent-Salamin) AGM iteration.    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the arithmetic-geometric mean of a0 and b0. Both sequences a_n+1 *
+ *  = (a_n + b_n) / 2 and b_n+1 = sqrt(a_n b_n) converge to the same limit,   *
+ *  quadratically, so a handful of steps is enough.                           */
+fn agm(a0: f64, b0: f64) -> f64 {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    let mut a: f64 = a0;
+    let mut b: f64 = b0;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        if a == b {
+            break;
+        }
+
+        let next: f64 = 0.5 * (a + b);
+        b = (a * b).sqrt();
+
+        /*  Rounding can make the means trade places forever in the last bit. *
+         *  Stop once the arithmetic mean no longer changes.                  */
+        if next == a {
+            break;
+        }
+
+        a = next;
+    }
+
+    return a;
+}
+/*  End of agm.                                                               */
+
+/*  Computes pi using the Gauss-Legendre algorithm. Start with a = 1, b = 1 / *
+ *  sqrt(2), t = 1 / 4, p = 1, and iterate:                                   *
+ *                                                                            *
+ *        a' = (a + b) / 2,    b' = sqrt(a b),                                *
+ *        t' = t - p (a - a')^2,    p' = 2 p.                                 *
+ *                                                                            *
+ *  Then pi ~= (a + b)^2 / 4t. The number of correct digits doubles at each   *
+ *  step: 1, 4, 9, 20, 42, ... Compare this with the Leibniz series pi = 4 (1 *
+ *  - 1/3 + 1/5 - ...), where the error after N terms is about 1 / N, so a    *
+ *  million terms buy only six digits.                                        */
+fn agm_pi(iterations: u32) -> f64 {
+    let mut a: f64 = 1.0;
+    let mut b: f64 = 0.5f64.sqrt();
+    let mut t: f64 = 0.25;
+    let mut p: f64 = 1.0;
+
+    for _ in 0 .. iterations {
+        let next: f64 = 0.5 * (a + b);
+        b = (a * b).sqrt();
+        t = t - p * (a - next) * (a - next);
+        p = 2.0 * p;
+        a = next;
+    }
+
+    return (a + b) * (a + b) / (4.0 * t);
+}
+/*  End of agm_pi.                                                            */
+
+/*  Main routine used for testing the Gauss-Legendre algorithm.               */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+
+    for iterations in 1 .. 5 {
+        let approx: f64 = agm_pi(iterations);
+        println!("{} iterations: pi = {:.16}, error = {:.3E}",
+                 iterations, approx, (approx - pi).abs());
+    }
+
+    /*  Rounding in t leaves the result within a couple of units in the last  *
+     *  place of pi, which is as good as double precision allows.             */
+    let error: f64 = (agm_pi(4) - pi).abs();
+    println!("4 iterations match to double precision: {}",
+             error <= 2.0 * f64::EPSILON * pi);
+
+    /*  A million terms of the Leibniz series, for contrast.                  */
+    let mut leibniz: f64 = 0.0;
+
+    for k in (0 .. 1000000).rev() {
+        let term: f64 = 1.0 / ((2 * k + 1) as f64);
+        leibniz = if k % 2 == 0 {leibniz + term} else {leibniz - term};
+    }
+
+    println!("Leibniz, 10^6 terms: error = {:.3E}", (4.0 * leibniz - pi).abs());
+    println!("agm(1, 1 / sqrt(2)) = {:.16}", agm(1.0, 0.5f64.sqrt()));
+}
@@ -0,0 +1,121 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Archimedes' polygon approximation of pi, with Richardson              *
+ *      extrapolation.                                                        *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The side of a regular 2n-gon inscribed in the unit circle, from the side  *
+ *  s of the n-gon. The textbook formula is:                                  *
+ *                                                                            *
+ *        s_2n = sqrt(2 - 2 sqrt(1 - s_n^2 / 4))                              *
+ *                                                                            *
+ *  As the polygons grow s_n becomes small, sqrt(1 - s_n^2 / 4) is very close *
+ *  to 1, and the subtraction 2 - 2 sqrt(...) cancels almost all of the       *
+ *  digits. Each doubling loses about two more bits, s^2 shrinks by 4, until  *
+ *  the difference is exactly zero and the perimeter collapses. Multiplying   *
+ *  by the conjugate gives an equivalent form with no subtraction:            *
+ *                                                                            *
+ *                                s_n                                         *
+ *        s_2n = -------------------------------------                        *
+ *                sqrt(2 + 2 sqrt(1 - s_n^2 / 4))                             *
+ *                                                                            *
+ *  which is accurate for every n.                                            */
+fn double_side(s: f64) -> f64 {
+    return s / (2.0 + 2.0 * (1.0 - 0.25 * s * s).sqrt()).sqrt();
+}
+
+/*  The textbook formula, kept for comparison.                                */
+fn double_side_naive(s: f64) -> f64 {
+    return (2.0 - 2.0 * (1.0 - 0.25 * s * s).sqrt()).sqrt();
+}
+
+/*  Computes the half-perimeter of the inscribed 6 * 2^doublings-gon. A       *
+ *  regular hexagon inscribed in the unit circle has sides of length 1, and   *
+ *  the half-perimeter n s_n / 2 = n sin(pi / n) increases to pi.             */
+fn archimedes_pi(doublings: u32) -> f64 {
+    let mut n: f64 = 6.0;
+    let mut s: f64 = 1.0;
+
+    for _ in 0 .. doublings {
+        s = double_side(s);
+        n = 2.0 * n;
+    }
+
+    return 0.5 * n * s;
+}
+/*  End of archimedes_pi.                                                     */
+
+/*  Improves archimedes_pi with Richardson extrapolation. The Taylor series   *
+ *  gives n sin(pi / n) = pi - pi^3 / 6n^2 + pi^5 / 120n^4 - ..., only even   *
+ *  powers of 1 / n, and each doubling halves 1 / n. This is exactly the      *
+ *  situation of Romberg integration: combining successive values as (4 P_2n  *
+ *  - P_n) / 3 removes the 1 / n^2 term, the next column removes 1 / n^4 with *
+ *  the factor 16, and so on.                                                 */
+fn archimedes_pi_extrapolated(doublings: u32) -> f64 {
+    let mut previous: Vec<f64> = Vec::new();
+
+    for k in 0 .. doublings + 1 {
+        let mut row: Vec<f64> = vec![archimedes_pi(k)];
+        let mut factor: f64 = 4.0;
+
+        for j in 1 .. (k as usize) + 1 {
+            let refined: f64 = row[j - 1];
+            row.push(refined + (refined - previous[j - 1]) / (factor - 1.0));
+            factor = 4.0 * factor;
+        }
+
+        previous = row;
+    }
+
+    return previous[doublings as usize];
+}
+/*  End of archimedes_pi_extrapolated.                                        */
+
+/*  Main routine used for testing the approximations.                         */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+    let mut naive_side: f64 = 1.0;
+    let mut n: f64 = 6.0;
+
+    /*  The stable recurrence converges, error ~ pi^3 / 6n^2, while the naive *
+     *  one improves at first and then falls apart.                           */
+    for doublings in 1 .. 31 {
+        naive_side = double_side_naive(naive_side);
+        n = 2.0 * n;
+
+        if doublings % 5 == 0 {
+            let stable: f64 = (archimedes_pi(doublings) - pi).abs();
+            let naive: f64 = (0.5 * n * naive_side - pi).abs();
+            println!("{:>2} doublings: stable error = {:.3E}, \
+                      naive error = {:.3E}",
+                     doublings, stable, naive);
+        }
+    }
+
+    /*  Extrapolation reaches full precision with only a few polygons.        */
+    for doublings in 1 .. 6 {
+        println!("extrapolated, {} doublings: error = {:.3E}", doublings,
+                 (archimedes_pi_extrapolated(doublings) - pi).abs());
+    }
+}
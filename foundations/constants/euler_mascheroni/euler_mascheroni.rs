@@ -0,0 +1,84 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the Euler-Mascheroni constant, the limit of H_n - ln(n).     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The sum 1 + 1/2 + ... + 1/n, added smallest first so the small terms are  *
+ *  not lost against the large partial sum.                                   */
+fn harmonic_sum(n: u64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for k in (1 .. n + 1).rev() {
+        sum = sum + 1.0 / (k as f64);
+    }
+
+    return sum;
+}
+/*  End of harmonic_sum.                                                      */
+
+/*  Computes gamma = lim (H_n - ln(n)) directly from the definition, with n = *
+ *  terms. The convergence is painfully slow. The difference H_n - ln(n) -    *
+ *  gamma is about 1 / 2n, so every extra correct digit costs ten times as    *
+ *  many terms: a million terms give only six digits, and full double         *
+ *  precision is out of reach entirely, since the error would need 10^15      *
+ *  terms and the rounding in the sum grows along the way.                    */
+fn euler_mascheroni(terms: u64) -> f64 {
+    return harmonic_sum(terms) - (terms as f64).ln();
+}
+/*  End of euler_mascheroni.                                                  */
+
+/*  Computes gamma with the Euler-Maclaurin correction. The error in the      *
+ *  definition has an asymptotic expansion in powers of 1 / n, with Bernoulli *
+ *  number coefficients:                                                      *
+ *                                                                            *
+ *                                   1        1          1                    *
+ *        H_n - ln(n) = gamma  +  ---- - ------- + -------- - ...             *
+ *                                 2n     12n^2     120n^4                    *
+ *                                                                            *
+ *  Subtracting these terms removes the slow part of the convergence. The     *
+ *  next term is 1 / 252n^6, so n = 10 already gives eight digits and n =     *
+ *  1000 is limited only by rounding.                                         */
+fn euler_mascheroni_accelerated(terms: u64) -> f64 {
+    let n: f64 = terms as f64;
+    let r: f64 = 1.0 / (n * n);
+    let correction: f64 = 0.5 / n - r * (1.0 / 12.0 - r / 120.0);
+    return harmonic_sum(terms) - n.ln() - correction;
+}
+/*  End of euler_mascheroni_accelerated.                                      */
+
+/*  Main routine used for comparing the two methods.                          */
+fn main() {
+    const GAMMA: f64 = 0.5772156649015329;
+
+    for &n in [10, 1000, 100000, 10000000].iter() {
+        println!("n = {:>8}: direct error = {:.3E}, accelerated error = {:.3E}",
+                 n, (euler_mascheroni(n) - GAMMA).abs(),
+                 (euler_mascheroni_accelerated(n) - GAMMA).abs());
+    }
+
+    /*  The slow version, with enough terms, still gets a few digits right.   */
+    let slow: f64 = euler_mascheroni(10000000);
+    println!("direct, 10^7 terms: {:.10}, matches 0.57721566 to 1e-7: {}",
+             slow, (slow - GAMMA).abs() < 1.0E-7);
+}
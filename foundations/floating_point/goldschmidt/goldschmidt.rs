@@ -0,0 +1,163 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Goldschmidt division, computing a quotient using only multiplication. *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Number of Goldschmidt steps. The denominator is scaled to [0.5, 1), so    *
+ *  the error 1 - d starts at most 0.5 and is squared each step. After six    *
+ *  steps it is at most 2^-64, below double precision.                        */
+const ITERATIONS: u32 = 6;
+
+/*  Returns 2^n for -1022 <= n <= 1023 by writing the exponent bits directly. */
+fn power_of_two(n: i32) -> f64 {
+    return f64::from_bits(((n + 1023) as u64) << 52);
+}
+/*  End of power_of_two.                                                      */
+
+/*  Multiplies x by 2^n for -1024 <= n <= 1024. The factor is split in two so *
+ *  that neither half falls outside the normal range. Multiplying by a power  *
+ *  of two only changes the exponent, so this is exact unless the result over *
+ *  or underflows.                                                            */
+fn scale_by_power_of_two(x: f64, n: i32) -> f64 {
+    let half: i32 = n / 2;
+    return x * power_of_two(half) * power_of_two(n - half);
+}
+/*  End of scale_by_power_of_two.                                             */
+
+/*  Computes numerator / denominator with Goldschmidt's algorithm. If d is    *
+ *  close to 1, write d = 1 - e. Then multiplying top and bottom by f = 2 - d *
+ *  = 1 + e gives a new denominator 1 - e^2, which is even closer to 1.       *
+ *  Repeating this drives the denominator to 1, and the numerator to the      *
+ *  quotient:                                                                 *
+ *                                                                            *
+ *        n     n (1 + e)     n (1 + e)(1 + e^2)                              *
+ *        -  =  ---------  =  ------------------  = ...                       *
+ *        d     1 - e^2          1 - e^4                                      *
+ *                                                                            *
+ *  Unlike Newton's method for 1 / d, the two products in each step do not    *
+ *  depend on each other, so a pipelined multiplier can start both at once.   *
+ *  This is why AMD and IBM have used it in hardware, typically starting from *
+ *  a lookup table that makes e small so fewer steps are needed. Here the     *
+ *  denominator is instead brought into [0.5, 1) by scaling with a power of   *
+ *  two, which is exact.                                                      *
+ *                                                                            *
+ *  Conventions match the IEEE-754 rules for the "/" operator. A zero         *
+ *  denominator gives a signed infinity, or NaN if the numerator is zero or   *
+ *  NaN. An infinite denominator gives a signed zero, or NaN if the numerator *
+ *  is also infinite. A negative denominator is handled by dividing by its    *
+ *  absolute value and flipping the sign. The result is not always correctly  *
+ *  rounded, but is within a few ULPs of the true quotient.                   */
+fn divide(numerator: f64, denominator: f64) -> f64 {
+
+    /*  The sign of the result is the XOR of the two sign bits.               */
+    let negative: bool = numerator.is_sign_negative()
+        != denominator.is_sign_negative();
+
+    let sign: f64 = if negative { -1.0 } else { 1.0 };
+    let mut n: f64 = numerator.abs();
+    let mut d: f64 = denominator.abs();
+
+    /*  Special cases, following IEEE-754.                                    */
+    if n.is_nan() || d.is_nan() {
+        return f64::NAN;
+    }
+
+    if d == 0.0 {
+        if n == 0.0 {
+            return f64::NAN;
+        }
+
+        return sign * f64::INFINITY;
+    }
+
+    if d.is_infinite() {
+        if n.is_infinite() {
+            return f64::NAN;
+        }
+
+        return sign * 0.0;
+    }
+
+    /*  Subnormal denominators have no implicit leading bit. Scale both       *
+     *  values by 2^64 to make d normal first.                                */
+    if d < f64::MIN_POSITIVE {
+        d = d * power_of_two(64);
+        n = n * power_of_two(64);
+    }
+
+    /*  d = 1.m * 2^(E - 1023) = 0.1m * 2^(E - 1022), E the biased exponent.  *
+     *  Scaling by 2^(1022 - E) puts d into [0.5, 1).                         */
+    let biased_exponent: i32 = (d.to_bits() >> 52) as i32;
+    let shift: i32 = 1022 - biased_exponent;
+    d = scale_by_power_of_two(d, shift);
+    n = scale_by_power_of_two(n, shift);
+
+    /*  The Goldschmidt steps. Both products use the same factor f.           */
+    for _ in 0 .. ITERATIONS {
+        let f: f64 = 2.0 - d;
+        n = n * f;
+        d = d * f;
+    }
+
+    return sign * n;
+}
+/*  End of divide.                                                            */
+
+/*  Main routine used for testing against the built-in division operator.     */
+fn main() {
+    println!("1 / 3 = {} (built-in: {})", divide(1.0, 3.0), 1.0 / 3.0);
+    println!("22 / 7 = {} (built-in: {})", divide(22.0, 7.0), 22.0 / 7.0);
+    println!("-5 / 8 = {}", divide(-5.0, 8.0));
+    println!("1 / -0 = {}", divide(1.0, -0.0));
+    println!("0 / 0 = {}", divide(0.0, 0.0));
+    println!("-2 / inf = {}", divide(-2.0, f64::INFINITY));
+
+    /*  Compare against "/" across many magnitudes and signs, including a     *
+     *  subnormal denominator.                                                */
+    let mut worst: f64 = 0.0;
+    let mut count: u32 = 0;
+
+    for i in -40 .. 41 {
+        for j in -40 .. 41 {
+            let sign: f64 = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let numerator: f64 = sign * 1.37f64.powi(7 * i);
+            let denominator: f64 = 0.61f64.powi(11 * j) + 0.25;
+            let exact: f64 = numerator / denominator;
+            let quotient: f64 = divide(numerator, denominator);
+            let error: f64 = ((quotient - exact) / exact).abs();
+            worst = worst.max(error);
+            count = count + 1;
+        }
+    }
+
+    let tiny: f64 = 3.0E-310;
+    let exact: f64 = 1.0E-300 / tiny;
+    let tiny_error: f64 = ((divide(1.0E-300, tiny) - exact) / exact).abs();
+    worst = worst.max(tiny_error);
+    count = count + 1;
+
+    println!("{} quotients, worst error = {:.2} ulp",
+             count, worst / f64::EPSILON);
+    println!("within 4 ulp: {}", worst <= 4.0 * f64::EPSILON);
+}
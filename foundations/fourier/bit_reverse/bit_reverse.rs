@@ -0,0 +1,84 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the bit-reversal permutation used by iterative FFT           *
+ *      algorithms.                                                           *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the permutation sending index k to the integer whose binary      *
+ *  digits are those of k written backwards, using log2(n) bits. n must be a  *
+ *  power of two, otherwise an empty vector is returned.                      */
+fn bit_reverse_indices(n: usize) -> Vec<usize> {
+
+    /*  Powers of two have exactly one bit set. This also rejects n = 0.      */
+    if !n.is_power_of_two() {
+        return Vec::new();
+    }
+
+    /*  The number of bits needed to write the indices 0, 1, ..., n - 1.      */
+    let number_of_bits: u32 = n.trailing_zeros();
+    let mut indices: Vec<usize> = Vec::with_capacity(n);
+
+    for index in 0 .. n {
+        let mut reversed: usize = 0;
+        let mut remaining: usize = index;
+
+        /*  Peel off the lowest bit of the index and push it onto the         *
+         *  reversed value. After number_of_bits steps the digits are         *
+         *  reversed.                                                         */
+        for _ in 0 .. number_of_bits {
+            reversed = (reversed << 1) | (remaining & 1);
+            remaining = remaining >> 1;
+        }
+
+        indices.push(reversed);
+    }
+
+    return indices;
+}
+/*  End of bit_reverse_indices.                                               */
+
+/*  Main routine used for testing the bit-reversal permutation.               */
+fn main() {
+
+    /*  For n = 8 we use three bits. 1 = 001 becomes 100 = 4, 3 = 011 becomes *
+     *  110 = 6, and so on. The result is [0, 4, 2, 6, 1, 5, 3, 7].           */
+    let indices: Vec<usize> = bit_reverse_indices(8);
+    println!("n = 8: {:?}", indices);
+
+    /*  Reversing the digits twice gives back the original digits, so the     *
+     *  permutation is its own inverse. Check this for several sizes.         */
+    for &n in [1, 2, 8, 64, 1024].iter() {
+        let permutation: Vec<usize> = bit_reverse_indices(n);
+        let mut identity: bool = true;
+
+        for index in 0 .. n {
+            identity = identity && permutation[permutation[index]] == index;
+        }
+
+        println!("n = {}: applying twice gives the identity: {}", n, identity);
+    }
+
+    /*  Non-powers of two do not have a bit-reversal permutation.             */
+    println!("n = 6: {:?}", bit_reverse_indices(6));
+}
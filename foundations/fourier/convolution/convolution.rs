@@ -0,0 +1,253 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the discrete convolution of two sequences using the FFT.     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for complex arithmetic.                                         */
+use std::ops::{Add, Mul, Sub};
+
+/*  Simple struct for complex numbers, z = real + i*imag.                     */
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    real: f64,
+    imag: f64
+}
+
+/*  Complex addition, done component-wise.                                    */
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag
+        };
+    }
+}
+
+/*  Complex subtraction, also done component-wise.                            */
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real - other.real,
+            imag: self.imag - other.imag
+        };
+    }
+}
+
+/*  Complex multiplication, (a + ib)(c + id) = (ac - bd) + i(ad + bc).        */
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real
+        };
+    }
+}
+
+/*  Computes exp(i theta) = cos(theta) + i sin(theta).                        */
+fn expi(theta: f64) -> Complex {
+    return Complex {
+        real: theta.cos(),
+        imag: theta.sin()
+    };
+}
+
+/*  Computes the bit-reversal permutation for a power of two n.               */
+fn bit_reverse_indices(n: usize) -> Vec<usize> {
+    let number_of_bits: u32 = n.trailing_zeros();
+    let mut indices: Vec<usize> = Vec::with_capacity(n);
+
+    for index in 0 .. n {
+        let mut reversed: usize = 0;
+        let mut remaining: usize = index;
+
+        for _ in 0 .. number_of_bits {
+            reversed = (reversed << 1) | (remaining & 1);
+            remaining = remaining >> 1;
+        }
+
+        indices.push(reversed);
+    }
+
+    return indices;
+}
+/*  End of bit_reverse_indices.                                               */
+
+/*  The iterative in-place radix-2 FFT. The length must be a power of two. If *
+ *  inverse is true the inverse transform is computed instead, which uses     *
+ *  exp(+2 pi i / N) and divides by N.                                        */
+fn fft_in_place(data: &mut [Complex], inverse: bool) {
+    let n: usize = data.len();
+    let sign: f64 = if inverse { 1.0 } else { -1.0 };
+
+    /*  Put the data in bit-reversed order so the butterflies work in place.  */
+    let reversed: Vec<usize> = bit_reverse_indices(n);
+
+    for index in 0 .. n {
+        if index < reversed[index] {
+            data.swap(index, reversed[index]);
+        }
+    }
+
+    /*  Combine transforms of size length / 2 into transforms of size length. */
+    let mut length: usize = 2;
+
+    while length <= n {
+        let theta: f64 = sign * 2.0 * std::f64::consts::PI / (length as f64);
+        let half: usize = length / 2;
+        let mut start: usize = 0;
+
+        while start < n {
+            for k in 0 .. half {
+                let even: Complex = data[start + k];
+                let twiddle: Complex = expi(theta * (k as f64));
+                let odd: Complex = twiddle * data[start + k + half];
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+
+            start = start + length;
+        }
+
+        length = length << 1;
+    }
+
+    /*  The inverse transform has a 1 / N normalization.                      */
+    if inverse {
+        let scale: f64 = 1.0 / (n as f64);
+
+        for z in data.iter_mut() {
+            z.real = z.real * scale;
+            z.imag = z.imag * scale;
+        }
+    }
+}
+/*  End of fft_in_place.                                                      */
+
+/*  Computes the convolution of a and b using the FFT. The output has length  *
+ *  a.len() + b.len() - 1 and is given by:                                    *
+ *                                                                            *
+ *                 ---                                                        *
+ *                 \                                                          *
+ *        c[k] =   /     a[j] b[k - j]                                        *
+ *                 ---                                                        *
+ *                  j                                                         *
+ *                                                                            *
+ *  If a and b are the coefficients of two polynomials, c is the list of      *
+ *  coefficients of their product. The convolution theorem says the FFT turns *
+ *  convolution into pointwise multiplication, so we transform, multiply, and *
+ *  transform back. This is O(n log n) instead of O(n m).                     */
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+
+    /*  The convolution with an empty sequence is empty.                      */
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    /*  The FFT computes a cyclic convolution. Zero padding to at least the   *
+     *  length of the output prevents the end of the sequence wrapping around *
+     *  onto the start. The radix-2 FFT also needs a power of two.            */
+    let output_length: usize = a.len() + b.len() - 1;
+    let n: usize = output_length.next_power_of_two();
+    let zero: Complex = Complex { real: 0.0, imag: 0.0 };
+
+    let mut a_hat: Vec<Complex> = vec![zero; n];
+    let mut b_hat: Vec<Complex> = vec![zero; n];
+
+    for (index, value) in a.iter().enumerate() {
+        a_hat[index].real = *value;
+    }
+
+    for (index, value) in b.iter().enumerate() {
+        b_hat[index].real = *value;
+    }
+
+    fft_in_place(&mut a_hat, false);
+    fft_in_place(&mut b_hat, false);
+
+    /*  Pointwise multiplication in frequency space.                          */
+    for index in 0 .. n {
+        a_hat[index] = a_hat[index] * b_hat[index];
+    }
+
+    /*  Transform back. The inputs are real, so the imaginary parts are only  *
+     *  rounding error and are discarded.                                     */
+    fft_in_place(&mut a_hat, true);
+    return a_hat[.. output_length].iter().map(|z| z.real).collect();
+}
+/*  End of convolve.                                                          */
+
+/*  Computes the convolution directly from the definition, in O(n m) time.    */
+fn convolve_direct(a: &[f64], b: &[f64]) -> Vec<f64> {
+
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut output: Vec<f64> = vec![0.0; a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            output[i + j] = output[i + j] + x * y;
+        }
+    }
+
+    return output;
+}
+/*  End of convolve_direct.                                                   */
+
+/*  Main routine used for testing the FFT convolution.                        */
+fn main() {
+
+    /*  (1 + 2x + 3x^2)(4 + 5x) = 4 + 13x + 22x^2 + 15x^3.                    */
+    let product: Vec<f64> = convolve(&[1.0, 2.0, 3.0], &[4.0, 5.0]);
+    println!("(1 + 2x + 3x^2)(4 + 5x) coefficients: {:?}",
+             product.iter().map(|c| c.round()).collect::<Vec<f64>>());
+
+    /*  Compare with the direct method for several sizes, including lengths   *
+     *  that are not powers of two.                                           */
+    let sizes: [(usize, usize); 5] = [
+        (1, 1), (3, 2), (7, 5), (16, 16), (33, 20)
+    ];
+
+    for &(n, m) in sizes.iter() {
+        let a: Vec<f64> = (0 .. n).map(|k| (0.7 * k as f64).sin()).collect();
+        let b: Vec<f64> = (0 .. m).map(|k| 1.0 / (1.0 + k as f64)).collect();
+        let fast: Vec<f64> = convolve(&a, &b);
+        let slow: Vec<f64> = convolve_direct(&a, &b);
+        let mut error: f64 = 0.0;
+
+        for (x, y) in fast.iter().zip(slow.iter()) {
+            error = error.max((x - y).abs());
+        }
+
+        println!("{:>2} x {:>2}: max error = {:.3E}, within 1e-9: {}",
+                 n, m, error, error < 1.0E-9);
+    }
+}
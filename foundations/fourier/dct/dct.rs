@@ -0,0 +1,121 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the type-II discrete cosine transform and its inverse.       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the DCT-II directly from the definition:                         *
+ *                                                                            *
+ *                 N-1                                                        *
+ *                 ---                                                        *
+ *                 \              pi         1                                *
+ *        X_k  =   /    x_n cos( --- ( n + --- ) k )                          *
+ *                 ---            N          2                                *
+ *                n = 0                                                       *
+ *                                                                            *
+ *  This is O(N^2). Fast versions, like the FFT, exist, but the direct sum    *
+ *  shows what is being computed.                                             */
+fn dct2(input: &[f64]) -> Vec<f64> {
+    let n: usize = input.len();
+    let scale: f64 = std::f64::consts::PI / (n as f64);
+    let mut output: Vec<f64> = Vec::with_capacity(n);
+
+    for k in 0 .. n {
+        let mut sum: f64 = 0.0;
+
+        for (index, x) in input.iter().enumerate() {
+            sum = sum + x * (scale * (index as f64 + 0.5) * (k as f64)).cos();
+        }
+
+        output.push(sum);
+    }
+
+    return output;
+}
+/*  End of dct2.                                                              */
+
+/*  Computes the inverse of dct2. This is a scaled DCT-III:                   *
+ *                                                                            *
+ *                                 N-1                                        *
+ *                                 ---                                        *
+ *                 X_0      2      \              pi         1                *
+ *        x_n  =   ---  +  ---     /    X_k cos( --- ( n + --- ) k )          *
+ *                  N       N      ---            N          2                *
+ *                                k = 1                                       *
+ *                                                                            */
+fn idct2(input: &[f64]) -> Vec<f64> {
+    let n: usize = input.len();
+    let scale: f64 = std::f64::consts::PI / (n as f64);
+    let mut output: Vec<f64> = Vec::with_capacity(n);
+
+    for index in 0 .. n {
+
+        /*  The zeroth term is weighted by half as much as the rest.          */
+        let mut sum: f64 = 0.5 * input[0];
+
+        for k in 1 .. n {
+            let angle: f64 = scale * (index as f64 + 0.5) * (k as f64);
+            sum = sum + input[k] * angle.cos();
+        }
+
+        output.push(2.0 * sum / (n as f64));
+    }
+
+    return output;
+}
+/*  End of idct2.                                                             */
+
+/*  Main routine used for testing the DCT.                                    */
+fn main() {
+
+    /*  Round trip some data that has no special structure.                   */
+    let data: Vec<f64> = (0 .. 32).map(|k| {
+        let t: f64 = k as f64;
+        return (0.3 * t).sin() + 0.1 * t * t - 2.0;
+    }).collect();
+
+    let round_trip: Vec<f64> = idct2(&dct2(&data));
+    let mut error: f64 = 0.0;
+
+    for (x, y) in data.iter().zip(round_trip.iter()) {
+        error = error.max((x - y).abs());
+    }
+
+    println!("Round trip max error = {:.3E}, within 1e-10: {}",
+             error, error < 1.0E-10);
+
+    /*  A constant signal has all of its energy in X_0, the average, and      *
+     *  every other coefficient is zero. Smooth signals have most of their    *
+     *  energy in the first few coefficients, so the rest can be stored       *
+     *  coarsely or dropped. This is the idea behind JPEG compression, which  *
+     *  uses the DCT on 8x8 blocks of pixels.                                 */
+    let constant: Vec<f64> = dct2(&[3.0; 8]);
+
+    for (k, coefficient) in constant.iter().enumerate() {
+        println!("X_{} = {:+.6E}", k, coefficient);
+    }
+
+    /*  Fraction of the total energy in the zeroth coefficient.               */
+    let total: f64 = constant.iter().map(|x| x * x).sum::<f64>();
+    println!("Energy in X_0: {:.16}", constant[0] * constant[0] / total);
+}
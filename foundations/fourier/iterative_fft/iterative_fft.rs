@@ -0,0 +1,234 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the FFT in place using bit reversal and butterfly passes, and*
+ *      compares it with the recursive FFT.                                   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for complex arithmetic.                                         */
+use std::ops::{Add, Mul, Sub};
+
+/*  Simple struct for complex numbers, z = real + i*imag.                     */
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    real: f64,
+    imag: f64
+}
+
+/*  Complex addition, done component-wise.                                    */
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag
+        };
+    }
+}
+
+/*  Complex subtraction, also done component-wise.                            */
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real - other.real,
+            imag: self.imag - other.imag
+        };
+    }
+}
+
+/*  Complex multiplication, (a + ib)(c + id) = (ac - bd) + i(ad + bc).        */
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real
+        };
+    }
+}
+
+/*  Computes exp(i theta) = cos(theta) + i sin(theta).                        */
+fn expi(theta: f64) -> Complex {
+    return Complex {
+        real: theta.cos(),
+        imag: theta.sin()
+    };
+}
+
+/*  Computes the bit-reversal permutation for a power of two n.               */
+fn bit_reverse_indices(n: usize) -> Vec<usize> {
+    let number_of_bits: u32 = n.trailing_zeros();
+    let mut indices: Vec<usize> = Vec::with_capacity(n);
+
+    for index in 0 .. n {
+        let mut reversed: usize = 0;
+        let mut remaining: usize = index;
+
+        for _ in 0 .. number_of_bits {
+            reversed = (reversed << 1) | (remaining & 1);
+            remaining = remaining >> 1;
+        }
+
+        indices.push(reversed);
+    }
+
+    return indices;
+}
+/*  End of bit_reverse_indices.                                               */
+
+/*  The recursive radix-2 FFT. The input is split into its even and odd       *
+ *  indexed parts, each is transformed, and the two halves are combined:      *
+ *                                                                            *
+ *        X_k         = E_k + w^k O_k                                         *
+ *        X_{k + N/2} = E_k - w^k O_k, w = exp(-2 pi i / N)                   *
+ *                                                                            *
+ *  Every level of the recursion allocates new vectors for the halves.        */
+fn fft(input: &[Complex]) -> Vec<Complex> {
+    let n: usize = input.len();
+
+    /*  The transform of a single point is itself.                            */
+    if n <= 1 {
+        return input.to_vec();
+    }
+
+    let evens: Vec<Complex> = input.iter().step_by(2).cloned().collect();
+    let odds: Vec<Complex> = input.iter().skip(1).step_by(2).cloned().collect();
+    let even_fft: Vec<Complex> = fft(&evens);
+    let odd_fft: Vec<Complex> = fft(&odds);
+
+    let mut output: Vec<Complex> = vec![Complex { real: 0.0, imag: 0.0 }; n];
+    let half: usize = n / 2;
+
+    for k in 0 .. half {
+        let theta: f64 = -2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+        let twiddle: Complex = expi(theta) * odd_fft[k];
+        output[k] = even_fft[k] + twiddle;
+        output[k + half] = even_fft[k] - twiddle;
+    }
+
+    return output;
+}
+/*  End of fft.                                                               */
+
+/*  The iterative in-place radix-2 FFT. The length of the data must be a      *
+ *  power of two.                                                             *
+ *                                                                            *
+ *  Unrolling the recursion of the recursive FFT, the innermost transforms    *
+ *  act on the inputs in bit-reversed order. Permuting the data into this     *
+ *  order first, the butterflies can be applied bottom-up: pairs, then blocks *
+ *  of 4, 8, and so on, all within the original array. No memory is allocated *
+ *  for the intermediate halves, and each pass sweeps linearly through the    *
+ *  array, which is much friendlier to the cache than the recursive version.  */
+fn fft_iterative(data: &mut [Complex]) {
+    let n: usize = data.len();
+
+    assert!(n.is_power_of_two(), "fft_iterative: length must be 2^k");
+
+    /*  Apply the bit-reversal permutation. Since it is its own inverse we    *
+     *  can swap pairs, doing each swap once (when index < reversed).         */
+    let reversed: Vec<usize> = bit_reverse_indices(n);
+
+    for index in 0 .. n {
+        if index < reversed[index] {
+            data.swap(index, reversed[index]);
+        }
+    }
+
+    /*  Butterfly passes. At each pass, blocks of size "length" are formed by *
+     *  combining two transforms of size length / 2.                          */
+    let mut length: usize = 2;
+
+    while length <= n {
+        let theta: f64 = -2.0 * std::f64::consts::PI / (length as f64);
+        let half: usize = length / 2;
+        let mut start: usize = 0;
+
+        while start < n {
+
+            /*  Same combination step as the recursive version, but in place. *
+             *  The even part sits in the first half of the block, the odd    *
+             *  part in the second half.                                      */
+            for k in 0 .. half {
+                let even: Complex = data[start + k];
+                let twiddle: Complex = expi(theta * (k as f64));
+                let odd: Complex = twiddle * data[start + k + half];
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+
+            start = start + length;
+        }
+
+        length = length << 1;
+    }
+}
+/*  End of fft_iterative.                                                     */
+
+/*  Computes the largest difference between two lists of complex numbers.     */
+fn max_difference(a: &[Complex], b: &[Complex]) -> f64 {
+    let mut max: f64 = 0.0;
+
+    for (z, w) in a.iter().zip(b.iter()) {
+        let difference: Complex = *z - *w;
+        max = max.max(difference.real.hypot(difference.imag));
+    }
+
+    return max;
+}
+/*  End of max_difference.                                                    */
+
+/*  Main routine used for comparing the iterative and recursive FFTs.         */
+fn main() {
+
+    /*  Test several power-of-two sizes with data that has no special         *
+     *  symmetry.                                                             */
+    for &n in [1, 2, 8, 64, 1024].iter() {
+        let mut data: Vec<Complex> = Vec::with_capacity(n);
+
+        for index in 0 .. n {
+            let t: f64 = index as f64;
+            data.push(Complex {
+                real: (0.3 * t).cos() + 0.01 * t,
+                imag: (1.7 * t).sin() - 0.5
+            });
+        }
+
+        let recursive: Vec<Complex> = fft(&data);
+        fft_iterative(&mut data);
+
+        let error: f64 = max_difference(&recursive, &data);
+        println!("n = {:>4}: max difference = {:.3E}, within 1e-10: {}",
+                 n, error, error < 1.0E-10);
+    }
+
+    /*  The transform of a unit impulse is the constant 1.                    */
+    let zero: Complex = Complex { real: 0.0, imag: 0.0 };
+    let mut impulse: Vec<Complex> = vec![zero; 4];
+    impulse[0].real = 1.0;
+    fft_iterative(&mut impulse);
+    println!("FFT of impulse: {:?}", impulse);
+}
@@ -0,0 +1,240 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the FFT of real-valued data using a half-length complex FFT. *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for complex arithmetic.                                         */
+use std::ops::{Add, Mul, Sub};
+
+/*  Simple struct for complex numbers, z = real + i*imag.                     */
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    real: f64,
+    imag: f64
+}
+
+/*  Complex addition, done component-wise.                                    */
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag
+        };
+    }
+}
+
+/*  Complex subtraction, also done component-wise.                            */
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real - other.real,
+            imag: self.imag - other.imag
+        };
+    }
+}
+
+/*  Complex multiplication, (a + ib)(c + id) = (ac - bd) + i(ad + bc).        */
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real
+        };
+    }
+}
+
+/*  Computes exp(i theta) = cos(theta) + i sin(theta).                        */
+fn expi(theta: f64) -> Complex {
+    return Complex {
+        real: theta.cos(),
+        imag: theta.sin()
+    };
+}
+
+/*  Computes the bit-reversal permutation for a power of two n.               */
+fn bit_reverse_indices(n: usize) -> Vec<usize> {
+    let number_of_bits: u32 = n.trailing_zeros();
+    let mut indices: Vec<usize> = Vec::with_capacity(n);
+
+    for index in 0 .. n {
+        let mut reversed: usize = 0;
+        let mut remaining: usize = index;
+
+        for _ in 0 .. number_of_bits {
+            reversed = (reversed << 1) | (remaining & 1);
+            remaining = remaining >> 1;
+        }
+
+        indices.push(reversed);
+    }
+
+    return indices;
+}
+/*  End of bit_reverse_indices.                                               */
+
+/*  The iterative in-place radix-2 FFT. The length must be a power of two.    */
+fn fft_in_place(data: &mut [Complex]) {
+    let n: usize = data.len();
+
+    /*  Put the data in bit-reversed order so the butterflies work in place.  */
+    let reversed: Vec<usize> = bit_reverse_indices(n);
+
+    for index in 0 .. n {
+        if index < reversed[index] {
+            data.swap(index, reversed[index]);
+        }
+    }
+
+    /*  Combine transforms of size length / 2 into transforms of size length. */
+    let mut length: usize = 2;
+
+    while length <= n {
+        let theta: f64 = -2.0 * std::f64::consts::PI / (length as f64);
+        let half: usize = length / 2;
+        let mut start: usize = 0;
+
+        while start < n {
+            for k in 0 .. half {
+                let even: Complex = data[start + k];
+                let twiddle: Complex = expi(theta * (k as f64));
+                let odd: Complex = twiddle * data[start + k + half];
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+
+            start = start + length;
+        }
+
+        length = length << 1;
+    }
+}
+/*  End of fft_in_place.                                                      */
+
+/*  Computes the complex conjugate, conj(x + iy) = x - iy.                    */
+fn conj(z: Complex) -> Complex {
+    return Complex {
+        real: z.real,
+        imag: -z.imag
+    };
+}
+
+/*  Computes the FFT of real data. The length must be a power of two. Only    *
+ *  the bins 0, 1, ..., N/2 are returned. For real input X_{N-k} = conj(X_k), *
+ *  so the remaining bins carry no new information.                           *
+ *                                                                            *
+ *  The trick is to pack the even samples into the real parts and the odd     *
+ *  samples into the imaginary parts of a complex array of length N/2:        *
+ *                                                                            *
+ *        z_j = x_{2j} + i x_{2j+1}                                           *
+ *                                                                            *
+ *  One FFT of length N/2 gives Z_k = E_k + i O_k, where E and O are the      *
+ *  transforms of the even and odd samples. Conjugate symmetry of E and O     *
+ *  (both are transforms of real data) lets us separate them:                 *
+ *                                                                            *
+ *        E_k = (Z_k + conj(Z_{N/2-k})) / 2                                   *
+ *        O_k = (Z_k - conj(Z_{N/2-k})) / 2i                                  *
+ *                                                                            *
+ *  and the same butterfly as the FFT finishes the job, X_k = E_k + w^k O_k   *
+ *  with w = exp(-2 pi i / N). This is about half the work of a complex FFT   *
+ *  of length N.                                                              */
+fn rfft(input: &[f64]) -> Vec<Complex> {
+    let n: usize = input.len();
+
+    assert!(n.is_power_of_two(), "rfft: length must be 2^k");
+
+    /*  With one point there is nothing to pack. The transform is the input.  */
+    if n == 1 {
+        return vec![Complex { real: input[0], imag: 0.0 }];
+    }
+
+    /*  Pack the real data into a complex array of half the length.           */
+    let half: usize = n / 2;
+    let mut packed: Vec<Complex> = Vec::with_capacity(half);
+
+    for j in 0 .. half {
+        packed.push(Complex {
+            real: input[2 * j],
+            imag: input[2 * j + 1]
+        });
+    }
+
+    fft_in_place(&mut packed);
+
+    /*  Unpack. Indices are taken mod N/2, so k = N/2 uses Z_0 again.         */
+    let mut output: Vec<Complex> = Vec::with_capacity(half + 1);
+    let minus_half_i: Complex = Complex { real: 0.0, imag: -0.5 };
+    let one_half: Complex = Complex { real: 0.5, imag: 0.0 };
+
+    for k in 0 ..= half {
+        let z_k: Complex = packed[k % half];
+        let z_mirror: Complex = conj(packed[(half - k) % half]);
+
+        let even: Complex = one_half * (z_k + z_mirror);
+        let odd: Complex = minus_half_i * (z_k - z_mirror);
+
+        let theta: f64 = -2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+        output.push(even + expi(theta) * odd);
+    }
+
+    return output;
+}
+/*  End of rfft.                                                              */
+
+/*  Main routine used for comparing rfft with the full complex FFT.           */
+fn main() {
+
+    for &n in [1, 2, 4, 16, 256, 4096].iter() {
+
+        /*  Real data with no special symmetry.                               */
+        let data: Vec<f64> = (0 .. n).map(|k| {
+            let t: f64 = k as f64;
+            return (0.4 * t).sin() + 0.25 * (2.3 * t).cos() + 0.001 * t;
+        }).collect();
+
+        /*  The full complex FFT of the same data.                            */
+        let mut full: Vec<Complex> = data.iter().map(|x| {
+            return Complex { real: *x, imag: 0.0 };
+        }).collect();
+
+        fft_in_place(&mut full);
+
+        /*  Compare the N/2 + 1 bins of rfft with the first half of the FFT.  */
+        let half_spectrum: Vec<Complex> = rfft(&data);
+        let mut error: f64 = 0.0;
+
+        for (z, w) in half_spectrum.iter().zip(full.iter()) {
+            let difference: Complex = *z - *w;
+            error = error.max(difference.real.hypot(difference.imag));
+        }
+
+        println!("n = {:>4}: {} bins, max error = {:.3E}, within 1e-10: {}",
+                 n, half_spectrum.len(), error, error < 1.0E-10);
+    }
+}
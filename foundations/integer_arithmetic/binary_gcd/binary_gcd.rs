@@ -0,0 +1,109 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Stein's binary GCD algorithm, using shifts and subtraction only.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the greatest common divisor using the Euclidean algorithm.       */
+fn gcd(a: u64, b: u64) -> u64 {
+    let mut x: u64 = a;
+    let mut y: u64 = b;
+
+    /*  gcd(x, y) = gcd(y, x mod y). Repeat until the remainder is zero.      */
+    while y != 0 {
+        let remainder: u64 = x % y;
+        x = y;
+        y = remainder;
+    }
+
+    return x;
+}
+/*  End of gcd.                                                               */
+
+/*  Computes gcd(a, b) with Stein's algorithm. It uses three facts:           *
+ *                                                                            *
+ *        gcd(2x, 2y) = 2 gcd(x, y)                                           *
+ *        gcd(2x, y)  = gcd(x, y), y odd                                      *
+ *        gcd(x, y)   = gcd(x - y, y), x >= y                                 *
+ *                                                                            *
+ *  The common factor 2^k is removed first with trailing_zeros. After that,   *
+ *  one of the numbers is always odd, stray factors of two are shifted away,  *
+ *  and the difference of two odd numbers is even, so each subtraction is     *
+ *  followed by at least one shift. The Euclidean algorithm needs a division  *
+ *  for every step, which on older hardware, and on many microcontrollers     *
+ *  today, is far slower than shifts and subtractions. This is why the binary *
+ *  method was historically faster, and it is still used in bignum libraries. */
+fn binary_gcd(a: u64, b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+
+    if b == 0 {
+        return a;
+    }
+
+    /*  2^shift is the largest power of two dividing both a and b.            */
+    let shift: u32 = (a | b).trailing_zeros();
+    let mut x: u64 = a >> a.trailing_zeros();
+    let mut y: u64 = b;
+
+    /*  x is odd from here on. Make y odd, then subtract the smaller one.     */
+    while y != 0 {
+        y = y >> y.trailing_zeros();
+
+        if x > y {
+            std::mem::swap(&mut x, &mut y);
+        }
+
+        y = y - x;
+    }
+
+    return x << shift;
+}
+/*  End of binary_gcd.                                                        */
+
+/*  Main routine used for testing Stein's algorithm against Euclid's.         */
+fn main() {
+    println!("binary_gcd(48, 180) = {}", binary_gcd(48, 180));
+    println!("binary_gcd(0, 7) = {}", binary_gcd(0, 7));
+    println!("binary_gcd(0, 0) = {}", binary_gcd(0, 0));
+    println!("binary_gcd(2^40, 2^40) = {}", binary_gcd(1 << 40, 1 << 40));
+
+    /*  Compare on all small pairs, including zeros and equal inputs.         */
+    let mut agree: bool = true;
+
+    for a in 0 .. 300 {
+        for b in 0 .. 300 {
+            agree = agree && binary_gcd(a, b) == gcd(a, b);
+        }
+    }
+
+    /*  And on large pairs built from known factors.                          */
+    let x: u64 = 2 * 2 * 3 * 1000003 * 999983;
+    let y: u64 = 2 * 2 * 2 * 5 * 999983 * 7919;
+    agree = agree && binary_gcd(x, y) == gcd(x, y);
+    agree = agree && binary_gcd(x, y) == 4 * 999983;
+    agree = agree && binary_gcd(u64::MAX, u64::MAX - 1) == 1;
+
+    println!("agrees with the Euclidean gcd: {}", agree);
+}
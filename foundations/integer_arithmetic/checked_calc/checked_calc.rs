@@ -0,0 +1,92 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A calculator that reports the first step where u64 arithmetic         *
+ *      overflows.                                                            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Applies a sequence of operations to a starting value. Each entry of ops   *
+ *  is an operator, '+', '-', or '*', and the right operand. The checked      *
+ *  methods return None instead of wrapping, so the first overflow (or        *
+ *  underflow below zero) can be reported. On failure the index into ops and  *
+ *  the operator are returned. An unknown operator is reported the same way.  */
+fn evaluate(ops: &[(char, u64)], start: u64) -> Result<u64, (usize, char)> {
+    let mut value: u64 = start;
+
+    for (index, &(operator, operand)) in ops.iter().enumerate() {
+        let next: Option<u64> = match operator {
+            '+' => value.checked_add(operand),
+            '-' => value.checked_sub(operand),
+            '*' => value.checked_mul(operand),
+            _ => None,
+        };
+
+        match next {
+            Some(result) => value = result,
+            None => return Err((index, operator)),
+        }
+    }
+
+    return Ok(value);
+}
+/*  End of evaluate.                                                          */
+
+/*  Prints the outcome of a calculation.                                      */
+fn print_result(result: Result<u64, (usize, char)>) {
+    match result {
+        Ok(value) => println!("    result: {}", value),
+        Err((index, operator)) => {
+            println!("    overflow at step {}, operator '{}'", index, operator)
+        }
+    }
+}
+/*  End of print_result.                                                      */
+
+/*  A short program for testing our functions.                                */
+fn main() {
+
+    /*  10! fits easily in 64 bits and completes.                             */
+    let factorial: Vec<(char, u64)> = (2 .. 11).map(|n| ('*', n)).collect();
+    let result: Result<u64, (usize, char)> = evaluate(&factorial, 1);
+    println!("10!:");
+    print_result(result);
+    println!("    correct: {}", result == Ok(3628800));
+
+    /*  Start at 1 and multiply by 1000 eight times. 1000^6 = 10^18 is less   *
+     *  than 2^64, about 1.8 x 10^19, but 1000^7 = 10^21 is not. The 7th      *
+     *  multiplication overflows, which is index 7 since the first entry is   *
+     *  the addition.                                                         */
+    let mut powers: Vec<(char, u64)> = vec![('+', 1)];
+    powers.extend(std::iter::repeat_n(('*', 1000), 8));
+    let result: Result<u64, (usize, char)> = evaluate(&powers, 0);
+    println!("1 * 1000 * 1000 * ...:");
+    print_result(result);
+    println!("    correct: {}", result == Err((7, '*')));
+
+    /*  Subtraction below zero is also an overflow for unsigned integers.     */
+    let steps: [(char, u64); 4] = [('+', 5), ('*', 3), ('-', 10), ('-', 6)];
+    let result: Result<u64, (usize, char)> = evaluate(&steps, 0);
+    println!("0 + 5 * 3 - 10 - 6:");
+    print_result(result);
+    println!("    correct: {}", result == Err((3, '-')));
+}
@@ -0,0 +1,168 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the continued fraction expansion of a real number and its    *
+ *      convergents.                                                          *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the first few coefficients [a0; a1, a2, ...] of the continued    *
+ *  fraction of x. That is:                                                   *
+ *                                                                            *
+ *                           1                                                *
+ *        x = a0 + ---------------------                                      *
+ *                              1                                             *
+ *                   a1 + --------------                                      *
+ *                                  1                                         *
+ *                          a2 + -------                                      *
+ *                                ...                                         *
+ *                                                                            *
+ *  Fewer than "terms" coefficients are returned if the expansion terminates, *
+ *  which happens when x is rational (or when rounding makes it look so), or  *
+ *  if a coefficient does not fit in an i64. In particular, if the integer    *
+ *  part of x is out of range, the expansion is empty.                        */
+fn continued_fraction(x: f64, terms: usize) -> Vec<i64> {
+    let mut coefficients: Vec<i64> = Vec::new();
+    let mut value: f64 = x;
+
+    /*  NaN and infinity have no expansion.                                   */
+    if !x.is_finite() {
+        return coefficients;
+    }
+
+    while coefficients.len() < terms {
+
+        /*  The next coefficient is the integer part. floor is used, and not  *
+         *  truncation, so that negative numbers work as well. Only a0 may be *
+         *  negative, the remaining coefficients are positive.                */
+        let integer_part: f64 = value.floor();
+
+        /*  The cast "as i64" saturates, silently giving i64::MAX for 1E20.   *
+         *  The valid range is [-2^63, 2^63), both ends exact doubles.        */
+        let range: std::ops::Range<f64> =
+            -9223372036854775808.0 .. 9223372036854775808.0;
+
+        if !range.contains(&integer_part) {
+            break;
+        }
+
+        coefficients.push(integer_part as i64);
+
+        /*  The remaining part is in [0, 1). If it is zero the expansion is   *
+         *  finished. Otherwise invert it and repeat. Each inversion          *
+         *  amplifies the rounding error in the fractional part, so only the  *
+         *  first dozen or so coefficients of a double are meaningful.        */
+        let fractional_part: f64 = value - integer_part;
+
+        if fractional_part == 0.0 {
+            break;
+        }
+
+        value = 1.0 / fractional_part;
+    }
+
+    return coefficients;
+}
+/*  End of continued_fraction.                                                */
+
+/*  Computes the convergents p_n / q_n of the continued fraction [a0; a1,     *
+ *  ...]. These are the fractions obtained by truncating the expansion after  *
+ *  a_n, and are computed with the recurrence:                                *
+ *                                                                            *
+ *        p_n = a_n p_{n-1} + p_{n-2}, p_{-1} = 1, p_{-2} = 0                 *
+ *        q_n = a_n q_{n-1} + q_{n-2}, q_{-1} = 0, q_{-2} = 1                 *
+ *                                                                            *
+ *  The numerators and denominators grow at least as fast as the Fibonacci    *
+ *  numbers, so they may overflow. The computation stops at the first         *
+ *  convergent that does not fit in an i64, and only the earlier ones are     *
+ *  returned.                                                                 */
+fn convergents(coeffs: &[i64]) -> Vec<(i64, i64)> {
+    let mut result: Vec<(i64, i64)> = Vec::new();
+
+    /*  (p_{n-2}, q_{n-2}) and (p_{n-1}, q_{n-1}), starting at n = 0.         */
+    let (mut p_older, mut q_older): (i64, i64) = (0, 1);
+    let (mut p_old, mut q_old): (i64, i64) = (1, 0);
+
+    for a in coeffs.iter() {
+        let p: i64 = match a.checked_mul(p_old) {
+            Some(product) => match product.checked_add(p_older) {
+                Some(sum) => sum,
+                None => break
+            },
+            None => break
+        };
+
+        let q: i64 = match a.checked_mul(q_old) {
+            Some(product) => match product.checked_add(q_older) {
+                Some(sum) => sum,
+                None => break
+            },
+            None => break
+        };
+
+        result.push((p, q));
+
+        p_older = p_old;
+        q_older = q_old;
+        p_old = p;
+        q_old = q;
+    }
+
+    return result;
+}
+/*  End of convergents.                                                       */
+
+/*  Main routine used for testing the continued fraction functions.           */
+fn main() {
+
+    /*  The expansion of pi begins [3; 7, 15, 1, 292, 1, 1, ...]. The large   *
+     *  coefficient 292 is why 355/113 is such a good approximation.          */
+    let pi: f64 = std::f64::consts::PI;
+    let coefficients: Vec<i64> = continued_fraction(pi, 8);
+    println!("pi = {:?}", coefficients);
+
+    /*  Print the convergents and their errors. Counting from c_0 = 3/1, the  *
+     *  third convergent is c_3 = 355/113.                                    */
+    for (n, &(p, q)) in convergents(&coefficients).iter().enumerate() {
+        let error: f64 = (p as f64 / q as f64 - pi).abs();
+        println!("c_{} = {}/{}, error = {:.3E}", n, p, q, error);
+    }
+
+    /*  The golden ratio (1 + sqrt(5)) / 2 = [1; 1, 1, 1, ...], and its       *
+     *  convergents are ratios of consecutive Fibonacci numbers.              */
+    let phi: f64 = 0.5 * (1.0 + 5.0f64.sqrt());
+    println!("phi = {:?}", continued_fraction(phi, 10));
+
+    /*  Rational numbers have finite expansions. 3.25 = 3 + 1/4 = [3; 4] and  *
+     *  -1.75 = -2 + 1/4 = [-2; 4]. These are exactly representable, so no    *
+     *  rounding error creeps into the expansion.                             */
+    println!("3.25 = {:?}", continued_fraction(3.25, 10));
+    println!("-1.75 = {:?}", continued_fraction(-1.75, 10));
+
+    /*  Rounding error makes the later coefficients of 123456.789 huge, and   *
+     *  the convergents overflow. They are cut off instead. 1E20 does not     *
+     *  fit in an i64 at all, so its expansion is empty.                      */
+    let coefficients: Vec<i64> = continued_fraction(123456.789, 20);
+    println!("123456.789 = {:?}", coefficients);
+    println!("convergents: {:?}", convergents(&coefficients));
+    println!("1E20 = {:?}", continued_fraction(1.0E20, 20));
+}
@@ -0,0 +1,157 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Lists the divisors of an integer and counts them.                     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes all divisors of n, in increasing order.                          */
+fn divisors(n: u64) -> Vec<u64> {
+
+    /*  Every positive integer divides 0, so there is no finite list. By      *
+     *  convention we return an empty list.                                   */
+    if n == 0 {
+        return Vec::new();
+    }
+
+    /*  Divisors come in pairs d and n / d with d <= sqrt(n). The small       *
+     *  divisors are found in increasing order, and their partners n / d are  *
+     *  found in decreasing order. Store these separately and join them at the*
+     *  end. The test d <= n / d is used rather than d * d <= n, since d * d  *
+     *  overflows for n close to 2^64.                                        */
+    let mut small: Vec<u64> = Vec::new();
+    let mut large: Vec<u64> = Vec::new();
+    let mut divisor: u64 = 1;
+
+    while divisor <= n / divisor {
+
+        if n % divisor == 0 {
+            small.push(divisor);
+
+            /*  Avoid listing sqrt(n) twice when n is a perfect square.       */
+            if n / divisor != divisor {
+                large.push(n / divisor);
+            }
+        }
+
+        divisor = divisor + 1;
+    }
+
+    /*  The large divisors were found in decreasing order. Reverse them.      */
+    large.reverse();
+    small.extend(large);
+    return small;
+}
+/*  End of divisors.                                                          */
+
+/*  Counts the divisors of n without storing them.                            */
+fn divisor_count(n: u64) -> u64 {
+
+    /*  Same convention as above, 0 returns zero.                             */
+    if n == 0 {
+        return 0;
+    }
+
+    let mut count: u64 = 0;
+    let mut divisor: u64 = 1;
+
+    /*  Same pairing trick. Each d < sqrt(n) gives two divisors, d and n / d, *
+     *  and d = sqrt(n) gives one.                                            */
+    while divisor <= n / divisor {
+
+        if n % divisor == 0 {
+            if n / divisor == divisor {
+                count = count + 1;
+            } else {
+                count = count + 2;
+            }
+        }
+
+        divisor = divisor + 1;
+    }
+
+    return count;
+}
+/*  End of divisor_count.                                                     */
+
+/*  Counts divisors using the prime factorization. If n = p_1^e_1 ... p_k^e_k,*
+ *  a divisor is p_1^f_1 ... p_k^f_k with 0 <= f_i <= e_i. There are e_i + 1  *
+ *  choices for each f_i, so the count is (e_1 + 1) ... (e_k + 1).            */
+fn divisor_count_from_factorization(n: u64) -> u64 {
+
+    if n == 0 {
+        return 0;
+    }
+
+    let mut remaining: u64 = n;
+    let mut count: u64 = 1;
+    let mut prime: u64 = 2;
+
+    /*  Trial division by increasing factors. Every factor found this way is  *
+     *  prime since the smaller primes have already been divided out.         */
+    while prime <= remaining / prime {
+        let mut exponent: u64 = 0;
+
+        while remaining % prime == 0 {
+            remaining = remaining / prime;
+            exponent = exponent + 1;
+        }
+
+        count = count * (exponent + 1);
+        prime = prime + 1;
+    }
+
+    /*  Whatever remains, if not 1, is a prime with exponent 1.               */
+    if remaining > 1 {
+        count = count * 2;
+    }
+
+    return count;
+}
+/*  End of divisor_count_from_factorization.                                  */
+
+/*  Main routine used for testing the divisor functions.                      */
+fn main() {
+
+    /*  The divisors of 12 are 1, 2, 3, 4, 6, and 12.                         */
+    println!("divisors(12) = {:?}", divisors(12));
+    println!("divisors(36) = {:?}", divisors(36));
+    println!("divisors(0) = {:?}", divisors(0));
+
+    /*  2*3*5*7*11 = 2310 has five distinct prime factors, each with exponent *
+     *  1, so it has 2^5 = 32 divisors.                                       */
+    let n: u64 = 2 * 3 * 5 * 7 * 11;
+    println!("divisor_count({}) = {}", n, divisor_count(n));
+    println!("Product formula: {}", divisor_count_from_factorization(n));
+
+    /*  Compare the three methods on many inputs.                             */
+    let mut all_agree: bool = true;
+
+    for m in 0 .. 5000 {
+        let count: u64 = divisor_count(m);
+        all_agree = all_agree
+                 && count == divisors(m).len() as u64
+                 && count == divisor_count_from_factorization(m);
+    }
+
+    println!("All counts agree for n < 5000: {}", all_agree);
+}
@@ -0,0 +1,102 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the Farey sequence of order n.                               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The Farey sequence of order n is the list of reduced fractions in [0, 1]  *
+ *  with denominator at most n, in increasing order. Fractions are stored as  *
+ *  (numerator, denominator) pairs.                                           */
+fn farey(n: u64) -> Vec<(u64, u64)> {
+
+    /*  There are no fractions with denominator at most zero. Return empty.   */
+    if n == 0 {
+        return Vec::new();
+    }
+
+    /*  The first two terms are always 0/1 and 1/n.                           */
+    let (mut a, mut b, mut c, mut d): (u64, u64, u64, u64) = (0, 1, 1, n);
+    let mut sequence: Vec<(u64, u64)> = vec![(a, b)];
+
+    /*  If a/b and c/d are neighbors, the next term is p/q with:              *
+     *                                                                        *
+     *        p = k*c - a, q = k*d - b, k = floor((n + b) / d)                *
+     *                                                                        *
+     *  This comes from the mediant property: c/d is the mediant of its       *
+     *  neighbors, (a + p) / (b + q), with q chosen as large as possible. This*
+     *  generates the sequence in order, without creating and then filtering  *
+     *  every fraction.                                                       */
+    loop {
+        sequence.push((c, d));
+
+        let k: u64 = (n + b) / d;
+        let p: u64 = k * c - a;
+        let q: u64 = k * d - b;
+
+        a = c;
+        b = d;
+        c = p;
+        d = q;
+
+        /*  The last term is 1/1. Stop once we have added it.                 */
+        if a == 1 && b == 1 {
+            break;
+        }
+    }
+
+    return sequence;
+}
+/*  End of farey.                                                             */
+
+/*  Main routine used for testing the Farey sequence.                         */
+fn main() {
+
+    /*  The Farey sequence of order 5 has 11 terms:                           *
+     *        0/1, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1/1           */
+    let sequence: Vec<(u64, u64)> = farey(5);
+    let expected: [(u64, u64); 11] = [
+        (0, 1), (1, 5), (1, 4), (1, 3), (2, 5), (1, 2),
+        (3, 5), (2, 3), (3, 4), (4, 5), (1, 1)
+    ];
+
+    for &(p, q) in sequence.iter() {
+        print!("{}/{} ", p, q);
+    }
+
+    println!();
+    println!("Matches expected terms: {}", sequence[..] == expected[..]);
+
+    /*  Consecutive terms a/b < c/d in any Farey sequence satisfy b*c - a*d = *
+     *  1. Check this for a larger order.                                     */
+    let big: Vec<(u64, u64)> = farey(100);
+    let mut neighbors: bool = true;
+
+    for index in 1 .. big.len() {
+        let (a, b): (u64, u64) = big[index - 1];
+        let (c, d): (u64, u64) = big[index];
+        neighbors = neighbors && b * c == a * d + 1;
+    }
+
+    println!("Order 100 has {} terms", big.len());
+    println!("Neighbor property holds: {}", neighbors);
+}
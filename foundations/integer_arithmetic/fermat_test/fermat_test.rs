@@ -0,0 +1,215 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The Fermat primality test, and why Carmichael numbers defeat it.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform integer in [0, n), n > 0. Taking next_u64() % n     *
+     *  directly favors small remainders, since 2^64 is not a multiple of n.  *
+     *  Draws from the incomplete block at the top of the range are rejected, *
+     *  which happens with probability less than n / 2^64.                    */
+    fn next_below(&mut self, n: u64) -> u64 {
+        let limit: u64 = u64::MAX - u64::MAX % n;
+
+        loop {
+            let x: u64 = self.next_u64();
+
+            if x < limit {
+                return x % n;
+            }
+        }
+    }
+}
+
+/*  Computes base^exponent mod modulus by repeated squaring. Products of two  *
+ *  values below the modulus are formed in u128, so any u64 modulus works.    */
+fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let m: u128 = modulus as u128;
+    let mut result: u128 = 1 % m;
+    let mut square: u128 = base as u128 % m;
+    let mut e: u64 = exponent;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            result = (result * square) % m;
+        }
+
+        square = (square * square) % m;
+        e = e >> 1;
+    }
+
+    return result as u64;
+}
+/*  End of mod_pow.                                                           */
+
+/*  Fermat's little theorem says a^(n - 1) = 1 mod n whenever n is prime and  *
+ *  a is not a multiple of n. The Fermat test picks random bases a in [2, n - *
+ *  2] and checks this. If it fails for any base, n is certainly composite.   *
+ *  If it holds for every base, n is declared probably prime.                 *
+ *                                                                            *
+ *  The converse of the theorem is false. A Carmichael number is a composite  *
+ *  n with a^(n - 1) = 1 mod n for every a coprime to n. The smallest is 561  *
+ *  = 3 x 11 x 17. For these, the test only catches n when it happens to pick *
+ *  a base sharing a factor with n, and when all prime factors are large that *
+ *  almost never happens. There are infinitely many Carmichael numbers, so no *
+ *  number of rounds makes the test reliable. This is the motivation for the  *
+ *  Miller-Rabin test below.                                                  */
+fn fermat_test(n: u64, rounds: u32, rng: &mut Xorshift64) -> bool {
+    if n < 4 {
+        return n == 2 || n == 3;
+    }
+
+    for _ in 0 .. rounds {
+        let a: u64 = 2 + rng.next_below(n - 3);
+
+        if mod_pow(a, n - 1, n) != 1 {
+            return false;
+        }
+    }
+
+    return true;
+}
+/*  End of fermat_test.                                                       */
+
+/*  The Miller-Rabin test. Write n - 1 = 2^s d with d odd. If n is prime, the *
+ *  only square roots of 1 mod n are 1 and -1, so the sequence a^d, a^2d,     *
+ *  ..., a^(n - 1) either starts at 1 or reaches -1 before it reaches 1. A    *
+ *  composite n, Carmichael or not, fails this for at least 3/4 of all bases, *
+ *  so each round cuts the chance of a wrong answer by a factor of 4.         */
+fn miller_rabin(n: u64, rounds: u32, rng: &mut Xorshift64) -> bool {
+    if n < 4 {
+        return n == 2 || n == 3;
+    }
+
+    if n % 2 == 0 {
+        return false;
+    }
+
+    let mut d: u64 = n - 1;
+    let mut s: u32 = 0;
+
+    while d % 2 == 0 {
+        d = d / 2;
+        s = s + 1;
+    }
+
+    'rounds: for _ in 0 .. rounds {
+        let a: u64 = 2 + rng.next_below(n - 3);
+        let mut x: u64 = mod_pow(a, d, n);
+
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        for _ in 1 .. s {
+            x = ((x as u128 * x as u128) % n as u128) as u64;
+
+            if x == n - 1 {
+                continue 'rounds;
+            }
+        }
+
+        return false;
+    }
+
+    return true;
+}
+/*  End of miller_rabin.                                                      */
+
+/*  Main routine used for testing the two primality tests.                    */
+fn main() {
+    let mut rng: Xorshift64 = Xorshift64::new(561);
+
+    /*  Every base coprime to 561 passes the Fermat check.                    */
+    let mut coprime: u32 = 0;
+    let mut passing: u32 = 0;
+
+    for a in 2 .. 560 {
+        if a % 3 != 0 && a % 11 != 0 && a % 17 != 0 {
+            coprime = coprime + 1;
+
+            if mod_pow(a, 560, 561) == 1 {
+                passing = passing + 1;
+            }
+        }
+    }
+
+    println!("561 = 3 x 11 x 17");
+    println!("    bases coprime to 561: {}, passing Fermat: {}",
+             coprime, passing);
+
+    println!("    Miller-Rabin, 20 rounds: {}",
+             miller_rabin(561, 20, &mut rng));
+
+    /*  A Carmichael number with three large prime factors. Only about 0.7%   *
+     *  of bases share a factor with it, so 20 Fermat rounds usually pass.    */
+    let n: u64 = 211 * 421 * 631;
+    println!("{} = 211 x 421 x 631", n);
+    println!("    Fermat, 20 rounds: {}", fermat_test(n, 20, &mut rng));
+    println!("    Miller-Rabin, 20 rounds: {}", miller_rabin(n, 20, &mut rng));
+
+    /*  Both tests accept primes, including the Mersenne prime 2^61 - 1.      */
+    let p: u64 = (1 << 61) - 1;
+    println!("2^61 - 1");
+    println!("    Fermat, 20 rounds: {}", fermat_test(p, 20, &mut rng));
+    println!("    Miller-Rabin, 20 rounds: {}", miller_rabin(p, 20, &mut rng));
+
+    /*  Check the two tests against trial division for small n.               */
+    let mut agree: bool = true;
+
+    for n in 2 .. 2000 {
+        let prime: bool = (2 .. n).take_while(|k| k * k <= n)
+                                  .all(|k| n % k != 0);
+        agree = agree && miller_rabin(n, 20, &mut rng) == prime;
+    }
+
+    println!("Miller-Rabin agrees with trial division below 2000: {}", agree);
+}
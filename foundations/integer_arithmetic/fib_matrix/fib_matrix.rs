@@ -0,0 +1,116 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Fibonacci numbers in O(log n) steps by matrix exponentiation.         *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  2x2 matrices of integers, stored as rows.                                 */
+type Matrix = [[u64; 2]; 2];
+
+/*  Computes the product AB of 2x2 matrices. Arithmetic wraps mod 2^64.       */
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut product: Matrix = [[0, 0], [0, 0]];
+
+    for i in 0 .. 2 {
+        for j in 0 .. 2 {
+            let first: u64 = a[i][0].wrapping_mul(b[0][j]);
+            let second: u64 = a[i][1].wrapping_mul(b[1][j]);
+            product[i][j] = first.wrapping_add(second);
+        }
+    }
+
+    return product;
+}
+/*  End of multiply.                                                          */
+
+/*  Computes F(n) the usual way, one addition per step. u64 holds up to F(93) *
+ *  = 12200160415121876738, and F(94) overflows. wrapping_add is used so that *
+ *  larger n give F(n) mod 2^64 instead of stopping the program.              */
+fn fibonacci(n: u64) -> u64 {
+    let mut previous: u64 = 0;
+    let mut current: u64 = 1;
+
+    if n == 0 {
+        return 0;
+    }
+
+    for _ in 1 .. n {
+        let next: u64 = previous.wrapping_add(current);
+        previous = current;
+        current = next;
+    }
+
+    return current;
+}
+/*  End of fibonacci.                                                         */
+
+/*  Computes F(n) by matrix exponentiation. The recurrence F(k + 1) = F(k) +  *
+ *  F(k - 1) is a matrix product, and applying it n times gives               *
+ *                                                                            *
+ *        -      -n     -                -                                    *
+ *        | 1  1 |      | F(n + 1)  F(n) |                                    *
+ *        | 1  0 |   =  | F(n)  F(n - 1) |                                    *
+ *        -      -      -                -                                    *
+ *                                                                            *
+ *  The n-th power is computed by repeated squaring, writing n in binary, so  *
+ *  only O(log n) matrix products are needed instead of n additions. Past     *
+ *  F(93) the entries wrap around, and the result is F(n) mod 2^64. Since     *
+ *  reduction mod 2^64 respects sums and products, this is still the correct  *
+ *  residue.                                                                  */
+fn fibonacci_fast(n: u64) -> u64 {
+    let mut result: Matrix = [[1, 0], [0, 1]];
+    let mut square: Matrix = [[1, 1], [1, 0]];
+    let mut e: u64 = n;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            result = multiply(&result, &square);
+        }
+
+        square = multiply(&square, &square);
+        e = e >> 1;
+    }
+
+    return result[0][1];
+}
+/*  End of fibonacci_fast.                                                    */
+
+/*  Main routine used for testing the matrix method.                          */
+fn main() {
+    println!("F(10) = {}", fibonacci_fast(10));
+    println!("F(93) = {}", fibonacci_fast(93));
+
+    /*  Exact agreement while the values fit in a u64.                        */
+    let exact: bool = (0 .. 94).all(|n| fibonacci_fast(n) == fibonacci(n));
+    println!("matches the iterative method for n <= 93: {}", exact);
+
+    /*  Past F(93) both methods give F(n) mod 2^64, and must still agree.     */
+    let wrapped: bool =
+        (94 .. 5000).all(|n| fibonacci_fast(n) == fibonacci(n));
+
+    println!("agrees mod 2^64 for 94 <= n < 5000: {}", wrapped);
+
+    /*  F(2^62) mod 2^64 takes about 62 squarings, where the iterative method *
+     *  would need 2^62 additions.                                            */
+    println!("F(2^62) mod 2^64 = {}", fibonacci_fast(1 << 62));
+}
@@ -0,0 +1,250 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Exact Fibonacci numbers of any size by fast doubling.                 *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Arbitrary precision unsigned integers. The number is stored in base 2^32, *
+ *  least significant digit (limb) first, with no leading zero limbs. Zero is *
+ *  the empty vector. 32-bit limbs are used so that the product of two limbs, *
+ *  plus a carry, fits in a u64.                                              */
+#[derive(Clone, Debug, PartialEq)]
+struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+
+    /*  Creates a BigUint from a u64, which needs at most two limbs.          */
+    fn from_u64(n: u64) -> BigUint {
+        let mut result: BigUint = BigUint {
+            limbs: vec![n as u32, (n >> 32) as u32]
+        };
+
+        result.normalize();
+        return result;
+    }
+    /*  End of from_u64.                                                      */
+
+    /*  Removes leading zero limbs so that each number has one                *
+     *  representation.                                                       */
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+    /*  End of normalize.                                                     */
+
+    /*  Computes self + other, limb by limb with a carry, as by hand.         */
+    fn add(&self, other: &BigUint) -> BigUint {
+        let length: usize = self.limbs.len().max(other.limbs.len());
+        let mut limbs: Vec<u32> = Vec::with_capacity(length + 1);
+        let mut carry: u64 = 0;
+
+        for k in 0 .. length {
+            let x: u64 = *self.limbs.get(k).unwrap_or(&0) as u64;
+            let y: u64 = *other.limbs.get(k).unwrap_or(&0) as u64;
+            let sum: u64 = x + y + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        limbs.push(carry as u32);
+
+        let mut result: BigUint = BigUint { limbs: limbs };
+        result.normalize();
+        return result;
+    }
+    /*  End of add.                                                           */
+
+    /*  Computes self - other, assuming self >= other, borrowing from the     *
+     *  next limb when a digit would go negative.                             */
+    fn sub(&self, other: &BigUint) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::with_capacity(self.limbs.len());
+        let mut borrow: i64 = 0;
+
+        for k in 0 .. self.limbs.len() {
+            let x: i64 = self.limbs[k] as i64;
+            let y: i64 = *other.limbs.get(k).unwrap_or(&0) as i64;
+            let mut difference: i64 = x - y - borrow;
+
+            if difference < 0 {
+                difference = difference + (1 << 32);
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+
+            limbs.push(difference as u32);
+        }
+
+        assert!(borrow == 0, "BigUint::sub: negative result.");
+
+        let mut result: BigUint = BigUint { limbs: limbs };
+        result.normalize();
+        return result;
+    }
+    /*  End of sub.                                                           */
+
+    /*  Computes self * other with the schoolbook method, O(mn) for numbers   *
+     *  with m and n limbs. Each partial product x y + limb + carry is at     *
+     *  most (2^32 - 1)^2 + 2 (2^32 - 1) = 2^64 - 1, so it never overflows a  *
+     *  u64.                                                                  */
+    fn mul(&self, other: &BigUint) -> BigUint {
+        if self.limbs.is_empty() || other.limbs.is_empty() {
+            return BigUint { limbs: Vec::new() };
+        }
+
+        let mut limbs: Vec<u32> = vec![0; self.limbs.len() + other.limbs.len()];
+
+        for i in 0 .. self.limbs.len() {
+            let mut carry: u64 = 0;
+            let x: u64 = self.limbs[i] as u64;
+
+            for j in 0 .. other.limbs.len() {
+                let y: u64 = other.limbs[j] as u64;
+                let total: u64 = x * y + limbs[i + j] as u64 + carry;
+                limbs[i + j] = total as u32;
+                carry = total >> 32;
+            }
+
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        let mut result: BigUint = BigUint { limbs: limbs };
+        result.normalize();
+        return result;
+    }
+    /*  End of mul.                                                           */
+
+    /*  Converts to a decimal string. Dividing by 10^9 repeatedly peels off   *
+     *  nine decimal digits at a time, starting from the least significant.   */
+    fn to_decimal(&self) -> String {
+        if self.limbs.is_empty() {
+            return String::from("0");
+        }
+
+        let mut limbs: Vec<u32> = self.limbs.clone();
+        let mut chunks: Vec<u32> = Vec::new();
+
+        while !limbs.is_empty() {
+            let mut remainder: u64 = 0;
+
+            /*  Long division by 10^9, from the most significant limb down.   */
+            for k in (0 .. limbs.len()).rev() {
+                let current: u64 = (remainder << 32) | limbs[k] as u64;
+                limbs[k] = (current / 1000000000) as u32;
+                remainder = current % 1000000000;
+            }
+
+            chunks.push(remainder as u32);
+
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+
+        /*  The leading chunk is printed as is, the rest padded to 9 digits.  */
+        let mut digits: String = format!("{}", chunks[chunks.len() - 1]);
+
+        for k in (0 .. chunks.len() - 1).rev() {
+            digits.push_str(&format!("{:09}", chunks[k]));
+        }
+
+        return digits;
+    }
+    /*  End of to_decimal.                                                    */
+}
+
+/*  Computes F(n) iteratively. Valid for n <= 93, after which u64 overflows.  */
+fn fibonacci(n: u64) -> u64 {
+    let mut previous: u64 = 0;
+    let mut current: u64 = 1;
+
+    if n == 0 {
+        return 0;
+    }
+
+    for _ in 1 .. n {
+        let next: u64 = previous + current;
+        previous = current;
+        current = next;
+    }
+
+    return current;
+}
+/*  End of fibonacci.                                                         */
+
+/*  Computes F(n) exactly with the fast doubling identities:                  *
+ *                                                                            *
+ *        F(2k)     = F(k) (2 F(k + 1) - F(k))                                *
+ *        F(2k + 1) = F(k)^2 + F(k + 1)^2                                     *
+ *                                                                            *
+ *  Starting from (F(0), F(1)) = (0, 1), the bits of n are read from most to  *
+ *  least significant. Each bit doubles k, and a 1 bit then steps to k + 1,   *
+ *  so about log2(n) doublings are needed. These follow from the matrix form  *
+ *  of the recurrence, squaring [[1, 1], [1, 0]]^k, but with half the         *
+ *  multiplications. Since F(k + 1) >= F(k), the subtraction never goes       *
+ *  negative.                                                                 */
+fn fibonacci_big(n: u64) -> BigUint {
+    let mut a: BigUint = BigUint::from_u64(0);
+    let mut b: BigUint = BigUint::from_u64(1);
+
+    for bit in (0 .. 64 - n.leading_zeros()).rev() {
+
+        /*  (a, b) = (F(k), F(k + 1)). Compute F(2k) and F(2k + 1).           */
+        let two_b: BigUint = b.add(&b);
+        let even: BigUint = a.mul(&two_b.sub(&a));
+        let odd: BigUint = a.mul(&a).add(&b.mul(&b));
+
+        if (n >> bit) & 1 == 1 {
+            b = even.add(&odd);
+            a = odd;
+        } else {
+            a = even;
+            b = odd;
+        }
+    }
+
+    return a;
+}
+/*  End of fibonacci_big.                                                     */
+
+/*  Main routine used for testing the fast doubling method.                   */
+fn main() {
+    let f100: String = fibonacci_big(100).to_decimal();
+    println!("F(100) = {}", f100);
+    println!("correct: {}", f100 == "354224848179261915075");
+
+    /*  Agreement with the iterative method while F(n) fits in a u64.         */
+    let agree: bool = (0 .. 94).all(|n| {
+        return fibonacci_big(n) == BigUint::from_u64(fibonacci(n));
+    });
+
+    println!("agrees with the iterative method for n <= 93: {}", agree);
+
+    /*  F(1000) has 209 digits.                                               */
+    let f1000: String = fibonacci_big(1000).to_decimal();
+    println!("F(1000) has {} digits, starting {}",
+             f1000.len(), &f1000[0 .. 20]);
+}
@@ -0,0 +1,172 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A small type for arithmetic modulo n, avoiding overflow with u128.    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  An integer mod n. The value is always kept reduced, 0 <= value < modulus. *
+ *  Products of two u64 values can be as large as 2^128, so intermediate      *
+ *  results are computed with u128 and then reduced.                          */
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+
+    /*  Creates value mod modulus. The modulus must be positive.              */
+    fn new(value: u64, modulus: u64) -> ModInt {
+        assert!(modulus > 0, "ModInt: modulus must be positive.");
+        return ModInt { value: value % modulus, modulus: modulus };
+    }
+    /*  End of new.                                                           */
+
+    /*  Reduces a u128 back to a ModInt with the same modulus.                */
+    fn reduce(&self, x: u128) -> ModInt {
+        let value: u64 = (x % self.modulus as u128) as u64;
+        return ModInt { value: value, modulus: self.modulus };
+    }
+    /*  End of reduce.                                                        */
+
+    /*  Computes self + other. The sum of two values below 2^64 may not fit   *
+     *  in a u64, but always fits in a u128.                                  */
+    fn add(&self, other: ModInt) -> ModInt {
+        assert_eq!(self.modulus, other.modulus);
+        return self.reduce(self.value as u128 + other.value as u128);
+    }
+    /*  End of add.                                                           */
+
+    /*  Computes self - other. Adding the modulus first keeps the difference  *
+     *  non-negative, so unsigned arithmetic can be used.                     */
+    fn sub(&self, other: ModInt) -> ModInt {
+        assert_eq!(self.modulus, other.modulus);
+        let shifted: u128 = self.value as u128 + self.modulus as u128;
+        return self.reduce(shifted - other.value as u128);
+    }
+    /*  End of sub.                                                           */
+
+    /*  Computes self * other. The product is less than 2^128.                */
+    fn mul(&self, other: ModInt) -> ModInt {
+        assert_eq!(self.modulus, other.modulus);
+        return self.reduce(self.value as u128 * other.value as u128);
+    }
+    /*  End of mul.                                                           */
+
+    /*  Computes self^exponent by repeated squaring. Writing the exponent in  *
+     *  binary, e = sum b_k 2^k, we have x^e = prod x^(b_k 2^k), and the      *
+     *  factors x^(2^k) are obtained by squaring. This needs about log2(e)    *
+     *  multiplications instead of e.                                         */
+    fn pow(&self, exponent: u64) -> ModInt {
+        let mut result: ModInt = ModInt::new(1, self.modulus);
+        let mut square: ModInt = *self;
+        let mut e: u64 = exponent;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul(square);
+            }
+
+            square = square.mul(square);
+            e = e >> 1;
+        }
+
+        return result;
+    }
+    /*  End of pow.                                                           */
+
+    /*  Computes the multiplicative inverse, assuming the modulus is a prime  *
+     *  p. By Fermat's little theorem x^(p - 1) = 1 mod p for x not divisible *
+     *  by p, so x^(p - 2) is the inverse. Zero has no inverse and None is    *
+     *  returned. For composite moduli the result is meaningless.             */
+    fn inverse(&self) -> Option<ModInt> {
+        if self.value == 0 {
+            return None;
+        }
+
+        return Some(self.pow(self.modulus - 2));
+    }
+    /*  End of inverse.                                                       */
+}
+
+/*  Computes base^exponent mod modulus directly with u128 arithmetic, one     *
+ *  multiplication at a time. Slow, but simple enough to check pow against.   */
+fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result: u128 = 1 % modulus as u128;
+
+    for _ in 0 .. exponent {
+        result = (result * base as u128) % modulus as u128;
+    }
+
+    return result as u64;
+}
+/*  End of mod_pow.                                                           */
+
+/*  A short program for testing our functions.                                */
+fn main() {
+
+    /*  A large prime, 2^61 - 1. Sums of residues still fit in a u64, but     *
+     *  products, near 2^122, need the u128 arithmetic.                       */
+    let p: u64 = (1 << 61) - 1;
+    let a: ModInt = ModInt::new(p - 3, p);
+    let b: ModInt = ModInt::new(5, p);
+
+    /*  Wrapping: (p - 3) + 5 = 2, and 5 - (p - 3) = 8, both mod p.           */
+    println!("(p - 3) + 5 = {} mod p", a.add(b).value);
+    println!("5 - (p - 3) = {} mod p", b.sub(a).value);
+    let wraps: bool = a.add(b).value == 2 && b.sub(a).value == 8;
+    println!("add and sub wrap: {}", wraps);
+
+    /*  (p - 3)^2 = 9 mod p. The true product is about 2^122.                 */
+    println!("(p - 3)^2 = {} mod p", a.mul(a).value);
+    println!("mul correct: {}", a.mul(a).value == 9);
+
+    /*  pow agrees with the slow method on a small modulus.                   */
+    let mut agrees: bool = true;
+
+    for base in 0 .. 20 {
+        for exponent in 0 .. 50 {
+            let x: ModInt = ModInt::new(base, 1009);
+            let expected: u64 = mod_pow(base, exponent, 1009);
+            agrees = agrees && x.pow(exponent).value == expected;
+        }
+    }
+
+    println!("pow matches mod_pow: {}", agrees);
+
+    /*  Every nonzero element times its inverse is 1.                         */
+    let mut inverses: bool = true;
+
+    for value in 1 .. 1009 {
+        let x: ModInt = ModInt::new(value, 1009);
+        let y: ModInt = x.inverse().unwrap();
+        inverses = inverses && x.mul(y).value == 1;
+    }
+
+    let c: ModInt = ModInt::new(123456789, p);
+    let product: ModInt = c.mul(c.inverse().unwrap());
+    inverses = inverses && product.value == 1;
+
+    println!("a * a^-1 == 1 mod p: {}", inverses);
+    println!("inverse of 0: {:?}", ModInt::new(0, p).inverse());
+}
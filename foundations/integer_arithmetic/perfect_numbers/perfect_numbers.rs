@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Classifies positive integers as perfect, abundant, or deficient.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  A positive integer n is perfect if the sum of its proper divisors (the    *
+ *  divisors less than n) equals n, abundant if the sum is bigger, and        *
+ *  deficient if the sum is smaller.                                          */
+#[derive(Debug, PartialEq)]
+enum Classification {
+    Perfect,
+    Abundant,
+    Deficient
+}
+
+/*  Computes the sum of the proper divisors of n using trial division. The    *
+ *  sum can be larger than n, and for n close to 2^64 it may not fit in a     *
+ *  u64. None is returned in this case.                                       */
+fn sum_of_proper_divisors(n: u64) -> Option<u64> {
+
+    /*  Convention: 0 and 1 have no proper divisors, so the sum is zero. For 0*
+     *  this is a choice, since every positive integer divides 0, but with it *
+     *  0 and 1 are both classified as deficient.                             */
+    if n < 2 {
+        return Some(0);
+    }
+
+    /*  1 divides every integer, and is a proper divisor for n > 1.           */
+    let mut sum: u64 = 1;
+
+    /*  Divisors come in pairs d and n / d, one of which is at most sqrt(n).  *
+     *  We only need to check d with d*d <= n, written as d <= n / d since    *
+     *  d*d overflows for n close to 2^64.                                    */
+    let mut divisor: u64 = 2;
+
+    while divisor <= n / divisor {
+
+        if n % divisor == 0 {
+            let pair: u64 = n / divisor;
+            sum = sum.checked_add(divisor)?;
+
+            /*  If n is a perfect square, d = n / d for d = sqrt(n). Avoid    *
+             *  counting this divisor twice.                                  */
+            if pair != divisor {
+                sum = sum.checked_add(pair)?;
+            }
+        }
+
+        divisor = divisor + 1;
+    }
+
+    return Some(sum);
+}
+/*  End of sum_of_proper_divisors.                                            */
+
+/*  Determines if n is perfect, abundant, or deficient.                       */
+fn classify(n: u64) -> Classification {
+
+    /*  If the sum overflows it is certainly bigger than n.                   */
+    let sum: u64 = match sum_of_proper_divisors(n) {
+        Some(sum) => sum,
+        None => return Classification::Abundant
+    };
+
+    if sum == n && n != 0 {
+        return Classification::Perfect;
+    } else if sum > n {
+        return Classification::Abundant;
+    }
+
+    return Classification::Deficient;
+}
+/*  End of classify.                                                          */
+
+/*  Main routine used for testing our classifier.                             */
+fn main() {
+
+    /*  6 = 1 + 2 + 3 and 28 = 1 + 2 + 4 + 7 + 14 are perfect, 12 is abundant *
+     *  since 1 + 2 + 3 + 4 + 6 = 16 > 12, and 8 is deficient since 1 + 2 + 4 *
+     *  = 7 < 8.                                                              */
+    let values: [u64; 6] = [0, 1, 6, 8, 12, 28];
+
+    for n in values.iter() {
+        println!("classify({}) = {:?}", n, classify(*n));
+    }
+
+    /*  Perfect numbers are rare. There are only four below 10,000.           */
+    print!("Perfect numbers below 10000:");
+
+    for n in 1 .. 10000 {
+        if classify(n) == Classification::Perfect {
+            print!(" {}", n);
+        }
+    }
+
+    println!();
+}
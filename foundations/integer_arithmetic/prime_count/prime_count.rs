@@ -0,0 +1,151 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Counting primes with Legendre's formula, without listing them all.    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The sieve of Eratosthenes. Returns a table with is_prime[k] true exactly  *
+ *  when k is prime, for 0 <= k <= n. Crossing out starts at p^2, since       *
+ *  smaller multiples of p have a smaller prime factor and were already       *
+ *  removed.                                                                  */
+fn sieve(n: usize) -> Vec<bool> {
+    let mut is_prime: Vec<bool> = vec![true; n + 1];
+    is_prime[0] = false;
+
+    if n >= 1 {
+        is_prime[1] = false;
+    }
+
+    let mut p: usize = 2;
+
+    while p * p <= n {
+        if is_prime[p] {
+            let mut multiple: usize = p * p;
+
+            while multiple <= n {
+                is_prime[multiple] = false;
+                multiple = multiple + p;
+            }
+        }
+
+        p = p + 1;
+    }
+
+    return is_prime;
+}
+/*  End of sieve.                                                             */
+
+/*  Returns floor(sqrt(n)). The floating point square root may be off by one  *
+ *  for large n, so the result is corrected with exact integer checks.        */
+fn integer_sqrt(n: u64) -> u64 {
+    let mut root: u64 = (n as f64).sqrt() as u64;
+
+    while root * root > n {
+        root = root - 1;
+    }
+
+    while (root + 1) * (root + 1) <= n {
+        root = root + 1;
+    }
+
+    return root;
+}
+/*  End of integer_sqrt.                                                      */
+
+/*  Legendre's phi function, the number of integers in [1, x] not divisible   *
+ *  by any of the first a primes. Removing the multiples of the a-th prime p  *
+ *  from those counted by phi(x, a - 1) gives the recursion                   *
+ *                                                                            *
+ *        phi(x, a) = phi(x, a - 1) - phi(floor(x / p), a - 1),               *
+ *                                                                            *
+ *  since the multiples m p <= x not divisible by the smaller primes          *
+ *  correspond to the m <= x / p not divisible by them (p itself is not one   *
+ *  of the smaller primes). phi(x, 0) = x. If x <= p then every number in [2, *
+ *  x] has a prime factor among the first a primes, leaving only 1.           */
+fn phi(x: u64, a: usize, primes: &[u64]) -> u64 {
+    if a == 0 || x == 0 {
+        return x;
+    }
+
+    let p: u64 = primes[a - 1];
+
+    if x <= p {
+        return 1;
+    }
+
+    return phi(x, a - 1, primes) - phi(x / p, a - 1, primes);
+}
+/*  End of phi.                                                               */
+
+/*  Counts the primes up to n with Legendre's formula. Let a be the number of *
+ *  primes up to sqrt(n). Every composite up to n has a prime factor no       *
+ *  bigger than sqrt(n), so the numbers counted by phi(n, a) are 1 and the    *
+ *  primes in (sqrt(n), n]. Hence                                             *
+ *                                                                            *
+ *        pi(n) = phi(n, a) + a - 1.                                          *
+ *                                                                            *
+ *  Only the primes up to sqrt(n) are needed, so memory is O(sqrt(n)) rather  *
+ *  than the O(n) of a full sieve, and the early exit in phi prunes most of   *
+ *  the 2^a terms of the inclusion-exclusion sum. The running time is then    *
+ *  sublinear in practice, and refinements of the same idea by Meissel and    *
+ *  Lehmer reach about O(n^(2/3)).                                            */
+fn prime_count(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+
+    let root: usize = integer_sqrt(n) as usize;
+    let is_prime: Vec<bool> = sieve(root);
+    let primes: Vec<u64> =
+        (2 .. root + 1).filter(|&k| is_prime[k]).map(|k| k as u64).collect();
+
+    let a: usize = primes.len();
+    return phi(n, a, &primes) + a as u64 - 1;
+}
+/*  End of prime_count.                                                       */
+
+/*  Main routine used for testing Legendre's formula against the sieve.       */
+fn main() {
+    println!("prime_count(100) = {}", prime_count(100));
+    println!("prime_count(1000) = {}", prime_count(1000));
+    println!("correct: {}", prime_count(100) == 25 && prime_count(1000) == 168);
+
+    /*  Compare with the sieve's running count for all n up to 10^4.          */
+    let limit: usize = 10000;
+    let is_prime: Vec<bool> = sieve(limit);
+    let mut count: u64 = 0;
+    let mut agree: bool = true;
+
+    for n in 0 .. limit + 1 {
+        if is_prime[n] {
+            count = count + 1;
+        }
+
+        agree = agree && prime_count(n as u64) == count;
+    }
+
+    println!("agrees with the sieve up to {}: {}", limit, agree);
+
+    /*  pi(10^9) = 50847534, computed from the 3401 primes below 31623.       */
+    println!("prime_count(10^9) = {}", prime_count(1000000000));
+}
@@ -0,0 +1,110 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Generates primitive Pythagorean triples using Euclid's formula.       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the greatest common divisor using the Euclidean algorithm.       */
+fn gcd(a: u64, b: u64) -> u64 {
+    let mut x: u64 = a;
+    let mut y: u64 = b;
+
+    /*  gcd(x, y) = gcd(y, x mod y). Repeat until the remainder is zero.      */
+    while y != 0 {
+        let remainder: u64 = x % y;
+        x = y;
+        y = remainder;
+    }
+
+    return x;
+}
+/*  End of gcd.                                                               */
+
+/*  Computes all primitive triples (a, b, c), a^2 + b^2 = c^2 with gcd(a, b,  *
+ *  c) = 1, with c <= limit. The triples are returned with a < b, sorted by   *
+ *  c.                                                                        */
+fn primitive_triples(limit: u64) -> Vec<(u64, u64, u64)> {
+
+    /*  Euclid's formula. For integers m > n > 0 we have:                     *
+     *                                                                        *
+     *        a = m^2 - n^2, b = 2mn, c = m^2 + n^2                           *
+     *                                                                        *
+     *  and a^2 + b^2 = c^2. The triple is primitive exactly when m and n are *
+     *  coprime and have opposite parity, and every primitive triple arises   *
+     *  this way exactly once.                                                */
+    let mut triples: Vec<(u64, u64, u64)> = Vec::new();
+    let mut m: u64 = 2;
+
+    /*  c = m^2 + n^2 > m^2, so once m^2 >= limit there is nothing left.      */
+    while m * m < limit {
+
+        /*  Opposite parity means m - n is odd, so n starts at 1 or 2.        */
+        let mut n: u64 = if m % 2 == 0 { 1 } else { 2 };
+
+        while n < m {
+            let c: u64 = m * m + n * n;
+
+            if c > limit {
+                break;
+            }
+
+            if gcd(m, n) == 1 {
+                let a: u64 = m * m - n * n;
+                let b: u64 = 2 * m * n;
+
+                /*  a may be larger than b. Store the legs in increasing      *
+                 *  order.                                                    */
+                triples.push((a.min(b), a.max(b), c));
+            }
+
+            n = n + 2;
+        }
+
+        m = m + 1;
+    }
+
+    /*  Sort by hypotenuse, and then by the smaller leg.                      */
+    triples.sort_by_key(|&(a, _, c)| (c, a));
+    return triples;
+}
+/*  End of primitive_triples.                                                 */
+
+/*  Main routine used for testing the generator.                              */
+fn main() {
+
+    let triples: Vec<(u64, u64, u64)> = primitive_triples(100);
+    let mut all_valid: bool = true;
+
+    /*  Print the triples and check that each is a primitive Pythagorean      *
+     *  triple.                                                               */
+    for &(a, b, c) in triples.iter() {
+        println!("({}, {}, {})", a, b, c);
+        all_valid = all_valid && a*a + b*b == c*c && gcd(gcd(a, b), c) == 1;
+    }
+
+    println!("Number of triples with c <= 100: {}", triples.len());
+    println!("All triples valid and primitive: {}", all_valid);
+    println!("Contains (3, 4, 5): {}", triples.contains(&(3, 4, 5)));
+    println!("Contains (5, 12, 13): {}", triples.contains(&(5, 12, 13)));
+    println!("Contains (6, 8, 10): {}", triples.contains(&(6, 8, 10)));
+}
@@ -0,0 +1,136 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Shows saturating arithmetic, which clamps at the limits instead of    *
+ *      wrapping.                                                             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Adds two unsigned 8-bit integers. If the true sum is larger than 255 the  *
+ *  result is clamped, or saturated, to 255 instead of wrapping back around   *
+ *  to zero as in integer_overflow.rs.                                        */
+fn add_u8(x: u8, y: u8) -> u8 {
+    return x.saturating_add(y);
+}
+/*  End of add_u8.                                                            */
+
+/*  Multiplies two unsigned 8-bit integers, saturating at 255.                */
+fn mul_u8(x: u8, y: u8) -> u8 {
+    return x.saturating_mul(y);
+}
+/*  End of mul_u8.                                                            */
+
+/*  Adds two signed 8-bit integers. Results above 127 are clamped to 127, and *
+ *  results below -128 are clamped to -128.                                   */
+fn add_i8(x: i8, y: i8) -> i8 {
+    return x.saturating_add(y);
+}
+/*  End of add_i8.                                                            */
+
+/*  Subtracts two signed 8-bit integers, saturating at -128 and 127.          */
+fn sub_i8(x: i8, y: i8) -> i8 {
+    return x.saturating_sub(y);
+}
+/*  End of sub_i8.                                                            */
+
+/*  Multiplies two signed 8-bit integers, saturating at -128 and 127. Note    *
+ *  the sign of the clamp follows the sign of the true product.               */
+fn mul_i8(x: i8, y: i8) -> i8 {
+    return x.saturating_mul(y);
+}
+/*  End of mul_i8.                                                            */
+
+/*  Formats the result of a checked operation. None means the operation       *
+ *  overflowed, which checked arithmetic reports instead of producing a       *
+ *  value.                                                                    */
+fn checked_to_string<T: std::fmt::Display>(result: Option<T>) -> String {
+    match result {
+        Some(value) => return format!("{}", value),
+        None => return String::from("overflow"),
+    }
+}
+/*  End of checked_to_string.                                                 */
+
+/*  Prints one row of the table, the same operation in all three modes.       */
+fn print_row(operation: &str, wrapping: String,
+             saturating: String, checked: String) {
+    println!("{:<16} {:>10} {:>12} {:>10}",
+             operation, wrapping, saturating, checked);
+}
+/*  End of print_row.                                                         */
+
+/*  A short program for testing our functions.                                */
+fn main() {
+
+    /*  The two examples from the text. Saturating stops at the limit.        */
+    println!("u8::MAX.saturating_add(10) == u8::MAX: {}",
+             add_u8(u8::MAX, 10) == u8::MAX);
+
+    println!("i8::MIN.saturating_sub(1) == i8::MIN: {}",
+             sub_i8(i8::MIN, 1) == i8::MIN);
+
+    println!("16 * 20 (u8) = {}", mul_u8(16, 20));
+    println!("100 + 100 (i8) = {}", add_i8(100, 100));
+    println!("-100 * 2 (i8) = {}", mul_i8(-100, 2));
+    println!();
+
+    /*  Rust has three explicit ways of handling overflow. Wrapping computes  *
+     *  the answer mod 2^N, saturating clamps to the nearest limit, and       *
+     *  checked returns None. Print them side by side.                        */
+    print_row("operation", String::from("wrapping"),
+              String::from("saturating"), String::from("checked"));
+
+    print_row("250 + 10 (u8)",
+              format!("{}", 250u8.wrapping_add(10)),
+              format!("{}", 250u8.saturating_add(10)),
+              checked_to_string(250u8.checked_add(10)));
+
+    print_row("5 - 10 (u8)",
+              format!("{}", 5u8.wrapping_sub(10)),
+              format!("{}", 5u8.saturating_sub(10)),
+              checked_to_string(5u8.checked_sub(10)));
+
+    print_row("16 * 20 (u8)",
+              format!("{}", 16u8.wrapping_mul(20)),
+              format!("{}", 16u8.saturating_mul(20)),
+              checked_to_string(16u8.checked_mul(20)));
+
+    print_row("100 + 100 (i8)",
+              format!("{}", 100i8.wrapping_add(100)),
+              format!("{}", 100i8.saturating_add(100)),
+              checked_to_string(100i8.checked_add(100)));
+
+    print_row("-128 - 1 (i8)",
+              format!("{}", i8::MIN.wrapping_sub(1)),
+              format!("{}", i8::MIN.saturating_sub(1)),
+              checked_to_string(i8::MIN.checked_sub(1)));
+
+    print_row("-100 * 2 (i8)",
+              format!("{}", (-100i8).wrapping_mul(2)),
+              format!("{}", (-100i8).saturating_mul(2)),
+              checked_to_string((-100i8).checked_mul(2)));
+
+    print_row("20 + 30 (u8)",
+              format!("{}", 20u8.wrapping_add(30)),
+              format!("{}", 20u8.saturating_add(30)),
+              checked_to_string(20u8.checked_add(30)));
+}
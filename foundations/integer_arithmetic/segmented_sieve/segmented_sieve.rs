@@ -0,0 +1,159 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A segmented sieve, finding the primes in [low, high) with little      *
+ *      memory.                                                               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The sieve of Eratosthenes. Returns a table with is_prime[k] true exactly  *
+ *  when k is prime, for 0 <= k <= n. Crossing out starts at p^2, since       *
+ *  smaller multiples of p have a smaller prime factor and were already       *
+ *  removed.                                                                  */
+fn sieve(n: usize) -> Vec<bool> {
+    let mut is_prime: Vec<bool> = vec![true; n + 1];
+    is_prime[0] = false;
+
+    if n >= 1 {
+        is_prime[1] = false;
+    }
+
+    let mut p: usize = 2;
+
+    while p * p <= n {
+        if is_prime[p] {
+            let mut multiple: usize = p * p;
+
+            while multiple <= n {
+                is_prime[multiple] = false;
+                multiple = multiple + p;
+            }
+        }
+
+        p = p + 1;
+    }
+
+    return is_prime;
+}
+/*  End of sieve.                                                             */
+
+/*  Returns floor(sqrt(n)). The floating point square root may be off by one  *
+ *  for large n, so the result is corrected with exact integer checks.        */
+fn integer_sqrt(n: u64) -> u64 {
+    let mut root: u64 = (n as f64).sqrt() as u64;
+
+    while root * root > n {
+        root = root - 1;
+    }
+
+    while (root + 1) * (root + 1) <= n {
+        root = root + 1;
+    }
+
+    return root;
+}
+/*  End of integer_sqrt.                                                      */
+
+/*  Finds the primes in [low, high). A composite m < high has a prime factor  *
+ *  p <= sqrt(m) < sqrt(high), so only the primes up to sqrt(high) are needed *
+ *  to cross out the composites in the window. These are found with an        *
+ *  ordinary sieve, using O(sqrt(high)) memory, and the window itself uses    *
+ *  O(high - low) memory. This allows finding primes near 10^12, say, where a *
+ *  full sieve would need a terabyte.                                         *
+ *                                                                            *
+ *  For each small prime p, the first multiple crossed out is max(p^2, the    *
+ *  first multiple of p that is >= low). Smaller multiples of p have a        *
+ *  smaller prime factor, and p itself must not be crossed out if it lies in  *
+ *  the window.                                                               */
+fn primes_in_range(low: u64, high: u64) -> Vec<u64> {
+    if high <= low {
+        return Vec::new();
+    }
+
+    let small_limit: usize = integer_sqrt(high - 1) as usize;
+    let small: Vec<bool> = sieve(small_limit);
+    let size: usize = (high - low) as usize;
+
+    /*  in_window[k] corresponds to the number low + k.                       */
+    let mut in_window: Vec<bool> = vec![true; size];
+
+    for p in 2 .. small_limit + 1 {
+        if !small[p] {
+            continue;
+        }
+
+        let p: u64 = p as u64;
+        let first_multiple: u64 = low.div_ceil(p) * p;
+        let mut multiple: u64 = first_multiple.max(p * p);
+
+        while multiple < high {
+            in_window[(multiple - low) as usize] = false;
+            multiple = multiple + p;
+        }
+    }
+
+    let mut primes: Vec<u64> = Vec::new();
+
+    for k in 0 .. size {
+        let n: u64 = low + k as u64;
+
+        if in_window[k] && n >= 2 {
+            primes.push(n);
+        }
+    }
+
+    return primes;
+}
+/*  End of primes_in_range.                                                   */
+
+/*  Main routine used for testing the segmented sieve.                        */
+fn main() {
+
+    /*  The primes between 10^6 and 10^6 + 100.                               */
+    let primes: Vec<u64> = primes_in_range(1000000, 1000100);
+    let known: [u64; 6] =
+        [1000003, 1000033, 1000037, 1000039, 1000081, 1000099];
+    println!("primes in [1000000, 1000100): {:?}", primes);
+    println!("matches known primes: {}", primes == known);
+
+    /*  Compare windows against a full sieve, including windows that start at *
+     *  0 or contain the small primes themselves.                             */
+    let limit: usize = 20000;
+    let full: Vec<bool> = sieve(limit);
+    let windows: [(u64, u64); 5] =
+        [(0, 100), (2, 3), (90, 400), (12345, 13579), (19000, 20001)];
+    let mut agree: bool = true;
+
+    for &(low, high) in windows.iter() {
+        let expected: Vec<u64> =
+            (low .. high).filter(|&n| full[n as usize]).collect();
+
+        agree = agree && primes_in_range(low, high) == expected;
+    }
+
+    println!("agrees with the full sieve: {}", agree);
+
+    /*  A window near 10^12, far beyond what a full sieve could store.        */
+    let low: u64 = 1000000000000;
+    println!("primes in [10^12, 10^12 + 100): {:?}",
+             primes_in_range(low, low + 100));
+}
@@ -0,0 +1,109 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Primality testing with Wilson's theorem, (n - 1)! = -1 mod n.         *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Wilson's theorem: n > 1 is prime if and only if (n - 1)! = -1 mod n. If n *
+ *  is prime, every number in 1, ..., n - 1 pairs up with its inverse mod n,  *
+ *  except 1 and n - 1 which are their own inverses, leaving 1 x (n - 1) =    *
+ *  -1. If n is composite it has a factor d with 1 < d < n, which divides     *
+ *  both (n - 1)! and n, so (n - 1)! cannot be -1 mod n (for n = 4 the        *
+ *  product is 2, for larger n it is 0).                                      *
+ *                                                                            *
+ *  The factorial is reduced mod n after every multiplication, so the values  *
+ *  never exceed n^2 and u128 products never overflow. Still, n - 2           *
+ *  multiplications are needed, far more than trial division's sqrt(n). The   *
+ *  test is hopelessly slow for large n, but is a clean illustration of the   *
+ *  theorem.                                                                  */
+fn wilson_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    let mut factorial: u128 = 1;
+
+    for k in 2 .. n {
+        factorial = (factorial * k as u128) % n as u128;
+    }
+
+    return factorial == (n - 1) as u128;
+}
+/*  End of wilson_prime.                                                      */
+
+/*  The sieve of Eratosthenes. Returns a table with is_prime[k] true exactly  *
+ *  when k is prime, for 0 <= k <= n. Crossing out starts at p^2, since       *
+ *  smaller multiples of p have a smaller prime factor and were already       *
+ *  removed.                                                                  */
+fn sieve(n: usize) -> Vec<bool> {
+    let mut is_prime: Vec<bool> = vec![true; n + 1];
+    is_prime[0] = false;
+
+    if n >= 1 {
+        is_prime[1] = false;
+    }
+
+    let mut p: usize = 2;
+
+    while p * p <= n {
+        if is_prime[p] {
+            let mut multiple: usize = p * p;
+
+            while multiple <= n {
+                is_prime[multiple] = false;
+                multiple = multiple + p;
+            }
+        }
+
+        p = p + 1;
+    }
+
+    return is_prime;
+}
+/*  End of sieve.                                                             */
+
+/*  Main routine used for testing Wilson's theorem against the sieve.         */
+fn main() {
+    let limit: usize = 100;
+    let is_prime: Vec<bool> = sieve(limit);
+    let mut agree: bool = true;
+    let mut primes: Vec<u64> = Vec::new();
+
+    for n in 0 .. limit + 1 {
+        let wilson: bool = wilson_prime(n as u64);
+        agree = agree && wilson == is_prime[n];
+
+        if wilson {
+            primes.push(n as u64);
+        }
+    }
+
+    println!("primes up to {} by Wilson's theorem: {:?}", limit, primes);
+    println!("agrees with the sieve: {}", agree);
+
+    /*  The composites are rejected, including squares of primes.             */
+    println!("wilson_prime(4) = {}", wilson_prime(4));
+    println!("wilson_prime(49) = {}", wilson_prime(49));
+    println!("wilson_prime(91) = {}", wilson_prime(91));
+    println!("wilson_prime(1000003) = {}", wilson_prime(1000003));
+}
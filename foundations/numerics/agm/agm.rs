@@ -0,0 +1,99 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The arithmetic-geometric mean of two non-negative real numbers.       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the arithmetic-geometric mean of a and b. Replace the pair by    *
+ *  its arithmetic and geometric means:                                       *
+ *                                                                            *
+ *        a_n+1 = (a_n + b_n) / 2,    b_n+1 = sqrt(a_n b_n)                   *
+ *                                                                            *
+ *  By the AM-GM inequality b_n <= b_n+1 <= a_n+1 <= a_n, and the gap shrinks *
+ *  quadratically, (a_n+1 - b_n+1) ~ (a_n - b_n)^2 / 8a, so the number of     *
+ *  correct digits doubles each step. Iterate until the two means agree to    *
+ *  within double precision epsilon. The geometric mean is not real for       *
+ *  negative inputs, NaN is returned.                                         */
+fn agm(a: f64, b: f64) -> f64 {
+
+    /*  Quadratic convergence makes this limit generous.                      */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    if a < 0.0 || b < 0.0 {
+        return (a - a) / (a - a);
+    }
+
+    /*  If either input is zero, every geometric mean is zero and the         *
+     *  arithmetic means halve forever towards zero. Return the limit         *
+     *  directly.                                                             */
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+
+    let mut arithmetic: f64 = a;
+    let mut geometric: f64 = b;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        if (arithmetic - geometric).abs() <= EPSILON * arithmetic {
+            break;
+        }
+
+        let next: f64 = 0.5 * (arithmetic + geometric);
+
+        /*  sqrt(a) sqrt(b) rather than sqrt(a b). The product overflows for  *
+         *  inputs around 1E200 and underflows for inputs around 1E-200, even *
+         *  though the mean itself is perfectly representable.                */
+        geometric = arithmetic.sqrt() * geometric.sqrt();
+        arithmetic = next;
+    }
+
+    return arithmetic;
+}
+/*  End of agm.                                                               */
+
+/*  Main routine used for testing the AGM.                                    */
+fn main() {
+
+    /*  Gauss's constant G = 1 / agm(1, sqrt(2)) = 0.83462684167407318628...  */
+    const GAUSS_CONSTANT: f64 = 0.8346268416740732;
+    let value: f64 = agm(1.0, 2.0f64.sqrt());
+    let error: f64 = (value - 1.0 / GAUSS_CONSTANT).abs();
+
+    println!("agm(1, sqrt(2)) = {:.16}, error = {:.3E}", value, error);
+
+    /*  The mean of a number with itself is that number, exactly.             */
+    let mut fixed: bool = true;
+
+    for &x in [0.0, 1.0, 0.3, 2.5E10, 7.0E-12].iter() {
+        fixed &= agm(x, x) == x;
+    }
+
+    println!("agm(x, x) == x: {}", fixed);
+    println!("agm(1, 0) = {}, agm(-1, 2) = {}", agm(1.0, 0.0), agm(-1.0, 2.0));
+
+    /*  agm(1, 2) = 1.4567910310469068..., and the AGM is homogeneous, so     *
+     *  scaling both inputs scales the mean. This works at the extremes too.  */
+    println!("agm(1E200, 2E200) = {:e}", agm(1.0E200, 2.0E200));
+    println!("agm(1E-200, 2E-200) = {:e}", agm(1.0E-200, 2.0E-200));
+}
@@ -0,0 +1,449 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the roots of a polynomial as the eigenvalues of its companion*
+ *      matrix, and compares with the Durand-Kerner method.                   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for complex arithmetic.                                         */
+use std::ops::{Add, Div, Mul, Sub};
+
+/*  Simple struct for complex numbers, z = real + i*imag.                     */
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    real: f64,
+    imag: f64
+}
+
+/*  Complex addition, done component-wise.                                    */
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag
+        };
+    }
+}
+
+/*  Complex subtraction, also done component-wise.                            */
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real - other.real,
+            imag: self.imag - other.imag
+        };
+    }
+}
+
+/*  Complex multiplication, (a + ib)(c + id) = (ac - bd) + i(ad + bc).        */
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        return Complex {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real
+        };
+    }
+}
+
+/*  Complex division, z / w = z conj(w) / |w|^2.                              */
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        let (a, b): (f64, f64) = (self.real, self.imag);
+        let (c, d): (f64, f64) = (other.real, other.imag);
+        let denominator: f64 = c*c + d*d;
+
+        return Complex {
+            real: (a*c + b*d) / denominator,
+            imag: (b*c - a*d) / denominator
+        };
+    }
+}
+
+/*  Computes the modulus |z| = sqrt(x^2 + y^2).                               */
+fn complex_abs(z: Complex) -> f64 {
+    return z.real.hypot(z.imag);
+}
+
+/*  Polynomials are stored as coefficient lists in increasing order of        *
+ *  degree, coeffs[k] being the coefficient of x^k. The leading coefficient   *
+ *  must be non-zero.                                                         */
+
+/*  Builds the companion matrix of the polynomial. Dividing by the leading    *
+ *  coefficient, p(x) = x^n + c_{n-1} x^{n-1} + ... + c_0, and the matrix is: *
+ *                                                                            *
+ *            [ -c_{n-1}  -c_{n-2}  ...  -c_1  -c_0 ]                         *
+ *            [     1         0     ...    0     0  ]                         *
+ *        C = [     0         1     ...    0     0  ]                         *
+ *            [    ...       ...    ...   ...   ... ]                         *
+ *            [     0         0     ...    1     0  ]                         *
+ *                                                                            *
+ *  Expanding det(xI - C) gives back p(x), so the eigenvalues of C are the    *
+ *  roots of p.                                                               */
+fn companion_matrix(coeffs: &[f64]) -> Vec<Vec<f64>> {
+    let n: usize = coeffs.len() - 1;
+    let leading: f64 = coeffs[n];
+    let mut matrix: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for column in 0 .. n {
+        matrix[0][column] = -coeffs[n - 1 - column] / leading;
+    }
+
+    for row in 1 .. n {
+        matrix[row][row - 1] = 1.0;
+    }
+
+    return matrix;
+}
+/*  End of companion_matrix.                                                  */
+
+/*  Applies the Householder reflection H = I - beta v v^T, v of length 2 or   *
+ *  3, to rows and columns k, ..., k + len(v) - 1 of the block low, ...,      *
+ *  high. Both sides are applied, A -> H A H, which preserves the eigenvalues *
+ *  since H is its own inverse.                                               */
+fn reflect(matrix: &mut [Vec<f64>], k: usize, v: &[f64], beta: f64,
+           low: usize, high: usize) {
+    let m: usize = v.len();
+
+    /*  H A, rows k to k + m - 1. Columns left of k - 1 are already zero.     */
+    for column in k.max(low + 1) - 1 .. high + 1 {
+        let mut dot: f64 = 0.0;
+
+        for i in 0 .. m {
+            dot = dot + v[i] * matrix[k + i][column];
+        }
+
+        for i in 0 .. m {
+            matrix[k + i][column] = matrix[k + i][column] - beta * v[i] * dot;
+        }
+    }
+
+    /*  A H, columns k to k + m - 1. Rows below k + m are zero there.         */
+    for row in low .. (k + m).min(high) + 1 {
+        let mut dot: f64 = 0.0;
+
+        for i in 0 .. m {
+            dot = dot + matrix[row][k + i] * v[i];
+        }
+
+        for i in 0 .. m {
+            matrix[row][k + i] = matrix[row][k + i] - beta * dot * v[i];
+        }
+    }
+}
+/*  End of reflect.                                                           */
+
+/*  Computes a Householder vector v and beta with (I - beta v v^T) w a        *
+ *  multiple of the first unit vector. Returns beta = 0 if w is already zero. */
+fn householder(w: &[f64]) -> (Vec<f64>, f64) {
+    let norm: f64 = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let mut v: Vec<f64> = w.to_vec();
+
+    if norm == 0.0 {
+        return (v, 0.0);
+    }
+
+    /*  Choosing the sign of alpha opposite to w[0] avoids cancellation.      */
+    let alpha: f64 = if w[0] >= 0.0 { -norm } else { norm };
+    v[0] = v[0] - alpha;
+
+    let length_squared: f64 = v.iter().map(|x| x * x).sum();
+    return (v, 2.0 / length_squared);
+}
+/*  End of householder.                                                       */
+
+/*  Performs one Francis double shift QR step on the block of rows and        *
+ *  columns low, ..., high, which must have at least 3 rows. The two shifts   *
+ *  are the roots of x^2 - sum x + product. Taking them to be the eigenvalues *
+ *  of the trailing 2x2 block, a complex pair is used as a pair of shifts     *
+ *  while all the arithmetic stays real, which a single real shift cannot do. *
+ *  Mathematically this is two QR steps, A - mu_1 I = Q_1 R_1 and so on, but  *
+ *  it is carried out implicitly. The first column of (A - mu_1 I)(A - mu_2   *
+ *  I) has only three nonzero entries, a reflection for it creates a bulge    *
+ *  below the subdiagonal, and further reflections chase the bulge down and   *
+ *  off the matrix, restoring the Hessenberg form. The result is the same     *
+ *  matrix as the explicit double step, up to signs.                          */
+fn francis_step(matrix: &mut [Vec<f64>], low: usize, high: usize,
+                sum: f64, product: f64) {
+    let (a00, a01): (f64, f64) = (matrix[low][low], matrix[low][low + 1]);
+    let (a10, a11): (f64, f64) =
+        (matrix[low + 1][low], matrix[low + 1][low + 1]);
+
+    let mut x: f64 = a00 * a00 + a01 * a10 - sum * a00 + product;
+    let mut y: f64 = a10 * (a00 + a11 - sum);
+    let mut z: f64 = a10 * matrix[low + 2][low + 1];
+
+    for k in low .. high - 1 {
+        let (v, beta): (Vec<f64>, f64) = householder(&[x, y, z]);
+        reflect(matrix, k, &v, beta, low, high);
+
+        x = matrix[k + 1][k];
+        y = matrix[k + 2][k];
+
+        if k + 3 <= high {
+            z = matrix[k + 3][k];
+        }
+    }
+
+    /*  The last reflection only involves the final two rows.                 */
+    let (v, beta): (Vec<f64>, f64) = householder(&[x, y]);
+    reflect(matrix, high - 1, &v, beta, low, high);
+}
+/*  End of francis_step.                                                      */
+
+/*  Computes the eigenvalues of the 2x2 block [[a, b], [c, d]] from its       *
+ *  characteristic polynomial x^2 - (a + d)x + (ad - bc).                     */
+fn block_eigenvalues(a: f64, b: f64, c: f64, d: f64) -> (Complex, Complex) {
+    let half_trace: f64 = 0.5 * (a + d);
+    let discriminant: f64 = 0.25 * (a - d) * (a - d) + b * c;
+
+    if discriminant >= 0.0 {
+        let root: f64 = discriminant.sqrt();
+        return (
+            Complex { real: half_trace + root, imag: 0.0 },
+            Complex { real: half_trace - root, imag: 0.0 }
+        );
+    }
+
+    let root: f64 = (-discriminant).sqrt();
+    return (
+        Complex { real: half_trace, imag: root },
+        Complex { real: half_trace, imag: -root }
+    );
+}
+/*  End of block_eigenvalues.                                                 */
+
+/*  Computes the roots of a polynomial as the eigenvalues of its companion    *
+ *  matrix using the Francis double shift QR algorithm with deflation.        *
+ *  Starting from the bottom, once the subdiagonal entry above the last row   *
+ *  is negligible, the last diagonal entry is an eigenvalue and the problem   *
+ *  shrinks by one. Once the entry above a trailing 2x2 block is negligible,  *
+ *  the block gives two eigenvalues, a complex conjugate pair or two real     *
+ *  ones, and the problem shrinks by two.                                     *
+ *                                                                            *
+ *  Unshifted QR cannot separate eigenvalues of equal modulus. For x^2 - 1    *
+ *  the companion matrix [[0, 1], [1, 0]] is a fixed point. Shifts fix this.  *
+ *  In rare symmetric cases the standard shifts also stall, so after every 10 *
+ *  steps without deflation exceptional shifts, based on the size of the last *
+ *  two subdiagonal entries, are used instead, as in EISPACK. If the          *
+ *  iteration still does not converge, the eigenvalues not yet found are      *
+ *  returned as NaN.                                                          */
+fn roots_via_companion(coeffs: &[f64]) -> Vec<Complex> {
+    const EPSILON: f64 = 1.0E-15;
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 1000;
+
+    if coeffs.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut matrix: Vec<Vec<f64>> = companion_matrix(coeffs);
+    let mut eigenvalues: Vec<Complex> = Vec::with_capacity(matrix.len());
+    let mut remaining: usize = matrix.len();
+    let mut iterations: u32 = 0;
+    let mut since_deflation: u32 = 0;
+
+    while remaining > 0 {
+        let high: usize = remaining - 1;
+
+        /*  Find the start of the unreduced block ending at row high, the     *
+         *  last row below a negligible subdiagonal entry.                    */
+        let mut low: usize = high;
+
+        while low > 0 {
+            let scale: f64 =
+                matrix[low - 1][low - 1].abs() + matrix[low][low].abs();
+
+            if matrix[low][low - 1].abs() <= EPSILON * scale {
+                matrix[low][low - 1] = 0.0;
+                break;
+            }
+
+            low = low - 1;
+        }
+
+        /*  A 1x1 block. The diagonal entry is an eigenvalue.                 */
+        if low == high {
+            eigenvalues.push(Complex { real: matrix[high][high], imag: 0.0 });
+            remaining = remaining - 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        /*  A 2x2 block. Its eigenvalues, real or complex, are eigenvalues.   */
+        if low + 1 == high {
+            let (first, second): (Complex, Complex) = block_eigenvalues(
+                matrix[low][low], matrix[low][high],
+                matrix[high][low], matrix[high][high]
+            );
+
+            eigenvalues.push(first);
+            eigenvalues.push(second);
+            remaining = remaining - 2;
+            since_deflation = 0;
+            continue;
+        }
+
+        if iterations == MAXIMUM_NUMBER_OF_ITERATIONS {
+            let nan: f64 = f64::NAN;
+
+            for _ in 0 .. remaining {
+                eigenvalues.push(Complex { real: nan, imag: nan });
+            }
+
+            return eigenvalues;
+        }
+
+        /*  The shifts are the eigenvalues of the trailing 2x2 block, given   *
+         *  by its trace and determinant.                                     */
+        let (mut sum, mut product): (f64, f64) = (
+            matrix[high - 1][high - 1] + matrix[high][high],
+            matrix[high - 1][high - 1] * matrix[high][high] -
+            matrix[high - 1][high] * matrix[high][high - 1]
+        );
+
+        /*  Exceptional shifts, x = a + 0.75 size +/- i sqrt(0.4375) size.    */
+        if since_deflation > 0 && since_deflation % 10 == 0 {
+            let size: f64 = matrix[high][high - 1].abs() +
+                            matrix[high - 1][high - 2].abs();
+
+            let center: f64 = matrix[high][high] + 0.75 * size;
+            sum = 2.0 * center;
+            product = center * center + 0.4375 * size * size;
+        }
+
+        francis_step(&mut matrix, low, high, sum, product);
+        iterations = iterations + 1;
+        since_deflation = since_deflation + 1;
+    }
+
+    return eigenvalues;
+}
+/*  End of roots_via_companion.                                               */
+
+/*  Evaluates a polynomial with real coefficients at a complex point using    *
+ *  Horner's method.                                                          */
+fn complex_horner(coeffs: &[f64], z: Complex) -> Complex {
+    let mut sum: Complex = Complex { real: 0.0, imag: 0.0 };
+
+    for coefficient in coeffs.iter().rev() {
+        sum = sum * z + Complex { real: *coefficient, imag: 0.0 };
+    }
+
+    return sum;
+}
+/*  End of complex_horner.                                                    */
+
+/*  The Durand-Kerner method. All roots are updated simultaneously by:        *
+ *                                                                            *
+ *                                p(z_i)                                      *
+ *        z_i  ->  z_i  -  -------------------                                *
+ *                          a_n prod (z_i - z_j)                              *
+ *                             j != i                                         *
+ *                                                                            */
+fn durand_kerner(coeffs: &[f64]) -> Vec<Complex> {
+    const NUMBER_OF_ITERATIONS: u32 = 500;
+
+    let n: usize = coeffs.len() - 1;
+    let leading: Complex = Complex { real: coeffs[n], imag: 0.0 };
+
+    /*  The standard starting points are powers of 0.4 + 0.9i, which is       *
+     *  neither real nor a root of unity.                                     */
+    let seed: Complex = Complex { real: 0.4, imag: 0.9 };
+    let mut roots: Vec<Complex> = Vec::with_capacity(n);
+    let mut power: Complex = Complex { real: 1.0, imag: 0.0 };
+
+    for _ in 0 .. n {
+        roots.push(power);
+        power = power * seed;
+    }
+
+    for _ in 0 .. NUMBER_OF_ITERATIONS {
+        for i in 0 .. n {
+            let mut denominator: Complex = leading;
+
+            for j in 0 .. n {
+                if i != j {
+                    denominator = denominator * (roots[i] - roots[j]);
+                }
+            }
+
+            let value: Complex = complex_horner(coeffs, roots[i]);
+            roots[i] = roots[i] - value / denominator;
+        }
+    }
+
+    return roots;
+}
+/*  End of durand_kerner.                                                     */
+
+/*  Compares the two methods on a given polynomial.                           */
+fn compare(name: &str, coeffs: &[f64]) {
+    let companion: Vec<Complex> = roots_via_companion(coeffs);
+
+    let reference: Vec<Complex> = durand_kerner(coeffs);
+    let mut agree: bool = companion.len() == reference.len();
+
+    println!("{}:", name);
+
+    /*  Match each eigenvalue with the closest Durand-Kerner root.            */
+    for z in companion.iter() {
+        let mut distance: f64 = f64::INFINITY;
+
+        for w in reference.iter() {
+            distance = distance.min(complex_abs(*z - *w));
+        }
+
+        println!("    {:+.12} {:+.12}i, distance to Durand-Kerner = {:.3E}",
+                 z.real, z.imag, distance);
+
+        agree = agree && distance < 1.0E-6;
+    }
+
+    println!("    Agrees with Durand-Kerner to 1e-6: {}", agree);
+}
+/*  End of compare.                                                           */
+
+/*  Main routine used for testing the companion matrix method.                */
+fn main() {
+
+    /*  (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6, all real roots.         */
+    compare("(x - 1)(x - 2)(x - 3)", &[-6.0, 11.0, -6.0, 1.0]);
+
+    /*  (x + 1)(x^2 - 2x + 5) = x^3 - x^2 + 3x + 5, with roots -1 and 1 +/-   *
+     *  2i.                                                                   */
+    compare("(x + 1)(x^2 - 2x + 5)", &[5.0, 3.0, -1.0, 1.0]);
+
+    /*  Roots of equal modulus, which unshifted QR cannot separate.           */
+    compare("x^2 - 1", &[-1.0, 0.0, 1.0]);
+    compare("x^2 - 4", &[-4.0, 0.0, 1.0]);
+    compare("x^3 - x", &[0.0, -1.0, 0.0, 1.0]);
+    compare("x^4 + 1", &[1.0, 0.0, 0.0, 0.0, 1.0]);
+    compare("x^5 - 1", &[-1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+}
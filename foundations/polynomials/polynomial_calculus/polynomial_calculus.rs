@@ -0,0 +1,101 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Differentiates and integrates polynomials given by their coefficients.*
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Polynomials are stored as coefficient lists in increasing order of        *
+ *  degree, coeffs[k] being the coefficient of x^k. So [2, -3, 1] is 2 - 3x + *
+ *  x^2.                                                                      */
+
+/*  Evaluates a polynomial using Horner's method.                             */
+fn horner(coeffs: &[f64], x: f64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    /*  a_0 + a_1 x + ... + a_n x^n = a_0 + x(a_1 + x(a_2 + ... + x a_n)).    *
+     *  Start at the innermost parentheses and work outwards.                 */
+    for coefficient in coeffs.iter().rev() {
+        sum = sum * x + coefficient;
+    }
+
+    return sum;
+}
+/*  End of horner.                                                            */
+
+/*  Computes the derivative of a polynomial. The derivative of a_k x^k is k   *
+ *  a_k x^(k-1), so each coefficient is multiplied by its index and shifted   *
+ *  down one place. A constant has derivative zero, which is returned as the  *
+ *  empty polynomial.                                                         */
+fn differentiate(coeffs: &[f64]) -> Vec<f64> {
+    let mut derivative: Vec<f64> = Vec::new();
+
+    for k in 1 .. coeffs.len() {
+        derivative.push((k as f64) * coeffs[k]);
+    }
+
+    return derivative;
+}
+/*  End of differentiate.                                                     */
+
+/*  Computes the antiderivative of a polynomial with constant term            *
+ *  "constant". The antiderivative of a_k x^k is a_k x^(k+1) / (k+1), so each *
+ *  coefficient is divided by its new index and shifted up one place.         */
+fn integrate(coeffs: &[f64], constant: f64) -> Vec<f64> {
+    let mut integral: Vec<f64> = vec![constant];
+
+    for (k, coefficient) in coeffs.iter().enumerate() {
+        integral.push(coefficient / ((k + 1) as f64));
+    }
+
+    return integral;
+}
+/*  End of integrate.                                                         */
+
+/*  Main routine used for testing polynomial differentiation and integration. */
+fn main() {
+
+    /*  p(x) = 1 + 2x - 3x^2 + 4x^3.                                          */
+    let p: [f64; 4] = [1.0, 2.0, -3.0, 4.0];
+
+    /*  p'(x) = 2 - 6x + 12x^2 and the antiderivative with constant 5 is 5 +  *
+     *  x + x^2 - x^3 + x^4.                                                  */
+    println!("p        = {:?}", p);
+    println!("p'       = {:?}", differentiate(&p));
+    println!("int p    = {:?}", integrate(&p, 5.0));
+
+    /*  Differentiating the antiderivative gives back p, the constant is      *
+     *  lost.                                                                 */
+    let recovered: Vec<f64> = differentiate(&integrate(&p, 5.0));
+    println!("(int p)' = {:?}", recovered);
+    println!("Recovers p: {}", recovered[..] == p[..]);
+
+    /*  The derivative of a constant is the empty (zero) polynomial.          */
+    println!("d/dx 7   = {:?}", differentiate(&[7.0]));
+
+    /*  Fundamental theorem of calculus: the integral of p over [0, 1] is     *
+     *  P(1) - P(0) for any antiderivative P. Exact value is 1 + 1 - 1 + 1 =  *
+     *  2.                                                                    */
+    let antiderivative: Vec<f64> = integrate(&p, 0.0);
+    let area: f64 = horner(&antiderivative, 1.0) - horner(&antiderivative, 0.0);
+    println!("Integral of p over [0, 1] = {}", area);
+}
@@ -0,0 +1,223 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Finds the real roots of a polynomial using Newton's method and        *
+ *      deflation.                                                            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Polynomials are stored as coefficient lists in increasing order of        *
+ *  degree, coeffs[k] being the coefficient of x^k.                           */
+
+/*  Evaluates a polynomial and its derivative at x using Horner's method.     *
+ *  Returns (p(x), p'(x)).                                                    */
+fn horner_with_derivative(coeffs: &[f64], x: f64) -> (f64, f64) {
+    let mut value: f64 = 0.0;
+    let mut derivative: f64 = 0.0;
+
+    /*  Differentiating the Horner step value = value * x + a_k gives         *
+     *  derivative = derivative * x + value. Compute both in one pass.        */
+    for coefficient in coeffs.iter().rev() {
+        derivative = derivative * x + value;
+        value = value * x + coefficient;
+    }
+
+    return (value, derivative);
+}
+/*  End of horner_with_derivative.                                            */
+
+/*  Newton's method for polynomials, starting at x. Returns None if the       *
+ *  iterates do not settle down, which happens if there is no real root       *
+ *  nearby (or none at all) or if we hit a critical point.                    */
+fn poly_newton(coeffs: &[f64], x: f64) -> Option<f64> {
+
+    /*  Multiple roots slow Newton's method down to linear convergence. Allow *
+     *  plenty of iterations to handle these.                                 */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 100;
+
+    /*  The relative size of the step below which we consider it converged.   */
+    const EPSILON: f64 = 1.0E-14;
+
+    let mut xn: f64 = x;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let (value, derivative): (f64, f64) =
+            horner_with_derivative(coeffs, xn);
+
+        /*  An exact root, nothing left to do.                                */
+        if value == 0.0 {
+            return Some(xn);
+        }
+
+        /*  A horizontal tangent line never meets the x axis. Give up.        */
+        if derivative == 0.0 {
+            return None;
+        }
+
+        let step: f64 = value / derivative;
+        xn = xn - step;
+
+        if !xn.is_finite() {
+            return None;
+        }
+
+        if step.abs() <= EPSILON * xn.abs().max(1.0) {
+            return Some(xn);
+        }
+    }
+
+    return None;
+}
+/*  End of poly_newton.                                                       */
+
+/*  Divides p(x) by (x - root) using synthetic division, discarding the       *
+ *  remainder, which is p(root) and hence close to zero.                      */
+fn deflate(coeffs: &[f64], root: f64) -> Vec<f64> {
+    let degree: usize = coeffs.len() - 1;
+    let mut quotient: Vec<f64> = vec![0.0; degree];
+    let mut carry: f64 = coeffs[degree];
+
+    for k in (1 .. degree).rev() {
+        quotient[k] = carry;
+        carry = coeffs[k] + root * carry;
+    }
+
+    quotient[0] = carry;
+    return quotient;
+}
+/*  End of deflate.                                                           */
+
+/*  Finds the real roots of a polynomial, in increasing order. Repeated roots *
+ *  are listed with their multiplicity.                                       *
+ *                                                                            *
+ *  A root is found with Newton's method and divided out, and the search      *
+ *  continues on the quotient. Each deflation uses a slightly inaccurate      *
+ *  root, so the coefficients of the quotients drift away from the true ones. *
+ *  To counter this, every root is polished at the end by running Newton's    *
+ *  method again on the original polynomial.                                  *
+ *                                                                            *
+ *  Only real roots are found. Newton's method on real numbers cannot         *
+ *  converge to a complex root, and once only complex roots remain the search *
+ *  stops. For complex roots use the Durand-Kerner method.                    */
+fn real_roots(coeffs: &[f64]) -> Vec<f64> {
+    let mut roots: Vec<f64> = Vec::new();
+
+    /*  Drop zero leading coefficients so the degree is correct.              */
+    let mut length: usize = coeffs.len();
+
+    while length > 0 && coeffs[length - 1] == 0.0 {
+        length = length - 1;
+    }
+
+    let mut working: Vec<f64> = coeffs[.. length].to_vec();
+
+    while working.len() > 1 {
+        let degree: usize = working.len() - 1;
+
+        /*  Linear polynomials are solved directly.                           */
+        if degree == 1 {
+            roots.push(-working[0] / working[1]);
+            break;
+        }
+
+        /*  Every root lies in |x| <= 1 + max |a_k / a_n| (Cauchy's bound).   *
+         *  Try starting at 0 and at the two ends of this interval.           */
+        let leading: f64 = working[degree];
+        let mut bound: f64 = 0.0;
+
+        for coefficient in working[.. degree].iter() {
+            bound = bound.max((coefficient / leading).abs());
+        }
+
+        bound = bound + 1.0;
+
+        let mut found: Option<f64> = None;
+
+        for &start in [0.0, bound, -bound].iter() {
+            found = poly_newton(&working, start);
+
+            if found.is_some() {
+                break;
+            }
+        }
+
+        /*  If Newton's method failed from every start, assume the rest of    *
+         *  the roots are complex.                                            */
+        match found {
+            Some(root) => {
+                roots.push(root);
+                working = deflate(&working, root);
+            }
+            None => break
+        }
+    }
+
+    /*  Polish the roots against the original polynomial. If the polished     *
+     *  iteration fails, keep the deflated value.                             */
+    for root in roots.iter_mut() {
+        if let Some(polished) = poly_newton(&coeffs[.. length], *root) {
+            *root = polished;
+        }
+    }
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    return roots;
+}
+/*  End of real_roots.                                                        */
+
+/*  Main routine used for testing the root finder.                            */
+fn main() {
+
+    /*  (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6.                         */
+    let cubic: [f64; 4] = [-6.0, 11.0, -6.0, 1.0];
+    let roots: Vec<f64> = real_roots(&cubic);
+    let mut accurate: bool = roots.len() == 3;
+
+    for (index, root) in roots.iter().enumerate() {
+        println!("Root {}: {:.16}", index + 1, root);
+        accurate = accurate && (root - (index + 1) as f64).abs() < 1.0E-6;
+    }
+
+    println!("Recovers 1, 2, 3 to 1e-6: {}", accurate);
+
+    /*  (x^2 + 1)(x - 2) = x^3 - 2x^2 + x - 2 has the complex roots +/- i,    *
+     *  which are skipped, and the real root 2.                               */
+    println!("Real roots of (x^2 + 1)(x - 2): {:?}",
+             real_roots(&[-2.0, 1.0, -2.0, 1.0]));
+
+    /*  Wilkinson-style example: (x - 1)(x - 2)...(x - 8). Build the          *
+     *  coefficients by multiplying out the factors.                          */
+    let mut product: Vec<f64> = vec![1.0];
+
+    for k in 1 .. 9 {
+        let mut next: Vec<f64> = vec![0.0; product.len() + 1];
+
+        for (index, coefficient) in product.iter().enumerate() {
+            next[index + 1] = next[index + 1] + coefficient;
+            next[index] = next[index] - (k as f64) * coefficient;
+        }
+
+        product = next;
+    }
+
+    println!("Real roots of (x - 1)...(x - 8): {:?}", real_roots(&product));
+}
@@ -0,0 +1,103 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Divides a polynomial by x - r using synthetic division.               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Polynomials are stored as coefficient lists in increasing order of        *
+ *  degree, coeffs[k] being the coefficient of x^k.                           */
+
+/*  Evaluates a polynomial using Horner's method.                             */
+fn horner(coeffs: &[f64], x: f64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for coefficient in coeffs.iter().rev() {
+        sum = sum * x + coefficient;
+    }
+
+    return sum;
+}
+/*  End of horner.                                                            */
+
+/*  Divides p(x) by (x - root), returning the quotient q and the remainder r, *
+ *  p(x) = (x - root) q(x) + r. If root is a root of p then r = 0 and q       *
+ *  contains the remaining roots. Dividing a found root out like this is      *
+ *  called deflation.                                                         */
+fn deflate(coeffs: &[f64], root: f64) -> (Vec<f64>, f64) {
+
+    /*  The zero polynomial and constants have no quotient. The remainder is  *
+     *  the constant itself.                                                  */
+    if coeffs.len() < 2 {
+        let remainder: f64 = if coeffs.is_empty() { 0.0 } else { coeffs[0] };
+        return (Vec::new(), remainder);
+    }
+
+    /*  If p(x) = a_0 + ... + a_n x^n and q(x) = b_0 + ... + b_{n-1} x^{n-1}, *
+     *  comparing coefficients in p(x) = (x - root) q(x) + r gives:           *
+     *                                                                        *
+     *        b_{n-1} = a_n                                                   *
+     *        b_{k-1} = a_k + root * b_k                                      *
+     *        r       = a_0 + root * b_0                                      *
+     *                                                                        *
+     *  These are the same steps as Horner's method, so r = p(root). This is  *
+     *  the remainder theorem.                                                */
+    let degree: usize = coeffs.len() - 1;
+    let mut quotient: Vec<f64> = vec![0.0; degree];
+    let mut carry: f64 = coeffs[degree];
+
+    for k in (1 .. degree).rev() {
+        quotient[k] = carry;
+        carry = coeffs[k] + root * carry;
+    }
+
+    quotient[0] = carry;
+    let remainder: f64 = coeffs[0] + root * carry;
+    return (quotient, remainder);
+}
+/*  End of deflate.                                                           */
+
+/*  Main routine used for testing synthetic division.                         */
+fn main() {
+
+    /*  x^2 - 3x + 2 = (x - 1)(x - 2). Dividing by x - 1 leaves x - 2, which  *
+     *  is [-2, 1] in our ordering, with zero remainder.                      */
+    let p: [f64; 3] = [2.0, -3.0, 1.0];
+    let (quotient, remainder): (Vec<f64>, f64) = deflate(&p, 1.0);
+    println!("(x^2 - 3x + 2) / (x - 1): quotient = {:?}, remainder = {}",
+             quotient, remainder);
+
+    /*  Deflate x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3) one root at a    *
+     *  time, leaving the constant 1.                                         */
+    let mut cubic: Vec<f64> = vec![-6.0, 11.0, -6.0, 1.0];
+
+    for &root in [3.0, 1.0, 2.0].iter() {
+        let (next, remainder): (Vec<f64>, f64) = deflate(&cubic, root);
+        println!("Divide by (x - {}): quotient = {:?}, remainder = {}",
+                 root, next, remainder);
+        cubic = next;
+    }
+
+    /*  For a number that is not a root the remainder is p(r).                */
+    let (_, remainder): (Vec<f64>, f64) = deflate(&p, 5.0);
+    println!("Remainder for r = 5: {}, p(5) = {}", remainder, horner(&p, 5.0));
+}
@@ -0,0 +1,124 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Gaussian random numbers from the Box-Muller transform and xorshift64. *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography. The second Box-Muller value is cached  *
+ *  here between calls to next_gaussian.                                      */
+struct Xorshift64 {
+    state: u64,
+    cached_gaussian: Option<f64>
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state, cached_gaussian: None};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform double in [0, 1). The top 53 bits fill the mantissa *
+     *  exactly, scaled by 2^-53.                                             */
+    fn next_f64(&mut self) -> f64 {
+        return ((self.next_u64() >> 11) as f64) * (1.0 / 9007199254740992.0);
+    }
+}
+
+/*  Returns a standard normal random number using the Box-Muller transform.   *
+ *  Given independent uniforms u_1, u_2 in (0, 1]:                            *
+ *                                                                            *
+ *        r = sqrt(-2 ln(u_1)),    theta = 2 pi u_2                           *
+ *        z_1 = r cos(theta),      z_2 = r sin(theta)                         *
+ *                                                                            *
+ *  are independent standard normals, since the pair (z_1, z_2) has a         *
+ *  rotationally symmetric density with r^2 / 2 exponentially distributed.    *
+ *  Each transform gives two values. The second is stored in the generator    *
+ *  and returned by the next call. 1 - next_f64() is used for u_1 so that it  *
+ *  is never zero, where the logarithm is infinite.                           */
+fn next_gaussian(rng: &mut Xorshift64) -> f64 {
+    if let Some(z) = rng.cached_gaussian.take() {
+        return z;
+    }
+
+    let u1: f64 = 1.0 - rng.next_f64();
+    let u2: f64 = rng.next_f64();
+    let r: f64 = (-2.0 * u1.ln()).sqrt();
+    let theta: f64 = 2.0 * std::f64::consts::PI * u2;
+
+    rng.cached_gaussian = Some(r * theta.sin());
+    return r * theta.cos();
+}
+/*  End of next_gaussian.                                                     */
+
+/*  Main routine used for testing the sampler.                                */
+fn main() {
+    const SAMPLES: usize = 1000000;
+    let mut rng: Xorshift64 = Xorshift64::new(2026);
+    let mut sum: f64 = 0.0;
+    let mut sum_of_squares: f64 = 0.0;
+
+    for _ in 0 .. SAMPLES {
+        let z: f64 = next_gaussian(&mut rng);
+        sum = sum + z;
+        sum_of_squares = sum_of_squares + z * z;
+    }
+
+    let n: f64 = SAMPLES as f64;
+    let mean: f64 = sum / n;
+    let variance: f64 = (sum_of_squares - n * mean * mean) / (n - 1.0);
+
+    /*  The sample mean has standard deviation 1 / sqrt(n) = 0.001, and the   *
+     *  sample variance sqrt(2 / n) ~= 0.0014. Allow five standard            *
+     *  deviations.                                                           */
+    println!("mean = {:.6}, within tolerance: {}", mean, mean.abs() < 0.005);
+    println!("variance = {:.6}, within tolerance: {}",
+             variance, (variance - 1.0).abs() < 0.007);
+
+    /*  The same seed must give the same sequence.                            */
+    let mut first: Xorshift64 = Xorshift64::new(42);
+    let mut second: Xorshift64 = Xorshift64::new(42);
+    let mut same: bool = true;
+
+    for _ in 0 .. 1000 {
+        same &= next_gaussian(&mut first) == next_gaussian(&mut second);
+    }
+
+    println!("fixed seed reproduces the sequence: {}", same);
+}
@@ -0,0 +1,129 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Reservoir sampling, choosing k items uniformly at random in one pass. *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform integer in [0, n), n > 0. Taking next_u64() % n     *
+     *  directly favors small remainders, since 2^64 is not a multiple of n.  *
+     *  Draws from the incomplete block at the top of the range are rejected, *
+     *  which happens with probability less than n / 2^64.                    */
+    fn next_below(&mut self, n: u64) -> u64 {
+        let limit: u64 = u64::MAX - u64::MAX % n;
+
+        loop {
+            let x: u64 = self.next_u64();
+
+            if x < limit {
+                return x % n;
+            }
+        }
+    }
+}
+
+/*  Selects k of the items uniformly at random with Vitter's Algorithm R. The *
+ *  first k items fill the reservoir. Item i, counting from zero, then        *
+ *  replaces a random slot with probability k / (i + 1). By induction, after  *
+ *  seeing i + 1 items each one is in the reservoir with probability k / (i + *
+ *  1), so at the end every item has probability k / n. The items are visited *
+ *  once, in order, and the length n is never needed in advance, which is the *
+ *  point of the method for streams. If k >= n every item is returned.        */
+fn reservoir_sample<T: Clone>(items: &[T], k: usize,
+                              rng: &mut Xorshift64) -> Vec<T> {
+    let mut reservoir: Vec<T> = Vec::with_capacity(k);
+
+    for (i, item) in items.iter().enumerate() {
+        if i < k {
+            reservoir.push(item.clone());
+        } else {
+            let j: usize = rng.next_below((i + 1) as u64) as usize;
+
+            if j < k {
+                reservoir[j] = item.clone();
+            }
+        }
+    }
+
+    return reservoir;
+}
+/*  End of reservoir_sample.                                                  */
+
+/*  Main routine used for testing reservoir sampling.                         */
+fn main() {
+    const TRIALS: usize = 100000;
+    let items: Vec<usize> = (0 .. 10).collect();
+    let mut rng: Xorshift64 = Xorshift64::new(2026);
+    let mut counts: [usize; 10] = [0; 10];
+
+    for _ in 0 .. TRIALS {
+        for &item in reservoir_sample(&items, 3, &mut rng).iter() {
+            counts[item] = counts[item] + 1;
+        }
+    }
+
+    /*  Each item should be chosen in 3 / 10 of the trials. The count has     *
+     *  standard deviation sqrt(n p (1 - p)) ~= 145, allow five of them.      */
+    let expected: f64 = 0.3 * (TRIALS as f64);
+    let mut uniform: bool = true;
+
+    for &count in counts.iter() {
+        uniform &= ((count as f64) - expected).abs() < 725.0;
+    }
+
+    println!("selection counts: {:?}", counts);
+    println!("roughly uniform: {}", uniform);
+
+    for &k in [0, 3, 10, 15].iter() {
+        let sample: Vec<usize> = reservoir_sample(&items, k, &mut rng);
+        println!("k = {:>2}: length = {:>2}, min(k, n) = {}",
+                 k, sample.len(), k.min(items.len()));
+    }
+}
@@ -0,0 +1,132 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The Fisher-Yates shuffle, a uniformly random permutation in place.    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform integer in [0, n), n > 0. Taking next_u64() % n     *
+     *  directly favors small remainders, since 2^64 is not a multiple of n.  *
+     *  Draws from the incomplete block at the top of the range are rejected, *
+     *  which happens with probability less than n / 2^64.                    */
+    fn next_below(&mut self, n: u64) -> u64 {
+        let limit: u64 = u64::MAX - u64::MAX % n;
+
+        loop {
+            let x: u64 = self.next_u64();
+
+            if x < limit {
+                return x % n;
+            }
+        }
+    }
+}
+
+/*  Shuffles the items in place with the Fisher-Yates algorithm. Walking down *
+ *  from the last position, swap position i with a uniformly chosen position  *
+ *  j in [0, i]. Each of the n! orderings is produced by exactly one sequence *
+ *  of choices, n (n - 1) ... 1 of them, all equally likely, so the shuffle   *
+ *  is unbiased. A common mistake is choosing j from the whole slice at every *
+ *  step, which gives n^n equally likely sequences. That is not a multiple of *
+ *  n! in general, so some permutations come up more often than others.       */
+fn shuffle<T>(items: &mut [T], rng: &mut Xorshift64) {
+    for i in (1 .. items.len()).rev() {
+        let j: usize = rng.next_below((i + 1) as u64) as usize;
+        items.swap(i, j);
+    }
+}
+/*  End of shuffle.                                                           */
+
+/*  Main routine used for testing the shuffle.                                */
+fn main() {
+
+    /*  The same seed gives the same permutation.                             */
+    let mut first: Vec<u32> = (0 .. 10).collect();
+    let mut second: Vec<u32> = (0 .. 10).collect();
+    shuffle(&mut first, &mut Xorshift64::new(7));
+    shuffle(&mut second, &mut Xorshift64::new(7));
+    println!("seed 7: {:?}, reproducible: {}", first, first == second);
+
+    /*  The elements are only moved around, never lost or duplicated.         */
+    let mut sorted: Vec<u32> = first.clone();
+    sorted.sort();
+    println!("same elements: {}", sorted == (0 .. 10).collect::<Vec<u32>>());
+
+    /*  Over many shuffles of [0, 1, 2, 3], each element should land in each  *
+     *  position a quarter of the time. The count has standard deviation      *
+     *  sqrt(n p (1 - p)) ~= 106, allow five of them.                         */
+    const TRIALS: usize = 60000;
+    let mut rng: Xorshift64 = Xorshift64::new(2026);
+    let mut counts: [[usize; 4]; 4] = [[0; 4]; 4];
+
+    for _ in 0 .. TRIALS {
+        let mut items: [usize; 4] = [0, 1, 2, 3];
+        shuffle(&mut items, &mut rng);
+
+        for position in 0 .. 4 {
+            let item: usize = items[position];
+            counts[position][item] = counts[position][item] + 1;
+        }
+    }
+
+    let expected: f64 = 0.25 * (TRIALS as f64);
+    let mut uniform: bool = true;
+
+    for position in 0 .. 4 {
+        for element in 0 .. 4 {
+            let count: f64 = counts[position][element] as f64;
+            uniform &= (count - expected).abs() < 530.0;
+        }
+
+        println!("position {}: {:?}", position, counts[position]);
+    }
+
+    println!("roughly uniform: {}", uniform);
+}
@@ -0,0 +1,248 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Exact rational arithmetic with fractions kept in lowest terms.        *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for rational arithmetic, ordering, and printing.                */
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/*  Computes the greatest common divisor using the Euclidean algorithm.       */
+fn gcd(a: u64, b: u64) -> u64 {
+    let mut x: u64 = a;
+    let mut y: u64 = b;
+
+    /*  gcd(x, y) = gcd(y, x mod y). Repeat until the remainder is zero.      */
+    while y != 0 {
+        let remainder: u64 = x % y;
+        x = y;
+        y = remainder;
+    }
+
+    return x;
+}
+/*  End of gcd.                                                               */
+
+/*  A fraction num / den. The fraction is always reduced, gcd(num, den) = 1,  *
+ *  and den > 0. Every rational number then has exactly one representation,   *
+ *  so the derived equality compares values, not just fields.                 */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rational {
+    num: i64,
+    den: i64
+}
+
+impl Rational {
+
+    /*  Creates num / den in lowest terms with a positive denominator.        *
+     *  Returns None if den is zero, or if the reduced fraction does not fit  *
+     *  in i64, which only happens for i64::MIN since its negative is too     *
+     *  large.                                                                */
+    fn new(num: i64, den: i64) -> Option<Rational> {
+        if den == 0 {
+            return None;
+        }
+
+        let divisor: u64 = gcd(num.unsigned_abs(), den.unsigned_abs());
+        let mut n: i128 = num as i128 / divisor as i128;
+        let mut d: i128 = den as i128 / divisor as i128;
+
+        if d < 0 {
+            n = -n;
+            d = -d;
+        }
+
+        let num: i64 = i64::try_from(n).ok()?;
+        let den: i64 = i64::try_from(d).ok()?;
+        return Some(Rational { num: num, den: den });
+    }
+    /*  End of new.                                                           */
+
+    /*  Creates the integer n as the fraction n / 1.                          */
+    fn from_integer(n: i64) -> Rational {
+        return Rational { num: n, den: 1 };
+    }
+    /*  End of from_integer.                                                  */
+
+    /*  Computes a/b + c/d. With g = gcd(b, d), the sum is (a (d/g) + c       *
+     *  (b/g)) / (b (d/g)). Dividing out g first keeps the intermediate       *
+     *  values small, and each step uses checked arithmetic so that overflow  *
+     *  gives None instead of a wrong answer.                                 */
+    fn checked_add(self, other: Rational) -> Option<Rational> {
+        let g: i64 = gcd(self.den as u64, other.den as u64) as i64;
+        let left: i64 = self.num.checked_mul(other.den / g)?;
+        let right: i64 = other.num.checked_mul(self.den / g)?;
+        let den: i64 = self.den.checked_mul(other.den / g)?;
+        return Rational::new(left.checked_add(right)?, den);
+    }
+    /*  End of checked_add.                                                   */
+
+    /*  Computes a/b - c/d the same way as the sum. The numerator is formed   *
+     *  directly rather than by negating c, which would overflow for c equal  *
+     *  to i64::MIN even when the difference is representable.                */
+    fn checked_sub(self, other: Rational) -> Option<Rational> {
+        let g: i64 = gcd(self.den as u64, other.den as u64) as i64;
+        let left: i64 = self.num.checked_mul(other.den / g)?;
+        let right: i64 = other.num.checked_mul(self.den / g)?;
+        let den: i64 = self.den.checked_mul(other.den / g)?;
+        return Rational::new(left.checked_sub(right)?, den);
+    }
+    /*  End of checked_sub.                                                   */
+
+    /*  Computes (a/b)(c/d). Cancelling gcd(a, d) and gcd(c, b) first means   *
+     *  the product is already reduced, and is as small as possible before    *
+     *  multiplying.                                                          */
+    fn checked_mul(self, other: Rational) -> Option<Rational> {
+        let g1: i64 = gcd(self.num.unsigned_abs(), other.den as u64) as i64;
+        let g2: i64 = gcd(other.num.unsigned_abs(), self.den as u64) as i64;
+
+        /*  gcd(0, d) = d, which is still a valid common factor.              */
+        let num: i64 = (self.num / g1).checked_mul(other.num / g2)?;
+        let den: i64 = (self.den / g2).checked_mul(other.den / g1)?;
+        return Rational::new(num, den);
+    }
+    /*  End of checked_mul.                                                   */
+
+    /*  Computes (a/b) / (c/d) = (a/b)(d/c). Division by zero gives None.     */
+    fn checked_div(self, other: Rational) -> Option<Rational> {
+        let reciprocal: Rational = Rational::new(other.den, other.num)?;
+        return self.checked_mul(reciprocal);
+    }
+    /*  End of checked_div.                                                   */
+}
+
+/*  Rational addition. Like the integer operators, this panics on overflow.   *
+ *  Use checked_add to handle overflow instead.                               */
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        return self.checked_add(other).expect("Rational: overflow in add.");
+    }
+}
+
+/*  Rational subtraction, panicking on overflow.                              */
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        return self.checked_sub(other).expect("Rational: overflow in sub.");
+    }
+}
+
+/*  Rational multiplication, panicking on overflow.                           */
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        return self.checked_mul(other).expect("Rational: overflow in mul.");
+    }
+}
+
+/*  Rational division, panicking on overflow or division by zero.             */
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        return self.checked_div(other)
+            .expect("Rational: overflow or division by zero in div.");
+    }
+}
+
+/*  Ordering. Since denominators are positive, a/b < c/d exactly when ad <    *
+ *  cb. The products are formed in i128 so they cannot overflow.              */
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let left: i128 = self.num as i128 * other.den as i128;
+        let right: i128 = other.num as i128 * self.den as i128;
+        return left.cmp(&right);
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/*  Prints num/den, or just num for integers.                                 */
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            return write!(f, "{}", self.num);
+        }
+
+        return write!(f, "{}/{}", self.num, self.den);
+    }
+}
+
+/*  Main routine used for testing rational arithmetic.                        */
+fn main() {
+    let half: Rational = Rational::new(1, 2).unwrap();
+    let third: Rational = Rational::new(1, 3).unwrap();
+
+    println!("1/2 + 1/3 = {}", half + third);
+    println!("1/2 - 1/3 = {}", half - third);
+    println!("1/2 * 1/3 = {}", half * third);
+    println!("(1/2) / (1/3) = {}", half / third);
+    println!("1/2 + 1/3 == 5/6: {}",
+             half + third == Rational::new(5, 6).unwrap());
+
+    /*  Reduction and the sign convention.                                    */
+    println!("2/4 = {}", Rational::new(2, 4).unwrap());
+    println!("2/4 == 1/2: {}", Rational::new(2, 4) == Some(half));
+    println!("3/-6 = {}", Rational::new(3, -6).unwrap());
+    println!("1/0 = {:?}", Rational::new(1, 0));
+
+    /*  1/1 + 1/2 + ... + 1/10, an exact harmonic number.                     */
+    let mut sum: Rational = Rational::from_integer(0);
+
+    for k in 1 .. 11 {
+        sum = sum + Rational::new(1, k).unwrap();
+    }
+
+    println!("H(10) = {}", sum);
+
+    /*  Sorting uses the ordering.                                            */
+    let pairs: [(i64, i64); 5] = [(3, 4), (-1, 2), (2, 3), (5, 8), (0, 1)];
+    let mut fractions: Vec<Rational> =
+        pairs.iter().map(|&(n, d)| Rational::new(n, d).unwrap()).collect();
+
+    fractions.sort();
+    let sorted: Vec<String> = fractions.iter().map(|q| q.to_string()).collect();
+    println!("sorted: {}", sorted.join(", "));
+
+    /*  Overflow is reported instead of giving a wrong answer.                */
+    let big: Rational = Rational::from_integer(i64::MAX);
+    let one: Rational = Rational::from_integer(1);
+    println!("i64::MAX + 1 = {:?}", big.checked_add(one));
+    println!("i64::MAX * 1/2 = {:?}", big.checked_mul(half));
+
+    /*  -1 - i64::MIN is i64::MAX, even though -i64::MIN does not fit.        */
+    let small: Rational = Rational::from_integer(i64::MIN);
+    let minus_one: Rational = Rational::from_integer(-1);
+    println!("-1 - i64::MIN = {:?}", minus_one.checked_sub(small));
+}
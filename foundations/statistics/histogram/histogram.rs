@@ -0,0 +1,88 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Counts data in equal width bins.                                      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Counts the data in equal width bins covering [min, max]. Bin k holds the  *
+ *  values in [min + k w, min + (k + 1) w), w = (max - min) / bins, except    *
+ *  the last bin, which also includes max itself. Conventions for the edge    *
+ *  cases:                                                                    *
+ *                                                                            *
+ *  Values below min are counted in the first bin, values above max in the    *
+ *  last bin, so every value is counted somewhere.                            *
+ *                                                                            *
+ *  NaN values are not in any bin and are skipped.                            *
+ *                                                                            *
+ *  If bins == 0 there is nowhere to put anything, an empty vector is         *
+ *  returned.                                                                 *
+ *                                                                            *
+ *  If min >= max the bins have no width. Rather than dividing by zero,       *
+ *  everything is counted in the first bin.                                   */
+fn histogram(data: &[f64], bins: usize, min: f64, max: f64) -> Vec<u64> {
+    let mut counts: Vec<u64> = vec![0; bins];
+
+    if bins == 0 {
+        return counts;
+    }
+
+    let scale: f64 = (bins as f64) / (max - min);
+
+    for &x in data.iter() {
+        if x.is_nan() {
+            continue;
+        }
+
+        /*  Float to integer casts saturate in Rust, so negative positions    *
+         *  become 0, clamping to the first bin, and huge ones become         *
+         *  usize::MAX.                                                       */
+        let index: usize = if min < max {
+            ((x - min) * scale).floor() as usize
+        } else {
+            0
+        };
+
+        let bin: usize = index.min(bins - 1);
+        counts[bin] = counts[bin] + 1;
+    }
+
+    return counts;
+}
+/*  End of histogram.                                                         */
+
+/*  Main routine used for testing the histogram.                              */
+fn main() {
+
+    /*  Evenly spaced data on [0, 1) should fill 10 bins equally.             */
+    let uniform: Vec<f64> = (0 .. 1000).map(|k| (k as f64) / 1000.0).collect();
+    println!("uniform: {:?}", histogram(&uniform, 10, 0.0, 1.0));
+
+    /*  min goes in the first bin, an interior edge starts the next bin, max  *
+     *  is in the last bin, and out of range values are clamped.              */
+    let edges: [f64; 6] = [0.0, 0.25, 0.5, 1.0, -3.0, 7.0];
+    println!("edges:   {:?}", histogram(&edges, 4, 0.0, 1.0));
+
+    println!("NaN:     {:?}", histogram(&[f64::NAN, 0.5], 2, 0.0, 1.0));
+    println!("0 bins:  {:?}", histogram(&uniform, 0, 0.0, 1.0));
+    println!("min=max: {:?}", histogram(&edges, 3, 1.0, 1.0));
+}
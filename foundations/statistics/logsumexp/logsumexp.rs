@@ -0,0 +1,96 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A numerically stable log-sum-exp, ln(exp(x_0) + ... + exp(x_n-1)).    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes ln(sum exp(x_k)) without overflow. exp(1000) overflows to        *
+ *  infinity, but with M the largest value:                                   *
+ *                                                                            *
+ *                           ---                                              *
+ *        ln(sum exp(x_k)) = M + ln( \   exp(x_k - M) )                       *
+ *                                   /                                        *
+ *                                   ---                                      *
+ *                                    k                                       *
+ *                                                                            *
+ *  Every exponent is now <= 0, and the largest term is exactly 1, so the sum *
+ *  is between 1 and n and the logarithm is safe. The sum of no terms is 0,   *
+ *  and ln(0) = -infinity, which is returned for an empty slice. If every     *
+ *  entry is -infinity the answer is also -infinity, this must be handled     *
+ *  separately since M - M would be NaN.                                      */
+fn log_sum_exp(values: &[f64]) -> f64 {
+    let mut max: f64 = f64::NEG_INFINITY;
+
+    for &x in values.iter() {
+        if x > max {
+            max = x;
+        }
+    }
+
+    /*  For the same reason, an entry of +infinity is handled here.           */
+    if max.is_infinite() {
+        return max;
+    }
+
+    /*  Entries equal to -infinity contribute exp(-infinity) = 0, as they     *
+     *  should.                                                               */
+    let mut sum: f64 = 0.0;
+
+    for &x in values.iter() {
+        sum = sum + (x - max).exp();
+    }
+
+    return max + sum.ln();
+}
+/*  End of log_sum_exp.                                                       */
+
+/*  The naive formula, for comparison.                                        */
+fn log_sum_exp_naive(values: &[f64]) -> f64 {
+    return values.iter().map(|x| x.exp()).sum::<f64>().ln();
+}
+
+/*  Main routine used for testing log-sum-exp.                                */
+fn main() {
+    let small: [f64; 4] = [0.5, -1.25, 2.0, 0.0];
+    let stable: f64 = log_sum_exp(&small);
+    let naive: f64 = log_sum_exp_naive(&small);
+
+    println!("small values: stable = {}, naive = {}, difference = {:.3E}",
+             stable, naive, (stable - naive).abs());
+
+    /*  The exact answer is 1001 + ln(1 + 1/e).                               */
+    let large: [f64; 2] = [1000.0, 1001.0];
+    let exact: f64 = 1001.0 + (1.0 + (-1.0f64).exp()).ln();
+
+    println!("[1000, 1001]: stable = {}, naive = {}, error = {:.3E}",
+             log_sum_exp(&large), log_sum_exp_naive(&large),
+             (log_sum_exp(&large) - exact).abs());
+
+    let with_infinity: [f64; 3] = [f64::NEG_INFINITY, 0.0, 0.0];
+    let all_infinity: [f64; 2] = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+
+    println!("[-inf, 0, 0] = {} (ln 2 = {})",
+             log_sum_exp(&with_infinity), 2.0f64.ln());
+    println!("[-inf, -inf] = {}", log_sum_exp(&all_infinity));
+    println!("[] = {}", log_sum_exp(&[]));
+}
@@ -0,0 +1,109 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Median and quantiles by sorting and linear interpolation.             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the q quantile of the data, for 0 <= q <= 1. The data is sorted  *
+ *  in place, x_0 <= x_1 <= ... <= x_n-1, and the quantile is read off at     *
+ *  position h = q (n - 1), interpolating linearly between the two order      *
+ *  statistics on either side:                                                *
+ *                                                                            *
+ *        Q(q) = x_i + (h - i) (x_i+1 - x_i),    i = floor(h)                 *
+ *                                                                            *
+ *  This is the default method in R and NumPy. Q(0) is the minimum, Q(1) the  *
+ *  maximum, and Q(1/2) the median. Empty data has no quantiles, and q        *
+ *  outside of [0, 1] has no meaning, NaN is returned in both cases.          */
+fn quantile(data: &mut [f64], q: f64) -> f64 {
+    let n: usize = data.len();
+
+    if n == 0 || !(0.0 ..= 1.0).contains(&q) {
+        return f64::NAN;
+    }
+
+    /*  total_cmp gives a total order even with NaN present, which sorts to   *
+     *  the end.                                                              */
+    data.sort_by(|a, b| a.total_cmp(b));
+
+    let h: f64 = q * ((n - 1) as f64);
+    let i: usize = h.floor() as usize;
+
+    if i + 1 >= n {
+        return data[n - 1];
+    }
+
+    return data[i] + (h - (i as f64)) * (data[i + 1] - data[i]);
+}
+/*  End of quantile.                                                          */
+
+/*  Computes the median, the middle value of the sorted data, or the mean of  *
+ *  the two middle values if there is an even number of them.                 */
+fn median(data: &mut [f64]) -> f64 {
+    let n: usize = data.len();
+
+    if n == 0 {
+        return f64::NAN;
+    }
+
+    data.sort_by(|a, b| a.total_cmp(b));
+
+    if n % 2 == 1 {
+        return data[n / 2];
+    }
+
+    return 0.5 * (data[n / 2 - 1] + data[n / 2]);
+}
+/*  End of median.                                                            */
+
+/*  Main routine used for testing the median and quantiles.                   */
+fn main() {
+    let mut odd: [f64; 5] = [3.0, -1.0, 7.5, 2.0, 4.0];
+    let mut even: [f64; 6] = [10.0, 2.0, 8.0, 4.0, 6.0, 1.0];
+
+    let odd_median: f64 = median(&mut odd);
+    let even_median: f64 = median(&mut even);
+
+    println!("median of {:?} = {}", odd, odd_median);
+    println!("median of {:?} = {}", even, even_median);
+
+    /*  The 0.5 quantile must agree with the median, and the extreme          *
+     *  quantiles with the smallest and largest values.                       */
+    for data in [&mut odd[..], &mut even[..]].iter_mut() {
+        let mid: f64 = median(data);
+        let half: f64 = quantile(data, 0.5);
+        let low: f64 = quantile(data, 0.0);
+        let high: f64 = quantile(data, 1.0);
+        let min: f64 = data.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max: f64 = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        println!("Q(0.5) == median: {}, Q(0) == min: {}, Q(1) == max: {}",
+                 half == mid, low == min, high == max);
+    }
+
+    let lower: f64 = quantile(&mut even, 0.25);
+    let upper: f64 = quantile(&mut even, 0.75);
+
+    println!("quartiles of {:?}: {}, {}", even, lower, upper);
+    println!("Q(1.5) = {}, median([]) = {}",
+             quantile(&mut odd, 1.5), median(&mut []));
+}
@@ -0,0 +1,125 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Pearson correlation and least squares regression lines.               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Sums accumulated in one pass over paired data: the means of x and y, and  *
+ *  the sums of squared deviations, S_xx = sum (x - mean_x)^2, S_yy and S_xy  *
+ *  alike.                                                                    */
+struct Moments {
+    mean_x: f64,
+    mean_y: f64,
+    sxx: f64,
+    syy: f64,
+    sxy: f64
+}
+
+/*  Computes the moments with Welford's one-pass update. The textbook formula *
+ *  S_xx = sum x^2 - n mean_x^2 subtracts two large, nearly equal numbers     *
+ *  when the mean is large compared to the spread, losing most of the digits. *
+ *  Instead, the means are updated for each new point (x, y) and the sums     *
+ *  grow by products of deviations:                                           *
+ *                                                                            *
+ *        dx = x - mean_x,   mean_x <- mean_x + dx / n,   likewise for y      *
+ *        S_xx <- S_xx + dx (x - mean_x),   S_xy <- S_xy + dx (y - mean_y)    *
+ *                                                                            *
+ *  Only pairs up to the length of the shorter slice are used.                */
+fn moments(xs: &[f64], ys: &[f64]) -> Moments {
+    let mut m: Moments = Moments {
+        mean_x: 0.0, mean_y: 0.0, sxx: 0.0, syy: 0.0, sxy: 0.0
+    };
+
+    let mut n: f64 = 0.0;
+
+    for (&x, &y) in xs.iter().zip(ys.iter()) {
+        n = n + 1.0;
+        let dx: f64 = x - m.mean_x;
+        let dy: f64 = y - m.mean_y;
+        m.mean_x = m.mean_x + dx / n;
+        m.mean_y = m.mean_y + dy / n;
+        m.sxx = m.sxx + dx * (x - m.mean_x);
+        m.syy = m.syy + dy * (y - m.mean_y);
+        m.sxy = m.sxy + dx * (y - m.mean_y);
+    }
+
+    return m;
+}
+/*  End of moments.                                                           */
+
+/*  Computes the Pearson correlation coefficient, r = S_xy / sqrt(S_xx S_yy), *
+ *  which lies in [-1, 1]. If either variable is constant, r is 0 / 0 and NaN *
+ *  is returned.                                                              */
+fn correlation(xs: &[f64], ys: &[f64]) -> f64 {
+    let m: Moments = moments(xs, ys);
+
+    if m.sxx == 0.0 || m.syy == 0.0 {
+        return f64::NAN;
+    }
+
+    /*  Rounding can push |r| slightly past 1 for perfectly correlated data.  */
+    let r: f64 = m.sxy / (m.sxx * m.syy).sqrt();
+    return r.clamp(-1.0, 1.0);
+}
+/*  End of correlation.                                                       */
+
+/*  Computes the least squares line y = slope x + intercept, with slope =     *
+ *  S_xy / S_xx and intercept = mean_y - slope mean_x. If every x is the      *
+ *  same, the line is vertical and there is no slope. (NaN, NaN) is returned. */
+fn linear_regression(xs: &[f64], ys: &[f64]) -> (f64, f64) {
+    let m: Moments = moments(xs, ys);
+
+    if m.sxx == 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let slope: f64 = m.sxy / m.sxx;
+    return (slope, m.mean_y - slope * m.mean_x);
+}
+/*  End of linear_regression.                                                 */
+
+/*  Main routine used for testing correlation and regression.                 */
+fn main() {
+    let xs: Vec<f64> = (0 .. 10).map(|k| 0.5 * (k as f64)).collect();
+    let up: Vec<f64> = xs.iter().map(|x| 3.0 * x - 2.0).collect();
+    let down: Vec<f64> = xs.iter().map(|x| 7.0 - 0.25 * x).collect();
+
+    let (slope, intercept): (f64, f64) = linear_regression(&xs, &up);
+    println!("y = 3x - 2: r = {}, slope = {}, intercept = {}",
+             correlation(&xs, &up), slope, intercept);
+
+    let (slope, intercept): (f64, f64) = linear_regression(&xs, &down);
+    println!("y = 7 - x/4: r = {}, slope = {}, intercept = {}",
+             correlation(&xs, &down), slope, intercept);
+
+    /*  A large offset in x ruins the textbook formula but not the one-pass   *
+     *  update.                                                               */
+    let shifted: Vec<f64> = xs.iter().map(|x| x + 1.0E9).collect();
+    let (slope, _): (f64, f64) = linear_regression(&shifted, &up);
+    println!("x shifted by 1E9: slope = {}", slope);
+
+    /*  Constant inputs have no variance.                                     */
+    let constant: Vec<f64> = vec![2.0; 10];
+    println!("constant y: r = {}", correlation(&xs, &constant));
+    println!("constant x: line = {:?}", linear_regression(&constant, &up));
+}
@@ -0,0 +1,76 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The softmax function, computed stably with the log-sum-exp shift.     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the softmax of the inputs, the probability vector:               *
+ *                                                                            *
+ *                          exp(x_k)           exp(x_k - M)                   *
+ *        softmax(x)_k = --------------  =  ------------------                *
+ *                       sum_j exp(x_j)     sum_j exp(x_j - M)                *
+ *                                                                            *
+ *  This is the same shift used by log-sum-exp, with M the largest input.     *
+ *  Every exponent is <= 0 and the denominator is between 1 and n, so nothing *
+ *  overflows. Equivalently softmax(x)_k = exp(x_k - log_sum_exp(x)), but for *
+ *  large inputs M + ln(sum) rounds to the precision of M, and dividing by    *
+ *  the sum directly is more accurate. Adding a constant to every input does  *
+ *  not change M - x_k, so the output is unchanged.                           */
+fn softmax(values: &[f64]) -> Vec<f64> {
+    let mut max: f64 = f64::NEG_INFINITY;
+
+    for &x in values.iter() {
+        if x > max {
+            max = x;
+        }
+    }
+
+    let shifted: Vec<f64> = values.iter().map(|x| (x - max).exp()).collect();
+    let sum: f64 = shifted.iter().sum();
+    return shifted.iter().map(|e| e / sum).collect();
+}
+/*  End of softmax.                                                           */
+
+/*  Main routine used for testing softmax.                                    */
+fn main() {
+    let values: [f64; 5] = [0.3, -2.0, 1.7, 0.0, 4.25];
+    let probabilities: Vec<f64> = softmax(&values);
+    let total: f64 = probabilities.iter().sum();
+
+    println!("softmax = {:?}", probabilities);
+    println!("sum = {}, within 1e-12 of 1: {}",
+             total, (total - 1.0).abs() < 1.0E-12);
+
+    /*  Shifting every input by the same amount changes nothing.              */
+    let shifted: Vec<f64> = values.iter().map(|x| x + 123.456).collect();
+    let mut difference: f64 = 0.0;
+
+    for (p, q) in probabilities.iter().zip(softmax(&shifted).iter()) {
+        difference = difference.max((p - q).abs());
+    }
+
+    println!("shift invariance, max difference = {:.3E}", difference);
+
+    /*  exp(1000) overflows, the stable form does not care.                   */
+    println!("softmax([1000, 1000]) = {:?}", softmax(&[1000.0, 1000.0]));
+}
@@ -0,0 +1,79 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The Levenshtein edit distance between two strings.                    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the Levenshtein distance, the fewest single character            *
+ *  insertions, deletions, and substitutions turning a into b. With d[i][j]   *
+ *  the distance between the first i characters of a and the first j of b,    *
+ *  d[i][0] = i, d[0][j] = j, and:                                            *
+ *                                                                            *
+ *        d[i][j] = min(d[i-1][j] + 1,                 delete a_i             *
+ *                      d[i][j-1] + 1,                 insert b_j             *
+ *                      d[i-1][j-1] + [a_i != b_j])    substitute, or keep    *
+ *                                                                            *
+ *  Row i only needs row i - 1, so two rows are kept and swapped instead of   *
+ *  the full table, using O(len(b)) memory. The strings are compared by char, *
+ *  not by byte. An accented letter like the e in "cafe" with an acute accent *
+ *  is two bytes in UTF-8, and comparing bytes would count changing it as two *
+ *  edits.                                                                    */
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0 .. b_chars.len() + 1).collect();
+    let mut current: Vec<usize> = vec![0; b_chars.len() + 1];
+
+    for i in 1 .. a_chars.len() + 1 {
+        current[0] = i;
+
+        for j in 1 .. b_chars.len() + 1 {
+            let cost: usize = if a_chars[i - 1] == b_chars[j - 1] {0} else {1};
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    return previous[b_chars.len()];
+}
+/*  End of edit_distance.                                                     */
+
+/*  Main routine used for testing the edit distance.                          */
+fn main() {
+    let pairs: [(&str, &str); 7] = [
+        ("kitten", "sitting"),
+        ("flaw", "lawn"),
+        ("intention", "execution"),
+        ("same", "same"),
+        ("", "abc"),
+        ("abcd", ""),
+        ("caf\u{e9}", "cafe")
+    ];
+
+    for &(a, b) in pairs.iter() {
+        println!("d({:?}, {:?}) = {}", a, b, edit_distance(a, b));
+    }
+}
@@ -0,0 +1,99 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Finds a longest common subsequence of two strings.                    *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes a longest common subsequence of a and b, the longest string      *
+ *  whose characters appear in both, in order, but not necessarily adjacent.  *
+ *  With L[i][j] the length of an LCS of the first i characters of a and the  *
+ *  first j of b, L[i][0] = L[0][j] = 0, and:                                 *
+ *                                                                            *
+ *        L[i][j] = L[i-1][j-1] + 1                if a_i == b_j              *
+ *        L[i][j] = max(L[i-1][j], L[i][j-1])      otherwise                  *
+ *                                                                            *
+ *  Unlike the edit distance, the whole table is kept, since the subsequence  *
+ *  itself is recovered by walking back from L[m][n]. Where the characters    *
+ *  match, that character is part of the LCS and the walk moves diagonally.   *
+ *  Otherwise it moves towards whichever neighbor has the larger length. The  *
+ *  characters are found in reverse and the result is flipped at the end.     *
+ *  There may be several longest subsequences, this returns one of them. The  *
+ *  table takes O(mn) time and memory. Strings are handled by char, so        *
+ *  multi-byte characters are never split.                                    */
+fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let m: usize = a_chars.len();
+    let n: usize = b_chars.len();
+    let mut table: Vec<Vec<usize>> = vec![vec![0; n + 1]; m + 1];
+
+    for i in 1 .. m + 1 {
+        for j in 1 .. n + 1 {
+            table[i][j] = if a_chars[i - 1] == b_chars[j - 1] {
+                table[i - 1][j - 1] + 1
+            } else {
+                table[i - 1][j].max(table[i][j - 1])
+            };
+        }
+    }
+
+    let mut reversed: Vec<char> = Vec::with_capacity(table[m][n]);
+    let (mut i, mut j): (usize, usize) = (m, n);
+
+    while i > 0 && j > 0 {
+        if a_chars[i - 1] == b_chars[j - 1] {
+            reversed.push(a_chars[i - 1]);
+            i = i - 1;
+            j = j - 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i = i - 1;
+        } else {
+            j = j - 1;
+        }
+    }
+
+    return reversed.iter().rev().collect();
+}
+/*  End of longest_common_subsequence.                                        */
+
+/*  Checks if the characters of sub appear in s in the same order.            */
+fn is_subsequence(sub: &str, s: &str) -> bool {
+    let mut remaining: std::str::Chars = s.chars();
+    return sub.chars().all(|c| remaining.any(|d| d == c));
+}
+/*  End of is_subsequence.                                                    */
+
+/*  Main routine used for testing the longest common subsequence.             */
+fn main() {
+    let (a, b): (&str, &str) = ("ABCBDAB", "BDCAB");
+    let lcs: String = longest_common_subsequence(a, b);
+
+    println!("LCS({}, {}) = {}, length = {}", a, b, lcs, lcs.chars().count());
+    println!("subsequence of both: {}",
+             is_subsequence(&lcs, a) && is_subsequence(&lcs, b));
+
+    println!("LCS(abc, xyz) = {:?}", longest_common_subsequence("abc", "xyz"));
+    println!("LCS(\u{3b1}\u{3b2}\u{3b3}\u{3b4}, \u{3b2}x\u{3b4}) = {}",
+             longest_common_subsequence("\u{3b1}\u{3b2}\u{3b3}\u{3b4}",
+                                        "\u{3b2}x\u{3b4}"));
+}
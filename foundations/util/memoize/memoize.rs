@@ -0,0 +1,102 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A memoizing wrapper for expensive real functions.                     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::collections::HashMap;
+
+/*  Wraps a function f, remembering every value it has computed. Inputs are   *
+ *  keyed by their bit pattern, f64 is not Eq or Hash since NaN != NaN, but   *
+ *  the bits of a float are an ordinary u64. This means 0.0 and -0.0 are      *
+ *  cached separately, which is harmless.                                     */
+struct Memoized<F: Fn(f64) -> f64> {
+    func: F,
+    cache: HashMap<u64, f64>,
+    evaluations: usize
+}
+
+impl<F: Fn(f64) -> f64> Memoized<F> {
+
+    /*  Creates a wrapper with an empty cache.                                */
+    fn new(func: F) -> Memoized<F> {
+        return Memoized {func: func, cache: HashMap::new(), evaluations: 0};
+    }
+
+    /*  Returns f(x), calling f only if x has not been seen before.           */
+    fn eval(&mut self, x: f64) -> f64 {
+        let key: u64 = x.to_bits();
+
+        if let Some(value) = self.cache.get(&key) {
+            return *value;
+        }
+
+        let value: f64 = (self.func)(x);
+        self.evaluations = self.evaluations + 1;
+        self.cache.insert(key, value);
+        return value;
+    }
+
+    /*  The number of times the inner function has actually been called.      */
+    fn evaluations(&self) -> usize {
+        return self.evaluations;
+    }
+}
+
+/*  Main routine used for testing the wrapper.                                */
+fn main() {
+
+    /*  An "expensive" function, a slowly converging series for ln(1 + x).    */
+    let mut ln = Memoized::new(|x: f64| {
+        let mut sum: f64 = 0.0;
+        let mut power: f64 = x;
+
+        for n in 1 .. 100000 {
+            sum = sum + power / (n as f64);
+            power = -power * x;
+        }
+
+        return sum;
+    });
+
+    let first: f64 = ln.eval(0.5);
+    let second: f64 = ln.eval(0.5);
+
+    println!("ln(1.5) = {}, again = {}, inner calls = {}",
+             first, second, ln.evaluations());
+
+    /*  Composite Simpson's rule evaluates the shared endpoint of adjacent    *
+     *  panels twice. With the cache, f is called only once per distinct      *
+     *  point. The call made for 0.5 above is not counted here.               */
+    let before: usize = ln.evaluations();
+    let mut total: f64 = 0.0;
+
+    for k in 0 .. 4 {
+        let a: f64 = 0.1 * (k as f64);
+        let b: f64 = 0.1 * ((k + 1) as f64);
+        let sum: f64 = ln.eval(a) + 4.0 * ln.eval(0.5 * (a + b)) + ln.eval(b);
+        total = total + sum * (b - a) / 6.0;
+    }
+
+    println!("integral of ln(1 + x) over [0, 0.4] = {:.12}", total);
+    println!("inner calls = {} for 12 requests", ln.evaluations() - before);
+}
@@ -0,0 +1,87 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A tiny timing helper returning the average nanoseconds per call.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::hint::black_box;
+use std::time::Instant;
+
+/*  Calls f iterations times and returns the average time per call, in        *
+ *  nanoseconds. A few untimed calls are made first, so caches, branch        *
+ *  predictors, and lazily initialized state are warm before the clock        *
+ *  starts. The total time is measured once around the whole loop, since      *
+ *  reading the clock costs tens of nanoseconds and would swamp a fast f.     *
+ *  This is only for informal comparisons. There is no statistical analysis,  *
+ *  and the result includes the small loop overhead. Returns 0 if iterations  *
+ *  is 0.                                                                     */
+fn time_ns<F: FnMut()>(iterations: u64, mut f: F) -> f64 {
+    const WARMUP_ITERATIONS: u64 = 10;
+
+    if iterations == 0 {
+        return 0.0;
+    }
+
+    for _ in 0 .. WARMUP_ITERATIONS {
+        f();
+    }
+
+    let start: Instant = Instant::now();
+
+    for _ in 0 .. iterations {
+        f();
+    }
+
+    let elapsed: f64 = start.elapsed().as_nanos() as f64;
+    return elapsed / (iterations as f64);
+}
+/*  End of time_ns.                                                           */
+
+/*  A fixed amount of work, summing n square roots. black_box stops the       *
+ *  compiler from computing the sum ahead of time or removing the loop.       */
+fn work(n: u32) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for k in 0 .. black_box(n) {
+        sum = sum + (k as f64).sqrt();
+    }
+
+    return sum;
+}
+/*  End of work.                                                              */
+
+/*  Main routine used for testing the timer.                                  */
+fn main() {
+    let empty: f64 = time_ns(1000000, || {});
+    println!("empty closure: {:.3} ns, small and non-negative: {}",
+             empty, (0.0 .. 100.0).contains(&empty));
+
+    /*  Four times the work should take about four times as long. Timings are *
+     *  noisy, so only a loose check is made.                                 */
+    let small: f64 = time_ns(2000, || { black_box(work(1000)); });
+    let large: f64 = time_ns(2000, || { black_box(work(4000)); });
+    let ratio: f64 = large / small;
+
+    println!("work(1000): {:.1} ns, work(4000): {:.1} ns", small, large);
+    println!("ratio = {:.2}, roughly linear: {}",
+             ratio, ratio > 2.0 && ratio < 8.0);
+}
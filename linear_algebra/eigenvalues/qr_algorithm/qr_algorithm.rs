@@ -0,0 +1,210 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the eigenvalues of a symmetric matrix using the unshifted QR *
+ *      algorithm.                                                            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the product of two matrices.                                     */
+fn matrix_multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let rows: usize = a.len();
+    let columns: usize = b[0].len();
+    let mut product: Vec<Vec<f64>> = vec![vec![0.0; columns]; rows];
+
+    for i in 0 .. rows {
+        for j in 0 .. columns {
+            for k in 0 .. b.len() {
+                product[i][j] = product[i][j] + a[i][k] * b[k][j];
+            }
+        }
+    }
+
+    return product;
+}
+/*  End of matrix_multiply.                                                   */
+
+/*  Computes the QR decomposition A = QR of an m x n matrix with m >= n. Q is *
+ *  an m x m orthogonal matrix and R is an m x n upper triangular matrix.     *
+ *                                                                            *
+ *  A Householder reflection H = I - 2 v v^T, with v a unit vector, reflects  *
+ *  across the plane perpendicular to v. Given a vector x, choosing v         *
+ *  parallel to x - alpha e_1 with |alpha| = ||x|| gives Hx = alpha e_1,      *
+ *  zeroing every entry but the first. Applying one reflection per column     *
+ *  zeroes everything below the diagonal, giving R = H_n ... H_1 A, and Q =   *
+ *  H_1 ... H_n since each H is its own inverse. An empty matrix has empty    *
+ *  factors.                                                                  */
+fn qr_householder(a: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    if a.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let m: usize = a.len();
+    let n: usize = a[0].len();
+
+    /*  R starts as A and is reduced column by column. Q starts as the        *
+     *  identity and accumulates the reflections.                             */
+    let mut r: Vec<Vec<f64>> = a.to_vec();
+    let mut q: Vec<Vec<f64>> = vec![vec![0.0; m]; m];
+
+    for i in 0 .. m {
+        q[i][i] = 1.0;
+    }
+
+    /*  The last row of a square matrix has nothing below the diagonal.       */
+    let steps: usize = if m > n { n } else { m - 1 };
+
+    for k in 0 .. steps {
+
+        /*  The part of column k on and below the diagonal.                   */
+        let mut v: Vec<f64> = (k .. m).map(|i| r[i][k]).collect();
+        let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        /*  Nothing to zero out in this column.                               */
+        if norm == 0.0 {
+            continue;
+        }
+
+        /*  Pick the sign of alpha opposite to x_1. Then x_1 - alpha is a sum *
+         *  of two numbers with the same sign, avoiding cancellation.         */
+        let alpha: f64 = if v[0] > 0.0 { -norm } else { norm };
+        v[0] = v[0] - alpha;
+
+        let v_norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        for x in v.iter_mut() {
+            *x = *x / v_norm;
+        }
+
+        /*  Apply H to R from the left, R -> R - 2 v (v^T R).                 */
+        for column in 0 .. n {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + v[i - k] * r[i][column];
+            }
+
+            for i in k .. m {
+                r[i][column] = r[i][column] - 2.0 * v[i - k] * dot;
+            }
+        }
+
+        /*  Apply H to Q from the right, Q -> Q - 2 (Q v) v^T.                */
+        for row in 0 .. m {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + q[row][i] * v[i - k];
+            }
+
+            for i in k .. m {
+                q[row][i] = q[row][i] - 2.0 * dot * v[i - k];
+            }
+        }
+    }
+
+    return (q, r);
+}
+/*  End of qr_householder.                                                    */
+
+/*  Computes the eigenvalues of a symmetric matrix, sorted in decreasing      *
+ *  order, using the unshifted QR algorithm. Starting with A_0 = A, each step *
+ *  factors A_k = Q_k R_k and sets A_{k+1} = R_k Q_k = Q_k^T A_k Q_k. This is *
+ *  a similarity transformation, so the eigenvalues are unchanged, and for    *
+ *  symmetric matrices the iterates converge to a diagonal matrix with the    *
+ *  eigenvalues on the diagonal.                                              *
+ *                                                                            *
+ *  The off-diagonal entries shrink at each step by a factor of roughly       *
+ *  |lambda_{i+1} / lambda_i|, the ratio of consecutive eigenvalues by size.  *
+ *  When two eigenvalues are close this ratio is nearly 1 and convergence is  *
+ *  very slow. Practical implementations use shifts to fix this.              */
+fn eigenvalues_symmetric(a: &[Vec<f64>], iterations: u32) -> Vec<f64> {
+
+    /*  We stop early once the off-diagonal part is this small.               */
+    const EPSILON: f64 = 1.0E-12;
+
+    let n: usize = a.len();
+    let mut current: Vec<Vec<f64>> = a.to_vec();
+
+    for _ in 0 .. iterations {
+        let (q, r): (Vec<Vec<f64>>, Vec<Vec<f64>>) = qr_householder(&current);
+        current = matrix_multiply(&r, &q);
+
+        /*  Check if the matrix is nearly diagonal.                           */
+        let mut off_diagonal: f64 = 0.0;
+
+        for i in 0 .. n {
+            for j in 0 .. n {
+                if i != j {
+                    off_diagonal = off_diagonal.max(current[i][j].abs());
+                }
+            }
+        }
+
+        if off_diagonal < EPSILON {
+            break;
+        }
+    }
+
+    let mut eigenvalues: Vec<f64> = (0 .. n).map(|i| current[i][i]).collect();
+    eigenvalues.sort_by(|x, y| y.partial_cmp(x).unwrap());
+    return eigenvalues;
+}
+/*  End of eigenvalues_symmetric.                                             */
+
+/*  Main routine used for testing the QR algorithm.                           */
+fn main() {
+
+    /*  The tridiagonal matrix with 2 on the diagonal and 1 off of it has     *
+     *  eigenvalues 2 + sqrt(2), 2, and 2 - sqrt(2).                          */
+    let a: Vec<Vec<f64>> = vec![
+        vec![2.0, 1.0, 0.0],
+        vec![1.0, 2.0, 1.0],
+        vec![0.0, 1.0, 2.0]
+    ];
+
+    let sqrt_2: f64 = std::f64::consts::SQRT_2;
+    let expected: [f64; 3] = [2.0 + sqrt_2, 2.0, 2.0 - sqrt_2];
+    let eigenvalues: Vec<f64> = eigenvalues_symmetric(&a, 500);
+    let mut accurate: bool = true;
+
+    for (lambda, exact) in eigenvalues.iter().zip(expected.iter()) {
+        let error: f64 = (lambda - exact).abs();
+        println!("lambda = {:.16}, error = {:.3E}", lambda, error);
+        accurate = accurate && error < 1.0E-6;
+    }
+
+    println!("Agrees with the known spectrum to 1e-6: {}", accurate);
+
+    /*  [[1, d], [d, 1]] has eigenvalues 1 + d and 1 - d. The ratio (1 - d) / *
+     *  (1 + d) is close to 1 for small d and the same number of iterations   *
+     *  does much worse.                                                      */
+    for &d in [0.5, 0.01].iter() {
+        let b: Vec<Vec<f64>> = vec![vec![1.0, d], vec![d, 1.0]];
+        let result: Vec<f64> = eigenvalues_symmetric(&b, 50);
+        println!("d = {}: 50 iterations give {:?}, error = {:.3E}",
+                 d, result, (result[0] - 1.0 - d).abs());
+    }
+}
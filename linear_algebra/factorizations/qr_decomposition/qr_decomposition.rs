@@ -0,0 +1,215 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the QR decomposition of a matrix using Householder           *
+ *      reflections.                                                          *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the product of two matrices.                                     */
+fn matrix_multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let rows: usize = a.len();
+    let columns: usize = b[0].len();
+    let mut product: Vec<Vec<f64>> = vec![vec![0.0; columns]; rows];
+
+    for i in 0 .. rows {
+        for j in 0 .. columns {
+            for k in 0 .. b.len() {
+                product[i][j] = product[i][j] + a[i][k] * b[k][j];
+            }
+        }
+    }
+
+    return product;
+}
+/*  End of matrix_multiply.                                                   */
+
+/*  Computes the transpose of a matrix, swapping rows and columns.            */
+fn transpose(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let mut result: Vec<Vec<f64>> = vec![vec![0.0; a.len()]; a[0].len()];
+
+    for i in 0 .. a.len() {
+        for j in 0 .. a[0].len() {
+            result[j][i] = a[i][j];
+        }
+    }
+
+    return result;
+}
+/*  End of transpose.                                                         */
+
+/*  Computes the QR decomposition A = QR of an m x n matrix with m >= n. Q is *
+ *  an m x m orthogonal matrix and R is an m x n upper triangular matrix.     *
+ *                                                                            *
+ *  A Householder reflection H = I - 2 v v^T, with v a unit vector, reflects  *
+ *  across the plane perpendicular to v. Given a vector x, choosing v         *
+ *  parallel to x - alpha e_1 with |alpha| = ||x|| gives Hx = alpha e_1,      *
+ *  zeroing every entry but the first. Applying one reflection per column     *
+ *  zeroes everything below the diagonal, giving R = H_n ... H_1 A, and Q =   *
+ *  H_1 ... H_n since each H is its own inverse. An empty matrix has empty    *
+ *  factors.                                                                  */
+fn qr_householder(a: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    if a.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let m: usize = a.len();
+    let n: usize = a[0].len();
+
+    /*  R starts as A and is reduced column by column. Q starts as the        *
+     *  identity and accumulates the reflections.                             */
+    let mut r: Vec<Vec<f64>> = a.to_vec();
+    let mut q: Vec<Vec<f64>> = vec![vec![0.0; m]; m];
+
+    for i in 0 .. m {
+        q[i][i] = 1.0;
+    }
+
+    /*  The last row of a square matrix has nothing below the diagonal.       */
+    let steps: usize = if m > n { n } else { m - 1 };
+
+    for k in 0 .. steps {
+
+        /*  The part of column k on and below the diagonal.                   */
+        let mut v: Vec<f64> = (k .. m).map(|i| r[i][k]).collect();
+        let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        /*  Nothing to zero out in this column.                               */
+        if norm == 0.0 {
+            continue;
+        }
+
+        /*  Pick the sign of alpha opposite to x_1. Then x_1 - alpha is a sum *
+         *  of two numbers with the same sign, avoiding cancellation.         */
+        let alpha: f64 = if v[0] > 0.0 { -norm } else { norm };
+        v[0] = v[0] - alpha;
+
+        let v_norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        for x in v.iter_mut() {
+            *x = *x / v_norm;
+        }
+
+        /*  Apply H to R from the left, R -> R - 2 v (v^T R).                 */
+        for column in 0 .. n {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + v[i - k] * r[i][column];
+            }
+
+            for i in k .. m {
+                r[i][column] = r[i][column] - 2.0 * v[i - k] * dot;
+            }
+        }
+
+        /*  Apply H to Q from the right, Q -> Q - 2 (Q v) v^T.                */
+        for row in 0 .. m {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + q[row][i] * v[i - k];
+            }
+
+            for i in k .. m {
+                q[row][i] = q[row][i] - 2.0 * dot * v[i - k];
+            }
+        }
+    }
+
+    return (q, r);
+}
+/*  End of qr_householder.                                                    */
+
+/*  Computes the largest absolute difference between two matrices.            */
+fn max_difference(a: &[Vec<f64>], b: &[Vec<f64>]) -> f64 {
+    let mut max: f64 = 0.0;
+
+    for (row_a, row_b) in a.iter().zip(b.iter()) {
+        for (x, y) in row_a.iter().zip(row_b.iter()) {
+            max = max.max((x - y).abs());
+        }
+    }
+
+    return max;
+}
+/*  End of max_difference.                                                    */
+
+/*  Main routine used for testing the QR decomposition.                       */
+fn main() {
+
+    /*  An overdetermined 4x3 matrix.                                         */
+    let a: Vec<Vec<f64>> = vec![
+        vec![12.0, -51.0, 4.0],
+        vec![6.0, 167.0, -68.0],
+        vec![-4.0, 24.0, -41.0],
+        vec![1.0, 2.0, 3.0]
+    ];
+
+    let (q, r): (Vec<Vec<f64>>, Vec<Vec<f64>>) = qr_householder(&a);
+
+    println!("R =");
+
+    for row in r.iter() {
+        for x in row.iter() {
+            print!("    {:+12.6}", x);
+        }
+
+        println!();
+    }
+
+    /*  Q is orthogonal, Q^T Q = I.                                           */
+    let mut identity: Vec<Vec<f64>> = vec![vec![0.0; 4]; 4];
+
+    for i in 0 .. 4 {
+        identity[i][i] = 1.0;
+    }
+
+    let q_transpose_q: Vec<Vec<f64>> = matrix_multiply(&transpose(&q), &q);
+    let orthogonality: f64 = max_difference(&q_transpose_q, &identity);
+
+    /*  R is upper triangular, every entry below the diagonal is zero.        */
+    let mut below_diagonal: f64 = 0.0;
+
+    for i in 0 .. r.len() {
+        for j in 0 .. i.min(r[0].len()) {
+            below_diagonal = below_diagonal.max(r[i][j].abs());
+        }
+    }
+
+    /*  QR reconstructs A.                                                    */
+    let reconstruction: f64 = max_difference(&matrix_multiply(&q, &r), &a);
+
+    println!("max |Q^T Q - I| = {:.3E}", orthogonality);
+    println!("max |R below diagonal| = {:.3E}", below_diagonal);
+    println!("max |QR - A| = {:.3E}, within 1e-10: {}",
+             reconstruction, reconstruction < 1.0E-10);
+
+    /*  The empty matrix factors into empty Q and R.                          */
+    let (q_empty, r_empty): (Vec<Vec<f64>>, Vec<Vec<f64>>) =
+        qr_householder(&[]);
+    println!("empty: Q is empty: {}, R is empty: {}",
+             q_empty.is_empty(), r_empty.is_empty());
+}
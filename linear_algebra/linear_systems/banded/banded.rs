@@ -0,0 +1,321 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solving banded linear systems with LU restricted to the band.         *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Solves the square system A x = b using Gaussian elimination with partial  *
+ *  pivoting. The matrix is assumed to be invertible.                         */
+fn gaussian_elimination(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut rhs: Vec<f64> = b.to_vec();
+
+    for k in 0 .. n {
+
+        /*  Swap the row with the largest entry in column k into place.       *
+         *  Dividing by the largest available pivot keeps the multipliers     *
+         *  small.                                                            */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        m.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        /*  Eliminate column k from the rows below.                           */
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+
+            rhs[i] = rhs[i] - factor * rhs[k];
+        }
+    }
+
+    return back_substitution(&m, &rhs, n);
+}
+/*  End of gaussian_elimination.                                              */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Solves a tridiagonal system with the Thomas algorithm, Gaussian           *
+ *  elimination without pivoting specialized to three diagonals. Row i reads  *
+ *  sub[i] x[i-1] + diag[i] x[i] + sup[i] x[i+1] = rhs[i], with sub[0] and    *
+ *  sup[n-1] unused. Without pivoting this is only safe for matrices like     *
+ *  diagonally dominant ones, where no pivot can vanish. Returns None if a    *
+ *  zero pivot is met.                                                        */
+fn thomas(sub: &[f64], diag: &[f64],
+          sup: &[f64], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n: usize = diag.len();
+    let mut c: Vec<f64> = vec![0.0; n];
+    let mut d: Vec<f64> = vec![0.0; n];
+
+    /*  Forward sweep, normalizing each row so its diagonal entry is 1.       */
+    for i in 0 .. n {
+        let below: f64 = if i == 0 { 0.0 } else { sub[i] };
+        let previous_c: f64 = if i == 0 { 0.0 } else { c[i - 1] };
+        let previous_d: f64 = if i == 0 { 0.0 } else { d[i - 1] };
+        let pivot: f64 = diag[i] - below * previous_c;
+
+        if pivot == 0.0 {
+            return None;
+        }
+
+        c[i] = if i + 1 < n { sup[i] / pivot } else { 0.0 };
+        d[i] = (rhs[i] - below * previous_d) / pivot;
+    }
+
+    /*  Back substitution.                                                    */
+    let mut x: Vec<f64> = d;
+
+    for i in (0 .. n.saturating_sub(1)).rev() {
+        x[i] = x[i] - c[i] * x[i + 1];
+    }
+
+    return Some(x);
+}
+/*  End of thomas.                                                            */
+
+/*  Solves A x = rhs for a banded matrix with lower sub-diagonals and upper   *
+ *  super-diagonals. The bands are stored as lower + upper + 1 vectors of     *
+ *  length n, one per diagonal, from the lowest to the highest:               *
+ *                                                                            *
+ *        bands[d][i] = A[i][i + d - lower],   0 <= d <= lower + upper        *
+ *                                                                            *
+ *  so bands[lower] is the main diagonal. Entries whose column falls outside  *
+ *  the matrix are ignored.                                                   *
+ *                                                                            *
+ *  This is Gaussian elimination with partial pivoting, touching only entries *
+ *  that can be nonzero. The pivot for column k is searched in rows k, ..., k *
+ *  + lower only, since entries further down are already zero. Swapping rows  *
+ *  can push entries to the right of the original band, but never past column *
+ *  k + lower + upper, so U has upper + lower super-diagonals. Each row is    *
+ *  stored in a window of 2 lower + upper + 1 columns, enough for this fill.  *
+ *  The cost is O(n lower (lower + upper)) instead of the O(n^3) of a dense   *
+ *  solve, linear in n for a fixed bandwidth. Returns None if the sizes do    *
+ *  not match or the matrix is singular.                                      */
+fn solve_banded(lower: usize, upper: usize,
+                bands: &[Vec<f64>], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n: usize = rhs.len();
+
+    if bands.len() != lower + upper + 1 || bands.iter().any(|b| b.len() != n) {
+        return None;
+    }
+
+    /*  Row i of the work array holds columns i - lower to i + lower + upper, *
+     *  column j at index j + lower - i.                                      */
+    let width: usize = 2 * lower + upper + 1;
+    let mut w: Vec<Vec<f64>> = vec![vec![0.0; width]; n];
+    let mut b: Vec<f64> = rhs.to_vec();
+
+    for d in 0 .. lower + upper + 1 {
+        for i in 0 .. n {
+            let column: isize = i as isize + d as isize - lower as isize;
+
+            if column >= 0 && (column as usize) < n {
+                w[i][d] = bands[d][i];
+            }
+        }
+    }
+
+    for k in 0 .. n {
+        let last_row: usize = (k + lower).min(n - 1);
+        let last_column: usize = (k + lower + upper).min(n - 1);
+
+        /*  Partial pivoting within the band. Column k of row i is at index k *
+         *  + lower - i.                                                      */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. last_row + 1 {
+            if w[i][k + lower - i].abs() > w[pivot][k + lower - pivot].abs() {
+                pivot = i;
+            }
+        }
+
+        if w[pivot][k + lower - pivot] == 0.0 {
+            return None;
+        }
+
+        /*  Both rows have only zeros left of column k, and nothing right of  *
+         *  last_column, so only these columns are swapped.                   */
+        if pivot != k {
+            for j in k .. last_column + 1 {
+                let temp: f64 = w[k][j + lower - k];
+                w[k][j + lower - k] = w[pivot][j + lower - pivot];
+                w[pivot][j + lower - pivot] = temp;
+            }
+
+            b.swap(k, pivot);
+        }
+
+        /*  Eliminate column k from the rows below, within the band.          */
+        for i in k + 1 .. last_row + 1 {
+            let factor: f64 = w[i][k + lower - i] / w[k][lower];
+
+            for j in k .. last_column + 1 {
+                let above: f64 = w[k][j + lower - k];
+                w[i][j + lower - i] = w[i][j + lower - i] - factor * above;
+            }
+
+            b[i] = b[i] - factor * b[k];
+        }
+    }
+
+    /*  Back substitution with the banded upper triangular factor.            */
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let last_column: usize = (i + lower + upper).min(n - 1);
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. last_column + 1 {
+            sum = sum - w[i][j + lower - i] * x[j];
+        }
+
+        x[i] = sum / w[i][lower];
+    }
+
+    return Some(x);
+}
+/*  End of solve_banded.                                                      */
+
+/*  Expands banded storage into a full matrix, for comparing with the dense   *
+ *  solver.                                                                   */
+fn to_dense(lower: usize, bands: &[Vec<f64>], n: usize) -> Vec<Vec<f64>> {
+    let mut a: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for d in 0 .. bands.len() {
+        for i in 0 .. n {
+            let column: isize = i as isize + d as isize - lower as isize;
+
+            if column >= 0 && (column as usize) < n {
+                a[i][column as usize] = bands[d][i];
+            }
+        }
+    }
+
+    return a;
+}
+/*  End of to_dense.                                                          */
+
+/*  Computes the largest difference between two vectors.                      */
+fn max_difference(x: &[f64], y: &[f64]) -> f64 {
+    let mut difference: f64 = 0.0;
+
+    for i in 0 .. x.len() {
+        difference = difference.max((x[i] - y[i]).abs());
+    }
+
+    return difference;
+}
+/*  End of max_difference.                                                    */
+
+/*  Main routine used for testing the banded solver.                          */
+fn main() {
+    let n: usize = 100;
+    let h: f64 = 1.0 / (n + 1) as f64;
+    let pi: f64 = std::f64::consts::PI;
+
+    /*  -u'' = pi^2 sin(pi x) on (0, 1), u(0) = u(1) = 0, with the second     *
+     *  difference (-u_{i-1} + 2 u_i - u_{i+1}) / h^2. The exact solution is  *
+     *  sin(pi x).                                                            */
+    let f: Vec<f64> = (1 .. n + 1)
+        .map(|i| pi * pi * (pi * i as f64 * h).sin() * h * h)
+        .collect();
+
+    let sub: Vec<f64> = vec![-1.0; n];
+    let diag: Vec<f64> = vec![2.0; n];
+    let sup: Vec<f64> = vec![-1.0; n];
+    let tridiagonal: Vec<Vec<f64>> =
+        vec![sub.clone(), diag.clone(), sup.clone()];
+
+    let banded: Vec<f64> = solve_banded(1, 1, &tridiagonal, &f).unwrap();
+    let thomas_x: Vec<f64> = thomas(&sub, &diag, &sup, &f).unwrap();
+    let exact: Vec<f64> =
+        (1 .. n + 1).map(|i| (pi * i as f64 * h).sin()).collect();
+
+    println!("Poisson: banded vs Thomas = {:.3E}",
+             max_difference(&banded, &thomas_x));
+    println!("Poisson: banded vs exact = {:.3E}",
+             max_difference(&banded, &exact));
+
+    /*  A penta-diagonal system, the fourth difference u_{i-2} - 4 u_{i-1} +  *
+     *  6 u_i - 4 u_{i+1} + u_{i+2}, plus a random-looking but fixed          *
+     *  perturbation of the diagonal.                                         */
+    let penta: Vec<Vec<f64>> = vec![
+        vec![1.0; n],
+        vec![-4.0; n],
+        (0 .. n).map(|i| 7.0 + (i as f64).sin()).collect(),
+        vec![-4.0; n],
+        vec![1.0; n]
+    ];
+
+    let rhs: Vec<f64> = (0 .. n).map(|i| (0.1 * i as f64).cos()).collect();
+    let banded: Vec<f64> = solve_banded(2, 2, &penta, &rhs).unwrap();
+    let dense: Vec<f64> = gaussian_elimination(&to_dense(2, &penta, n), &rhs);
+    println!("penta-diagonal: banded vs dense = {:.3E}",
+             max_difference(&banded, &dense));
+
+    /*  A tridiagonal matrix with a zero on the diagonal, which the Thomas    *
+     *  algorithm cannot handle. Pivoting swaps rows and fills in a second    *
+     *  super-diagonal.                                                       */
+    let sub: Vec<f64> = vec![1.0; n];
+    let diag: Vec<f64> =
+        (0 .. n).map(|i| if i % 3 == 0 { 0.0 } else { 0.5 }).collect();
+    let sup: Vec<f64> = vec![2.0; n];
+    let bands: Vec<Vec<f64>> = vec![sub.clone(), diag.clone(), sup.clone()];
+
+    let banded: Vec<f64> = solve_banded(1, 1, &bands, &rhs).unwrap();
+    let dense: Vec<f64> = gaussian_elimination(&to_dense(1, &bands, n), &rhs);
+    println!("zero diagonal: Thomas gives None: {}",
+             thomas(&sub, &diag, &sup, &rhs).is_none());
+    println!("zero diagonal: banded vs dense = {:.3E}",
+             max_difference(&banded, &dense));
+}
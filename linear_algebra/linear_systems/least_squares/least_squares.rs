@@ -0,0 +1,278 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves linear least-squares problems using the normal equations and   *
+ *      using the QR decomposition.                                           *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the product of two matrices.                                     */
+fn matrix_multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let rows: usize = a.len();
+    let columns: usize = b[0].len();
+    let mut product: Vec<Vec<f64>> = vec![vec![0.0; columns]; rows];
+
+    for i in 0 .. rows {
+        for j in 0 .. columns {
+            for k in 0 .. b.len() {
+                product[i][j] = product[i][j] + a[i][k] * b[k][j];
+            }
+        }
+    }
+
+    return product;
+}
+/*  End of matrix_multiply.                                                   */
+
+/*  Computes the transpose of a matrix, swapping rows and columns.            */
+fn transpose(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let mut result: Vec<Vec<f64>> = vec![vec![0.0; a.len()]; a[0].len()];
+
+    for i in 0 .. a.len() {
+        for j in 0 .. a[0].len() {
+            result[j][i] = a[i][j];
+        }
+    }
+
+    return result;
+}
+/*  End of transpose.                                                         */
+
+/*  Computes the QR decomposition A = QR of an m x n matrix with m >= n. Q is *
+ *  an m x m orthogonal matrix and R is an m x n upper triangular matrix.     *
+ *                                                                            *
+ *  A Householder reflection H = I - 2 v v^T, with v a unit vector, reflects  *
+ *  across the plane perpendicular to v. Given a vector x, choosing v         *
+ *  parallel to x - alpha e_1 with |alpha| = ||x|| gives Hx = alpha e_1,      *
+ *  zeroing every entry but the first. Applying one reflection per column     *
+ *  zeroes everything below the diagonal, giving R = H_n ... H_1 A, and Q =   *
+ *  H_1 ... H_n since each H is its own inverse. An empty matrix has empty    *
+ *  factors.                                                                  */
+fn qr_householder(a: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    if a.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let m: usize = a.len();
+    let n: usize = a[0].len();
+
+    /*  R starts as A and is reduced column by column. Q starts as the        *
+     *  identity and accumulates the reflections.                             */
+    let mut r: Vec<Vec<f64>> = a.to_vec();
+    let mut q: Vec<Vec<f64>> = vec![vec![0.0; m]; m];
+
+    for i in 0 .. m {
+        q[i][i] = 1.0;
+    }
+
+    /*  The last row of a square matrix has nothing below the diagonal.       */
+    let steps: usize = if m > n { n } else { m - 1 };
+
+    for k in 0 .. steps {
+
+        /*  The part of column k on and below the diagonal.                   */
+        let mut v: Vec<f64> = (k .. m).map(|i| r[i][k]).collect();
+        let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        /*  Nothing to zero out in this column.                               */
+        if norm == 0.0 {
+            continue;
+        }
+
+        /*  Pick the sign of alpha opposite to x_1. Then x_1 - alpha is a sum *
+         *  of two numbers with the same sign, avoiding cancellation.         */
+        let alpha: f64 = if v[0] > 0.0 { -norm } else { norm };
+        v[0] = v[0] - alpha;
+
+        let v_norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        for x in v.iter_mut() {
+            *x = *x / v_norm;
+        }
+
+        /*  Apply H to R from the left, R -> R - 2 v (v^T R).                 */
+        for column in 0 .. n {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + v[i - k] * r[i][column];
+            }
+
+            for i in k .. m {
+                r[i][column] = r[i][column] - 2.0 * v[i - k] * dot;
+            }
+        }
+
+        /*  Apply H to Q from the right, Q -> Q - 2 (Q v) v^T.                */
+        for row in 0 .. m {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + q[row][i] * v[i - k];
+            }
+
+            for i in k .. m {
+                q[row][i] = q[row][i] - 2.0 * dot * v[i - k];
+            }
+        }
+    }
+
+    return (q, r);
+}
+/*  End of qr_householder.                                                    */
+
+/*  Solves the square system A x = b using Gaussian elimination with partial  *
+ *  pivoting. The matrix is assumed to be invertible.                         */
+fn gaussian_elimination(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut rhs: Vec<f64> = b.to_vec();
+
+    for k in 0 .. n {
+
+        /*  Swap the row with the largest entry in column k into place.       *
+         *  Dividing by the largest available pivot keeps the multipliers     *
+         *  small.                                                            */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        m.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        /*  Eliminate column k from the rows below.                           */
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+
+            rhs[i] = rhs[i] - factor * rhs[k];
+        }
+    }
+
+    return back_substitution(&m, &rhs, n);
+}
+/*  End of gaussian_elimination.                                              */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Computes the x minimizing ||Ax - b|| by solving the normal equations A^T  *
+ *  A x = A^T b. This is simple, but the condition number of A^T A is the     *
+ *  square of that of A, so about twice as many digits are lost.              */
+fn lstsq_normal(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let a_transpose: Vec<Vec<f64>> = transpose(a);
+    let normal_matrix: Vec<Vec<f64>> = matrix_multiply(&a_transpose, a);
+
+    let normal_rhs: Vec<f64> = a_transpose.iter().map(|row| {
+        return row.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<f64>();
+    }).collect();
+
+    return gaussian_elimination(&normal_matrix, &normal_rhs);
+}
+/*  End of lstsq_normal.                                                      */
+
+/*  Computes the x minimizing ||Ax - b|| using A = QR. Q is orthogonal, so    *
+ *  ||Ax - b|| = ||Rx - Q^T b||. The bottom rows of R are zero, and the best  *
+ *  we can do is solve the top n rows exactly. Orthogonal transformations do  *
+ *  not amplify errors, so this only loses digits according to the condition  *
+ *  number of A itself.                                                       */
+fn lstsq_qr(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a[0].len();
+    let (q, r): (Vec<Vec<f64>>, Vec<Vec<f64>>) = qr_householder(a);
+
+    /*  Compute Q^T b, the entries are the dot products of b with the columns *
+     *  of Q.                                                                 */
+    let q_transpose_b: Vec<f64> = (0 .. q.len()).map(|j| {
+        return (0 .. b.len()).map(|i| q[i][j] * b[i]).sum::<f64>();
+    }).collect();
+
+    return back_substitution(&r, &q_transpose_b, n);
+}
+/*  End of lstsq_qr.                                                          */
+
+/*  Main routine used for comparing the two least-squares solvers.            */
+fn main() {
+
+    /*  Fit a line y = c_0 + c_1 x to points near y = 1 + 2x. Each row of A   *
+     *  is [1, x_i].                                                          */
+    let xs: [f64; 6] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys: [f64; 6] = [1.1, 2.9, 5.2, 6.8, 9.1, 11.0];
+    let a: Vec<Vec<f64>> = xs.iter().map(|x| vec![1.0, *x]).collect();
+
+    println!("Line fit, normal equations: {:?}", lstsq_normal(&a, &ys));
+    println!("Line fit, QR:               {:?}", lstsq_qr(&a, &ys));
+
+    /*  A nearly rank-deficient matrix, the Lauchli matrix:                   *
+     *                                                                        *
+     *            [ 1  1 ]                                                    *
+     *        A = [ e  0 ]                                                    *
+     *            [ 0  e ]                                                    *
+     *                                                                        *
+     *  A^T A = [[1 + e^2, 1], [1, 1 + e^2]], and for small e the e^2 is      *
+     *  mostly lost to rounding when added to 1. b = A [0.3, -1.7]^T = [-1.4, *
+     *  0.3 e, -1.7 e]^T is consistent, so the exact least squares solution   *
+     *  is [0.3, -1.7] with zero residual.                                    */
+    let e: f64 = 1.0E-7;
+    let lauchli: Vec<Vec<f64>> = vec![
+        vec![1.0, 1.0],
+        vec![e, 0.0],
+        vec![0.0, e]
+    ];
+    let b: [f64; 3] = [-1.4, 0.3 * e, -1.7 * e];
+
+    let normal: Vec<f64> = lstsq_normal(&lauchli, &b);
+    let qr: Vec<f64> = lstsq_qr(&lauchli, &b);
+    let normal_error: f64 = (normal[0] - 0.3).abs() + (normal[1] + 1.7).abs();
+    let qr_error: f64 = (qr[0] - 0.3).abs() + (qr[1] + 1.7).abs();
+
+    println!("Near-collinear, normal equations: {:?}, error = {:.3E}",
+             normal, normal_error);
+    println!("Near-collinear, QR:               {:?}, error = {:.3E}",
+             qr, qr_error);
+    println!("QR is more accurate: {}", qr_error < normal_error);
+}
@@ -0,0 +1,162 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the condition number of a matrix in the 1-norm.              *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Solves the square system A x = b using Gaussian elimination with partial  *
+ *  pivoting. The matrix is assumed to be invertible.                         */
+fn gaussian_elimination(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut rhs: Vec<f64> = b.to_vec();
+
+    for k in 0 .. n {
+
+        /*  Swap the row with the largest entry in column k into place.       *
+         *  Dividing by the largest available pivot keeps the multipliers     *
+         *  small.                                                            */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        m.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        /*  Eliminate column k from the rows below.                           */
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+
+            rhs[i] = rhs[i] - factor * rhs[k];
+        }
+    }
+
+    return back_substitution(&m, &rhs, n);
+}
+/*  End of gaussian_elimination.                                              */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Computes the 1-norm, the largest absolute column sum. This is the         *
+ *  operator norm for vectors measured with ||x||_1 = |x_1| + ... + |x_n|.    */
+fn norm_one(a: &[Vec<f64>]) -> f64 {
+
+    if a.is_empty() {
+        return 0.0;
+    }
+
+    let mut max: f64 = 0.0;
+
+    for j in 0 .. a[0].len() {
+        let mut sum: f64 = 0.0;
+
+        for row in a.iter() {
+            sum = sum + row[j].abs();
+        }
+
+        max = max.max(sum);
+    }
+
+    return max;
+}
+/*  End of norm_one.                                                          */
+
+/*  Computes the condition number ||A|| ||A^{-1}|| in the 1-norm. The inverse *
+ *  is computed one column at a time, column j of A^{-1} being the solution   *
+ *  of A x = e_j.                                                             *
+ *                                                                            *
+ *  The condition number bounds how much relative errors in b are amplified   *
+ *  in the solution of Ax = b. Roughly, log10 of the condition number is the  *
+ *  number of decimal digits lost when solving. A value near 1 is ideal,      *
+ *  while a value near 10^16 means a double precision solution may have no    *
+ *  correct digits at all.                                                    */
+fn condition_number_estimate(a: &[Vec<f64>]) -> f64 {
+    let n: usize = a.len();
+    let mut inverse: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for j in 0 .. n {
+        let mut e_j: Vec<f64> = vec![0.0; n];
+        e_j[j] = 1.0;
+
+        let column: Vec<f64> = gaussian_elimination(a, &e_j);
+
+        for i in 0 .. n {
+            inverse[i][j] = column[i];
+        }
+    }
+
+    return norm_one(a) * norm_one(&inverse);
+}
+/*  End of condition_number_estimate.                                         */
+
+/*  Main routine used for testing the condition number.                       */
+fn main() {
+
+    /*  A matrix close to the identity is well-conditioned.                   */
+    let good: Vec<Vec<f64>> = vec![
+        vec![1.0, 0.1, 0.0],
+        vec![0.1, 1.0, 0.1],
+        vec![0.0, 0.1, 1.0]
+    ];
+
+    println!("Nearly the identity: {}", condition_number_estimate(&good));
+
+    /*  The Hilbert-like matrices with entries 1 / (i + j + 1) have columns   *
+     *  that are very nearly parallel, and the condition number explodes.     */
+    for n in 2 .. 9 {
+        let hilbert: Vec<Vec<f64>> = (0 .. n).map(|i| {
+            return (0 .. n).map(|j| 1.0 / ((i + j + 1) as f64)).collect();
+        }).collect();
+
+        println!("Hilbert-like, n = {}: {:.6E}",
+                 n, condition_number_estimate(&hilbert));
+    }
+}
@@ -0,0 +1,114 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the 1-norm, infinity-norm, and Frobenius norm of a matrix.   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the Frobenius norm, the square root of the sum of the squares of *
+ *  the entries. This is the Euclidean norm of the matrix viewed as one long  *
+ *  vector.                                                                   */
+fn norm_frobenius(a: &[Vec<f64>]) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for row in a.iter() {
+        for x in row.iter() {
+            sum = sum + x * x;
+        }
+    }
+
+    return sum.sqrt();
+}
+/*  End of norm_frobenius.                                                    */
+
+/*  Computes the 1-norm, the largest absolute column sum. This is the         *
+ *  operator norm for vectors measured with ||x||_1 = |x_1| + ... + |x_n|.    */
+fn norm_one(a: &[Vec<f64>]) -> f64 {
+
+    if a.is_empty() {
+        return 0.0;
+    }
+
+    let mut max: f64 = 0.0;
+
+    for j in 0 .. a[0].len() {
+        let mut sum: f64 = 0.0;
+
+        for row in a.iter() {
+            sum = sum + row[j].abs();
+        }
+
+        max = max.max(sum);
+    }
+
+    return max;
+}
+/*  End of norm_one.                                                          */
+
+/*  Computes the infinity-norm, the largest absolute row sum. This is the     *
+ *  operator norm for vectors measured with ||x||_inf = max |x_i|.            */
+fn norm_inf(a: &[Vec<f64>]) -> f64 {
+    let mut max: f64 = 0.0;
+
+    for row in a.iter() {
+        let sum: f64 = row.iter().map(|x| x.abs()).sum::<f64>();
+        max = max.max(sum);
+    }
+
+    return max;
+}
+/*  End of norm_inf.                                                          */
+
+/*  Main routine used for testing the matrix norms.                           */
+fn main() {
+
+    /*  For this matrix, by hand:                                             *
+     *        Frobenius: sqrt(1 + 4 + 9 + 16 + 25 + 36) = sqrt(91)            *
+     *        One:       max(1 + 4, 2 + 5, 3 + 6) = 9                         *
+     *        Infinity:  max(1 + 2 + 3, 4 + 5 + 6) = 15                       *
+     *  The signs do not matter, only absolute values are used.               */
+    let a: Vec<Vec<f64>> = vec![
+        vec![1.0, -2.0, 3.0],
+        vec![-4.0, 5.0, -6.0]
+    ];
+
+    println!("Frobenius = {}, expected {}", norm_frobenius(&a), 91.0f64.sqrt());
+    println!("One       = {}, expected 9", norm_one(&a));
+    println!("Infinity  = {}, expected 15", norm_inf(&a));
+
+    /*  The identity matrix of size n has Frobenius norm sqrt(n), but its     *
+     *  operator norms are all 1.                                             */
+    for &n in [1, 4, 9, 100].iter() {
+        let mut identity: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+        for i in 0 .. n {
+            identity[i][i] = 1.0;
+        }
+
+        println!("n = {:>3}: Frobenius = {}, sqrt(n) = {}, one = {}, inf = {}",
+                 n, norm_frobenius(&identity), (n as f64).sqrt(),
+                 norm_one(&identity), norm_inf(&identity));
+    }
+}
@@ -0,0 +1,99 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the transpose of a matrix, and transposes square matrices in *
+ *      place.                                                                *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the transpose of an m x n matrix, the n x m matrix b with        *
+ *  b[j][i] = a[i][j].                                                        */
+fn transpose(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+
+    /*  The transpose of the empty matrix is empty.                           */
+    if a.is_empty() {
+        return Vec::new();
+    }
+
+    let rows: usize = a.len();
+    let columns: usize = a[0].len();
+    let mut result: Vec<Vec<f64>> = vec![vec![0.0; rows]; columns];
+
+    for i in 0 .. rows {
+        for j in 0 .. columns {
+            result[j][i] = a[i][j];
+        }
+    }
+
+    return result;
+}
+/*  End of transpose.                                                         */
+
+/*  Transposes a square matrix without allocating a new one. Entries on the   *
+ *  diagonal stay put, and each entry above the diagonal is swapped with its  *
+ *  mirror image below it.                                                    */
+fn transpose_square_inplace(a: &mut [Vec<f64>]) {
+    let n: usize = a.len();
+
+    /*  Only loop over j > i. Looping over every pair would swap each pair    *
+     *  twice, undoing the work.                                              */
+    for i in 0 .. n {
+        for j in i + 1 .. n {
+            let temp: f64 = a[i][j];
+            a[i][j] = a[j][i];
+            a[j][i] = temp;
+        }
+    }
+}
+/*  End of transpose_square_inplace.                                          */
+
+/*  Main routine used for testing the transpose functions.                    */
+fn main() {
+
+    /*  A 2x3 matrix, its transpose is 3x2.                                   */
+    let a: Vec<Vec<f64>> = vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0]
+    ];
+
+    let a_transpose: Vec<Vec<f64>> = transpose(&a);
+    println!("A   = {:?}", a);
+    println!("A^T = {:?}", a_transpose);
+
+    /*  Transposing twice gives back the original matrix.                     */
+    println!("(A^T)^T == A: {}", transpose(&a_transpose) == a);
+
+    /*  The in-place version agrees with the allocating one.                  */
+    let mut square: Vec<Vec<f64>> = vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0]
+    ];
+
+    let expected: Vec<Vec<f64>> = transpose(&square);
+    transpose_square_inplace(&mut square);
+    println!("In place: {:?}", square);
+    println!("Matches transpose: {}", square == expected);
+}
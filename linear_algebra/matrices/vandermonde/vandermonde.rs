@@ -0,0 +1,117 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Builds Vandermonde matrices and checks the determinant formula.       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Builds the Vandermonde matrix whose row i is [1, x_i, x_i^2, ...,         *
+ *  x_i^degree].                                                              */
+fn vandermonde(xs: &[f64], degree: usize) -> Vec<Vec<f64>> {
+    let mut matrix: Vec<Vec<f64>> = Vec::with_capacity(xs.len());
+
+    for x in xs.iter() {
+        let mut row: Vec<f64> = Vec::with_capacity(degree + 1);
+        let mut power: f64 = 1.0;
+
+        for _ in 0 ..= degree {
+            row.push(power);
+            power = power * x;
+        }
+
+        matrix.push(row);
+    }
+
+    return matrix;
+}
+/*  End of vandermonde.                                                       */
+
+/*  Computes the determinant of a square matrix using Gaussian elimination    *
+ *  with partial pivoting. The determinant is the product of the pivots, with *
+ *  a sign flip for every row swap.                                           */
+fn determinant(a: &[Vec<f64>]) -> f64 {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut det: f64 = 1.0;
+
+    for k in 0 .. n {
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        /*  A zero column means the matrix is singular.                       */
+        if m[pivot][k] == 0.0 {
+            return 0.0;
+        }
+
+        if pivot != k {
+            m.swap(k, pivot);
+            det = -det;
+        }
+
+        det = det * m[k][k];
+
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+        }
+    }
+
+    return det;
+}
+/*  End of determinant.                                                       */
+
+/*  Main routine used for testing the Vandermonde matrix.                     */
+fn main() {
+
+    /*  For three points the determinant of the square Vandermonde matrix is: *
+     *                                                                        *
+     *            [ 1  x_0  x_0^2 ]                                           *
+     *        det [ 1  x_1  x_1^2 ] = (x_1 - x_0)(x_2 - x_0)(x_2 - x_1)       *
+     *            [ 1  x_2  x_2^2 ]                                           *
+     *                                                                        *
+     *  and in general it is the product of x_j - x_i over i < j.             */
+    let xs: [f64; 3] = [0.5, 2.0, -1.5];
+    let matrix: Vec<Vec<f64>> = vandermonde(&xs, 2);
+
+    for row in matrix.iter() {
+        println!("    {:?}", row);
+    }
+
+    let formula: f64 = (xs[1] - xs[0]) * (xs[2] - xs[0]) * (xs[2] - xs[1]);
+    println!("det = {}, formula = {}", determinant(&matrix), formula);
+
+    /*  If two nodes are equal, two rows are equal and the matrix is          *
+     *  singular. This is why interpolation needs distinct nodes.             */
+    let repeated: Vec<Vec<f64>> = vandermonde(&[1.0, 3.0, 1.0], 2);
+    println!("det with a repeated node = {}", determinant(&repeated));
+}
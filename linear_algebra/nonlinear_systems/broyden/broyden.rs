@@ -0,0 +1,278 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves systems of nonlinear equations using Broyden's method, and     *
+ *      compares the cost with Newton's method using finite differences.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Atomic counter used for tallying function evaluations.                    */
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*  Type for a function F: R^n -> R^n.                                        */
+type VectorFunc = fn(&[f64]) -> Vec<f64>;
+
+/*  Solves the square system A x = b using Gaussian elimination with partial  *
+ *  pivoting. The matrix is assumed to be invertible.                         */
+fn gaussian_elimination(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut rhs: Vec<f64> = b.to_vec();
+
+    for k in 0 .. n {
+
+        /*  Swap the row with the largest entry in column k into place.       *
+         *  Dividing by the largest available pivot keeps the multipliers     *
+         *  small.                                                            */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        m.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        /*  Eliminate column k from the rows below.                           */
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+
+            rhs[i] = rhs[i] - factor * rhs[k];
+        }
+    }
+
+    return back_substitution(&m, &rhs, n);
+}
+/*  End of gaussian_elimination.                                              */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Approximates the Jacobian of F at x with forward differences. Column j is *
+ *  (F(x + h e_j) - F(x)) / h, where e_j is the j^th standard basis vector.   *
+ *  The error is O(h) from truncation plus O(epsilon / h) from rounding, and  *
+ *  h ~= sqrt(epsilon) balances the two.                                      */
+fn numerical_jacobian(f: VectorFunc, x: &[f64], h: f64) -> Vec<Vec<f64>> {
+    let n: usize = x.len();
+    let f_x: Vec<f64> = f(x);
+    let mut jacobian: Vec<Vec<f64>> = vec![vec![0.0; n]; f_x.len()];
+    let mut perturbed: Vec<f64> = x.to_vec();
+
+    for j in 0 .. n {
+
+        /*  Nudge the j^th component, evaluate, and restore it.               */
+        perturbed[j] = x[j] + h;
+        let f_perturbed: Vec<f64> = f(&perturbed);
+        perturbed[j] = x[j];
+
+        for i in 0 .. f_x.len() {
+            jacobian[i][j] = (f_perturbed[i] - f_x[i]) / h;
+        }
+    }
+
+    return jacobian;
+}
+/*  End of numerical_jacobian.                                                */
+
+/*  Solves F(x) = 0 with Newton's method, approximating the Jacobian with     *
+ *  forward differences at every step. Each step costs n + 2 evaluations of   *
+ *  F: one at x, and n + 1 to build the Jacobian.                             */
+fn newton_system_numerical(f: VectorFunc, x0: &[f64]) -> Vec<f64> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+    const EPSILON: f64 = 1.0E-14;
+    const H: f64 = 1.0E-8;
+
+    let mut x: Vec<f64> = x0.to_vec();
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let minus_f: Vec<f64> = f(&x).iter().map(|y| -y).collect();
+        let jacobian: Vec<Vec<f64>> = numerical_jacobian(f, &x, H);
+        let dx: Vec<f64> = gaussian_elimination(&jacobian, &minus_f);
+        let mut step_size: f64 = 0.0;
+
+        for i in 0 .. x.len() {
+            x[i] = x[i] + dx[i];
+            step_size = step_size.max(dx[i].abs());
+        }
+
+        if step_size <= EPSILON {
+            break;
+        }
+    }
+
+    return x;
+}
+/*  End of newton_system_numerical.                                           */
+
+/*  Solves F(x) = 0 with Broyden's method, starting at x0.                    *
+ *                                                                            *
+ *  Instead of recomputing the Jacobian, we keep an approximation B of its    *
+ *  inverse and correct it after every step. With dx the step taken and df    *
+ *  the resulting change in F, the true inverse Jacobian J^{-1} roughly       *
+ *  satisfies J^{-1} df = dx. The "good" Broyden update is the smallest       *
+ *  change to B, a rank-one correction, making this hold exactly:             *
+ *                                                                            *
+ *                      (dx - B df) dx^T B                                    *
+ *        B  ->  B  +  --------------------                                   *
+ *                          dx^T B df                                         *
+ *                                                                            *
+ *  The steps are x -> x - B F(x), just like Newton's method with B in place  *
+ *  of J^{-1}. Each step needs one new evaluation of F and no linear solve.   *
+ *  The convergence is superlinear, slower than Newton's method per step but  *
+ *  much cheaper per step.                                                    */
+fn broyden(f: VectorFunc, x0: &[f64]) -> Vec<f64> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 100;
+    const EPSILON: f64 = 1.0E-14;
+    const H: f64 = 1.0E-8;
+
+    let n: usize = x0.len();
+    let mut x: Vec<f64> = x0.to_vec();
+    let mut f_x: Vec<f64> = f(&x);
+
+    /*  Start with the inverse of a finite difference Jacobian. This is the   *
+     *  only time the Jacobian is computed.                                   */
+    let jacobian: Vec<Vec<f64>> = numerical_jacobian(f, &x, H);
+    let mut b: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for j in 0 .. n {
+        let mut e_j: Vec<f64> = vec![0.0; n];
+        e_j[j] = 1.0;
+
+        let column: Vec<f64> = gaussian_elimination(&jacobian, &e_j);
+
+        for i in 0 .. n {
+            b[i][j] = column[i];
+        }
+    }
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+
+        /*  The quasi-Newton step dx = -B F(x).                               */
+        let dx: Vec<f64> = (0 .. n).map(|i| {
+            return -(0 .. n).map(|j| b[i][j] * f_x[j]).sum::<f64>();
+        }).collect();
+
+        let mut step_size: f64 = 0.0;
+
+        for i in 0 .. n {
+            x[i] = x[i] + dx[i];
+            step_size = step_size.max(dx[i].abs());
+        }
+
+        if step_size <= EPSILON {
+            break;
+        }
+
+        /*  The change in F over this step.                                   */
+        let f_new: Vec<f64> = f(&x);
+        let df: Vec<f64> = (0 .. n).map(|i| f_new[i] - f_x[i]).collect();
+        f_x = f_new;
+
+        /*  Compute B df and dx^T B, then apply the rank-one update.          */
+        let b_df: Vec<f64> = (0 .. n).map(|i| {
+            return (0 .. n).map(|j| b[i][j] * df[j]).sum::<f64>();
+        }).collect();
+
+        let dx_b: Vec<f64> = (0 .. n).map(|j| {
+            return (0 .. n).map(|i| dx[i] * b[i][j]).sum::<f64>();
+        }).collect();
+
+        let denominator: f64 = (0 .. n).map(|i| dx[i] * b_df[i]).sum::<f64>();
+
+        /*  If the denominator vanishes the update is undefined. We are       *
+         *  either at the root already or stuck, so stop.                     */
+        if denominator == 0.0 {
+            break;
+        }
+
+        for i in 0 .. n {
+            for j in 0 .. n {
+                b[i][j] = b[i][j] + (dx[i] - b_df[i]) * dx_b[j] / denominator;
+            }
+        }
+    }
+
+    return x;
+}
+/*  End of broyden.                                                           */
+
+/*  Counter for the number of times circle_and_line is called.                */
+static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/*  The circle x^2 + y^2 = 4 and the line y = x - 1, written as F(x, y) = 0.  */
+fn circle_and_line(v: &[f64]) -> Vec<f64> {
+    let (x, y): (f64, f64) = (v[0], v[1]);
+    EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+    return vec![x*x + y*y - 4.0, y - x + 1.0];
+}
+
+/*  Main routine used for comparing Broyden's method with Newton's method.    */
+fn main() {
+
+    /*  One intersection is at ((1 + sqrt(7)) / 2, (sqrt(7) - 1) / 2).        */
+    let sqrt_7: f64 = 7.0f64.sqrt();
+    let exact: [f64; 2] = [0.5 * (1.0 + sqrt_7), 0.5 * (sqrt_7 - 1.0)];
+    let start: [f64; 2] = [2.0, 0.0];
+
+    EVALUATIONS.store(0, Ordering::SeqCst);
+    let newton: Vec<f64> = newton_system_numerical(circle_and_line, &start);
+    let newton_evaluations: usize = EVALUATIONS.load(Ordering::SeqCst);
+
+    EVALUATIONS.store(0, Ordering::SeqCst);
+    let quasi_newton: Vec<f64> = broyden(circle_and_line, &start);
+    let broyden_evaluations: usize = EVALUATIONS.load(Ordering::SeqCst);
+
+    let newton_error: f64 = (newton[0] - exact[0]).abs()
+                          + (newton[1] - exact[1]).abs();
+
+    let broyden_error: f64 = (quasi_newton[0] - exact[0]).abs()
+                           + (quasi_newton[1] - exact[1]).abs();
+
+    println!("Newton:  ({:.16}, {:.16}), error = {:.3E}, evaluations = {}",
+             newton[0], newton[1], newton_error, newton_evaluations);
+
+    println!("Broyden: ({:.16}, {:.16}), error = {:.3E}, evaluations = {}",
+             quasi_newton[0], quasi_newton[1], broyden_error,
+             broyden_evaluations);
+}
@@ -0,0 +1,239 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Solves systems of nonlinear equations using Newton's method.          *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Types for a function F: R^n -> R^n and for its Jacobian matrix, stored as *
+ *  a vector of rows, J[i][j] being the partial derivative of F_i with        *
+ *  respect to x_j.                                                           */
+type VectorFunc = fn(&[f64]) -> Vec<f64>;
+type JacobianFunc = fn(&[f64]) -> Vec<Vec<f64>>;
+
+/*  Solves the square system A x = b using Gaussian elimination with partial  *
+ *  pivoting. The matrix is assumed to be invertible.                         */
+fn gaussian_elimination(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a.len();
+    let mut m: Vec<Vec<f64>> = a.to_vec();
+    let mut rhs: Vec<f64> = b.to_vec();
+
+    for k in 0 .. n {
+
+        /*  Swap the row with the largest entry in column k into place.       *
+         *  Dividing by the largest available pivot keeps the multipliers     *
+         *  small.                                                            */
+        let mut pivot: usize = k;
+
+        for i in k + 1 .. n {
+            if m[i][k].abs() > m[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        m.swap(k, pivot);
+        rhs.swap(k, pivot);
+
+        /*  Eliminate column k from the rows below.                           */
+        for i in k + 1 .. n {
+            let factor: f64 = m[i][k] / m[k][k];
+
+            for j in k .. n {
+                m[i][j] = m[i][j] - factor * m[k][j];
+            }
+
+            rhs[i] = rhs[i] - factor * rhs[k];
+        }
+    }
+
+    return back_substitution(&m, &rhs, n);
+}
+/*  End of gaussian_elimination.                                              */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Performs Newton's method for F(x) = 0 starting at x0, using jac to        *
+ *  compute the Jacobian matrix at each step. Near x, F(x + dx) ~= F(x) +     *
+ *  J(x) dx, and setting this to zero gives the linear system J(x) dx =       *
+ *  -F(x). Each step solves this system and moves to x + dx. In one dimension *
+ *  this is the usual x -> x - f(x) / f'(x).                                  */
+fn newton_iteration<J>(f: VectorFunc, jac: J, x0: &[f64]) -> Vec<f64>
+    where J: Fn(&[f64]) -> Vec<Vec<f64>> {
+
+    /*  Convergence is quadratic near a root, few iterations are needed.      */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+
+    /*  The size of the step below which we stop.                             */
+    const EPSILON: f64 = 1.0E-14;
+
+    let mut x: Vec<f64> = x0.to_vec();
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let minus_f: Vec<f64> = f(&x).iter().map(|y| -y).collect();
+        let dx: Vec<f64> = gaussian_elimination(&jac(&x), &minus_f);
+        let mut step_size: f64 = 0.0;
+
+        for i in 0 .. x.len() {
+            x[i] = x[i] + dx[i];
+            step_size = step_size.max(dx[i].abs());
+        }
+
+        if step_size <= EPSILON {
+            break;
+        }
+    }
+
+    return x;
+}
+/*  End of newton_iteration.                                                  */
+
+/*  Solves F(x) = 0 with Newton's method, starting at x0, given the Jacobian. */
+fn newton_system(f: VectorFunc, jac: JacobianFunc, x0: &[f64]) -> Vec<f64> {
+    return newton_iteration(f, jac, x0);
+}
+/*  End of newton_system.                                                     */
+
+/*  Approximates the Jacobian of F at x with forward differences. Column j is *
+ *  (F(x + h e_j) - F(x)) / h, where e_j is the j^th standard basis vector.   *
+ *  The error is O(h) from truncation plus O(epsilon / h) from rounding, and  *
+ *  h ~= sqrt(epsilon) balances the two.                                      */
+fn numerical_jacobian(f: VectorFunc, x: &[f64], h: f64) -> Vec<Vec<f64>> {
+    let n: usize = x.len();
+    let f_x: Vec<f64> = f(x);
+    let mut jacobian: Vec<Vec<f64>> = vec![vec![0.0; n]; f_x.len()];
+    let mut perturbed: Vec<f64> = x.to_vec();
+
+    for j in 0 .. n {
+
+        /*  Nudge the j^th component, evaluate, and restore it.               */
+        perturbed[j] = x[j] + h;
+        let f_perturbed: Vec<f64> = f(&perturbed);
+        perturbed[j] = x[j];
+
+        for i in 0 .. f_x.len() {
+            jacobian[i][j] = (f_perturbed[i] - f_x[i]) / h;
+        }
+    }
+
+    return jacobian;
+}
+/*  End of numerical_jacobian.                                                */
+
+/*  Solves F(x) = 0 with Newton's method, starting at x0, approximating the   *
+ *  Jacobian with finite differences. The inexact Jacobian makes the          *
+ *  convergence slightly slower than quadratic, but the root found is the     *
+ *  same.                                                                     */
+fn newton_system_numerical(f: VectorFunc, x0: &[f64]) -> Vec<f64> {
+
+    /*  Roughly the square root of double precision epsilon.                  */
+    const H: f64 = 1.0E-8;
+
+    return newton_iteration(f, |x: &[f64]| numerical_jacobian(f, x, H), x0);
+}
+/*  End of newton_system_numerical.                                           */
+
+/*  The circle x^2 + y^2 = 4 and the line y = x - 1, written as F(x, y) = 0.  */
+fn circle_and_line(v: &[f64]) -> Vec<f64> {
+    let (x, y): (f64, f64) = (v[0], v[1]);
+    return vec![x*x + y*y - 4.0, y - x + 1.0];
+}
+
+/*  The Jacobian of circle_and_line.                                          */
+fn circle_and_line_jacobian(v: &[f64]) -> Vec<Vec<f64>> {
+    let (x, y): (f64, f64) = (v[0], v[1]);
+    return vec![vec![2.0 * x, 2.0 * y], vec![-1.0, 1.0]];
+}
+
+/*  Main routine used for testing Newton's method for systems.                */
+fn main() {
+
+    /*  Substituting y = x - 1 gives 2x^2 - 2x - 3 = 0, so the intersections  *
+     *  are at x = (1 +/- sqrt(7)) / 2. Starting near each, Newton's method   *
+     *  should find the corresponding point.                                  */
+    let sqrt_7: f64 = 7.0f64.sqrt();
+    let expected: [[f64; 2]; 2] = [
+        [0.5 * (1.0 + sqrt_7), 0.5 * (sqrt_7 - 1.0)],
+        [0.5 * (1.0 - sqrt_7), -0.5 * (1.0 + sqrt_7)]
+    ];
+
+    let starts: [[f64; 2]; 2] = [[2.0, 0.0], [-1.0, -2.0]];
+
+    for (start, exact) in starts.iter().zip(expected.iter()) {
+        let root: Vec<f64> =
+            newton_system(circle_and_line, circle_and_line_jacobian, start);
+
+        let numerical_root: Vec<f64> =
+            newton_system_numerical(circle_and_line, start);
+
+        let error: f64 = (root[0] - exact[0]).abs()
+                       + (root[1] - exact[1]).abs();
+
+        let numerical_error: f64 = (numerical_root[0] - exact[0]).abs()
+                                 + (numerical_root[1] - exact[1]).abs();
+
+        println!("Start {:?}: ({:.16}, {:.16}), within 1e-8: {}",
+                 start, root[0], root[1], error < 1.0E-8);
+
+        println!("    Numerical Jacobian: ({:.16}, {:.16}), within 1e-8: {}",
+                 numerical_root[0], numerical_root[1],
+                 numerical_error < 1.0E-8);
+    }
+
+    /*  The forward difference error is proportional to h. Here the second    *
+     *  derivatives are at most 2, so the error is at most about h.           */
+    let point: [f64; 2] = [0.7, -1.3];
+    let exact_jacobian: Vec<Vec<f64>> = circle_and_line_jacobian(&point);
+
+    for &h in [1.0E-2, 1.0E-4, 1.0E-6].iter() {
+        let approximate: Vec<Vec<f64>> =
+            numerical_jacobian(circle_and_line, &point, h);
+
+        let mut error: f64 = 0.0;
+
+        for i in 0 .. 2 {
+            for j in 0 .. 2 {
+                let difference: f64 = approximate[i][j] - exact_jacobian[i][j];
+                error = error.max(difference.abs());
+            }
+        }
+
+        println!("h = {:.0E}: Jacobian error = {:.3E}, error <= 2h: {}",
+                 h, error, error <= 2.0 * h);
+    }
+}
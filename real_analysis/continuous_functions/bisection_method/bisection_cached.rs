@@ -0,0 +1,240 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Bisection with cached evaluations, counting the calls made to f.      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
+
+/*  Computes the root of a function using the bisection method.               */
+fn bisection_method<F: Function>(f: F, a: f64, b: f64) -> f64 {
+
+    /*  Tell the algorithm to stop after several iterations to avoid an       *
+     *  infinite loop. Double precision numbers have 52 bits in the mantissa, *
+     *  so if |b - a| ~= 1, after 52 iterations of bisection we will get as   *
+     *  close as we can to the root. To allow for |b - a| to be larger, halt  *
+     *  the algorithm after at most 64 steps.                                 */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+
+    /*  Getting exact roots is hard using floating-point numbers. Allow a     *
+     *  tolerance in our computation. This value is double precision epsilon. */
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    /*  The midpoint for the bisection method. This will update as we iterate.*/
+    let mut midpoint: f64;
+
+    /*  We do not require a < b, nor do we require f(a) < f(b). We only need  *
+     *  one of these to evaluate to a negative under f and one to evaluate to *
+     *  positive. We will call the negative entry left and positive one right.*/
+    let mut left: f64;
+    let mut right: f64;
+
+    /*  Evaluate f at the two endpoints to determine which is positive and    *
+     *  which is negative. We transform [a, b] to [left, right] by doing this.*/
+    let a_eval: f64 = f(a);
+    let b_eval: f64 = f(b);
+
+    /*  Rare case, f(a) = 0. Return a, no bisection needed.                   */
+    if a_eval == 0.0 {
+        return a;
+    }
+
+    /*  Similarly, if f(b) = 0, then we have already found the root. Return b.*/
+    if b_eval == 0.0 {
+        return b;
+    }
+
+    /*  Compare the two evaluations and set left and right accordingly.       */
+    if a_eval < b_eval {
+
+        /*  If both evaluations are negative, or if both are positive, then   *
+         *  the bisection method will not work. Return NaN.                   */
+        if b_eval < 0.0 || a_eval > 0.0 {
+            return (a - a) / (a - a);
+        }
+
+        /*  Otherwise, since f(a) < f(b), set left = a and right = b.         */
+        left = a;
+        right = b;
+
+    /*  In this case the function starts positive and tends to a negative.    */
+    } else {
+        /*  Same sanity check as before. We need one evaluation to be         *
+         *  negative and one to be positive. Abort if both have the same sign.*/
+        if a_eval < 0.0 || b_eval > 0.0 {
+            return (a - a) / (a - a);
+        }
+
+        /*  Since f(a) > f(b), set left = b and right = a.                    */
+        left = b;
+        right = a;
+    }
+
+    /*  Start the bisection method. Compute the midpoint of a and b.          */
+    midpoint = 0.5 * (a + b);
+
+    /*  Iteratively divide the range in half to find the root.                */
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+
+        /*  If f(x) is very small, we are close to a root and can break out   *
+         *  of this for loop. Check for this.                                 */
+        let eval: f64 = f(midpoint);
+
+        if eval.abs() <= EPSILON {
+            break;
+        }
+
+        /*  Apply bisection to get a better approximation for the root. We    *
+         *  have f(left) < 0 < f(right). If f(midpoint) < 0, replace the      *
+         *  interval [left, right] with [midpoint, right]. Set left to the    *
+         *  midpoint and reset the midpoint to be closer to right.            */
+        if eval < 0.0 {
+            left = midpoint;
+            midpoint = 0.5 * (midpoint + right);
+
+        /*  In the other case, f(midpoint) > 0, we replace right with the     *
+         *  midpoint, changing [left, right] into [left, midpoint]. We then   *
+         *  set the midpoint to be closer to left.                            */
+        } else {
+            right = midpoint;
+            midpoint = 0.5 * (left + midpoint);
+        }
+    }
+
+    /*  After n iterations, we are no more than |b - a| / 2^n away from the   *
+     *  root of the function. 1 / 2^n goes to zero very quickly, meaning the  *
+     *  convergence is very quick.                                            */
+    return midpoint;
+}
+/*  End of bisection_method.                                                  */
+
+/*  Computes the root of a function using the bisection method, evaluating f  *
+ *  as few times as possible. f is evaluated once at each endpoint, and after *
+ *  that only once per iteration, at the midpoint. The value f(left) is       *
+ *  stored, and when the midpoint replaces left, its stored value is reused   *
+ *  instead of calling f again. The signs are compared using these stored     *
+ *  values. Returns the root and the total number of evaluations, which is    *
+ *  always the number of iterations plus two.                                 */
+fn bisection_cached<F: Function>(f: F, a: f64, b: f64) -> (f64, u32) {
+
+    /*  Same limits as the original bisection method.                         */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    let mut left: f64 = a;
+    let mut right: f64 = b;
+    let mut left_eval: f64 = f(a);
+    let right_eval: f64 = f(b);
+    let mut evals: u32 = 2;
+
+    if left_eval == 0.0 {
+        return (a, evals);
+    }
+
+    if right_eval == 0.0 {
+        return (b, evals);
+    }
+
+    /*  The method needs a sign change on the interval.                       */
+    if (left_eval < 0.0) == (right_eval < 0.0) {
+        return ((a - a) / (a - a), evals);
+    }
+
+    let mut midpoint: f64 = 0.5 * (left + right);
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let eval: f64 = f(midpoint);
+        evals = evals + 1;
+
+        if eval.abs() <= EPSILON {
+            break;
+        }
+
+        /*  If f(midpoint) has the same sign as f(left) the root is in        *
+         *  [midpoint, right], and the new value of f(left) is the one just   *
+         *  computed. Otherwise the root is in [left, midpoint], and f(left)  *
+         *  is unchanged. Either way f is never called at an endpoint twice.  */
+        if (eval < 0.0) == (left_eval < 0.0) {
+            left = midpoint;
+            left_eval = eval;
+        } else {
+            right = midpoint;
+        }
+
+        midpoint = 0.5 * (left + right);
+    }
+
+    return (midpoint, evals);
+}
+/*  End of bisection_cached.                                                  */
+
+/*  Counter for the number of times counted_sin is called.                    */
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/*  The sine function, counting each call. This stands in for an expensive    *
+ *  function.                                                                 */
+fn counted_sin(x: f64) -> f64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    return x.sin();
+}
+
+/*  Main routine used for comparing with the original bisection method.       */
+fn main() {
+    const A: f64 = 3.0;
+    const B: f64 = 4.0;
+
+    CALLS.store(0, Ordering::SeqCst);
+    let original: f64 = bisection_method(counted_sin, A, B);
+    let original_calls: usize = CALLS.load(Ordering::SeqCst);
+
+    CALLS.store(0, Ordering::SeqCst);
+    let (root, evals): (f64, u32) = bisection_cached(counted_sin, A, B);
+    let calls: usize = CALLS.load(Ordering::SeqCst);
+
+    /*  Each loop iteration costs exactly one call, the endpoints cost two.   */
+    let iterations: u32 = evals - 2;
+
+    println!("original: pi = {}, calls = {}", original, original_calls);
+    println!("cached:   pi = {}, calls = {}", root, calls);
+    println!("iterations = {}, evals = iterations + 2: {}",
+             iterations, calls == (iterations + 2) as usize);
+    println!("root unchanged: {}", root == original);
+
+    /*  A closure can count its own calls by capturing a Cell, no static      *
+     *  needed. The closure only borrows the Cell, so the count can still be  *
+     *  read after the closure has been moved into bisection_cached.          */
+    let count: Cell<u32> = Cell::new(0);
+    let counted = |x: f64| {
+        count.set(count.get() + 1);
+        return x.sin();
+    };
+
+    let (root, evals): (f64, u32) = bisection_cached(counted, A, B);
+    println!("closure:  pi = {}, calls = {}, matches evals: {}",
+             root, count.get(), count.get() == evals);
+}
@@ -24,12 +24,13 @@
  *  Date:   2025/04/17                                                        *
  ******************************************************************************/
 
-/*  Function pointer notation is a little confusing. Create a typedef for it  *
- *  so we do not need to explicitly use it later.                             */
-type RealFunc = fn(f64) -> f64;
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
 
 /*  Computes the root of a function using the bisection method.               */
-fn bisection_method(f: RealFunc, a: f64, b: f64) -> f64 {
+fn bisection_method<F: Function>(f: F, a: f64, b: f64) -> f64 {
 
     /*  Tell the algorithm to stop after several iterations to avoid an       *
      *  infinite loop. Double precision numbers have 52 bits in the mantissa, *
@@ -141,4 +142,10 @@ fn main() {
      *  to about 16 decimals.                                                 */
     let pi: f64 = bisection_method(f64::sin, A, B);
     println!("pi( = {}", pi);
+
+    /*  Closures that capture their environment work too. The root of x^2 - c *
+     *  on [1, 2] is sqrt(c).                                                 */
+    let c: f64 = 2.0;
+    let sqrt_c: f64 = bisection_method(|x: f64| x*x - c, 1.0, 2.0);
+    println!("sqrt({}) = {}", c, sqrt_c);
 }
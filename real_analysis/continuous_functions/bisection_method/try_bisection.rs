@@ -0,0 +1,135 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Bisection for functions that can fail, propagating the function's     *
+ *      error.                                                                *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  The ways try_bisection can fail. E is the error type of the function      *
+ *  being solved.                                                             */
+#[derive(Debug)]
+enum BisectionError<E> {
+
+    /*  f(a) and f(b) have the same sign, so bisection does not apply.        */
+    NoSignChange,
+
+    /*  An evaluation of f failed. The function's own error is passed on.     */
+    Function(E)
+}
+
+/*  Computes a root of f on [a, b] with the bisection method, for functions   *
+ *  returning a Result. Any failed evaluation stops the method at once and    *
+ *  the error is returned as BisectionError::Function. The alternative,       *
+ *  returning NaN from f, would be silently absorbed: NaN compares false to   *
+ *  everything, so bisection would just keep going with a meaningless         *
+ *  interval. Otherwise this behaves like bisection_method, stopping after 64 *
+ *  halvings or once |f(midpoint)| <= epsilon.                                */
+fn try_bisection<F, E>(f: F, a: f64, b: f64) -> Result<f64, BisectionError<E>>
+    where F: Fn(f64) -> Result<f64, E> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    /*  Evaluates f, converting its error into a BisectionError.              */
+    let eval = |x: f64| f(x).map_err(BisectionError::Function);
+
+    let a_eval: f64 = eval(a)?;
+    let b_eval: f64 = eval(b)?;
+
+    if a_eval == 0.0 {
+        return Ok(a);
+    }
+
+    if b_eval == 0.0 {
+        return Ok(b);
+    }
+
+    if (a_eval < 0.0) == (b_eval < 0.0) {
+        return Err(BisectionError::NoSignChange);
+    }
+
+    /*  Orient the interval so f(left) < 0 < f(right).                        */
+    let (mut left, mut right): (f64, f64) =
+        if a_eval < 0.0 {(a, b)} else {(b, a)};
+    let mut midpoint: f64 = 0.5 * (a + b);
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let mid_eval: f64 = eval(midpoint)?;
+
+        if mid_eval.abs() <= EPSILON {
+            break;
+        }
+
+        if mid_eval < 0.0 {
+            left = midpoint;
+        } else {
+            right = midpoint;
+        }
+
+        midpoint = 0.5 * (left + right);
+    }
+
+    return Ok(midpoint);
+}
+/*  End of try_bisection.                                                     */
+
+/*  The error for a square root of a negative number, recording the input.    */
+#[derive(Debug)]
+struct DomainError {
+    x: f64
+}
+
+/*  sqrt(x) - 1.5, which is only defined for x >= 0. The root is 2.25.        */
+fn shifted_sqrt(x: f64) -> Result<f64, DomainError> {
+    if x < 0.0 {
+        return Err(DomainError {x: x});
+    }
+
+    return Ok(x.sqrt() - 1.5);
+}
+/*  End of shifted_sqrt.                                                      */
+
+/*  Main routine used for testing try_bisection.                              */
+fn main() {
+
+    /*  Inside the domain this is ordinary bisection.                         */
+    println!("[0, 4]:  {:?}", try_bisection(shifted_sqrt, 0.0, 4.0));
+
+    /*  With a = -1 the very first evaluation fails, and the error says       *
+     *  where.                                                                */
+    match try_bisection(shifted_sqrt, -1.0, 4.0) {
+        Err(BisectionError::Function(error)) => {
+            println!("[-1, 4]: domain error at x = {}", error.x);
+        }
+        other => println!("[-1, 4]: unexpected {:?}", other)
+    }
+
+    /*  Closures work too. 1 / x changes sign on [-1, 1] without a root, and  *
+     *  plain bisection would converge to the pole at 0. Here the first       *
+     *  midpoint is 0 and the division is reported instead.                   */
+    let reciprocal = |x: f64| {
+        if x == 0.0 {Err("division by zero")} else {Ok(1.0 / x)}
+    };
+
+    println!("1 / x on [-1, 1]: {:?}", try_bisection(reciprocal, -1.0, 1.0));
+    println!("no sign change: {:?}", try_bisection(shifted_sqrt, 3.0, 4.0));
+}
@@ -0,0 +1,45 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Provides the Function trait for functions f: R -> R, shared by the    *
+ *      root finding examples. This is a module, not a program, and it is     *
+ *      included with #[path = "../function/function.rs"] mod function;       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Only the blanket impl below may implement Sealed, since this module is    *
+ *  private to the files including it. This makes Function a sealed trait: no *
+ *  other impls can be written, so every Function is exactly a type callable  *
+ *  as Fn(f64) -> f64.                                                        */
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T: Fn(f64) -> f64> Sealed for T {}
+}
+
+/*  Functions of the form f: R -> R. Any type callable as Fn(f64) -> f64      *
+ *  implements this through the blanket impl below, so plain functions like   *
+ *  f64::sin and closures that capture their environment both work. A fn      *
+ *  pointer type, fn(f64) -> f64, only accepts the former.                    */
+pub trait Function: Fn(f64) -> f64 + sealed::Sealed {}
+
+impl<T: Fn(f64) -> f64> Function for T {}
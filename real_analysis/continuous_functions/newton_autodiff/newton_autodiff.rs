@@ -0,0 +1,237 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Newton's method with derivatives from dual numbers, no f' required.   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for dual number arithmetic.                                     */
+use std::ops::{Add, Div, Mul, Sub};
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Dual numbers a + b e with e^2 = 0. Expanding a function in a Taylor       *
+ *  series gives f(a + b e) = f(a) + f'(a) b e exactly, since all higher      *
+ *  powers of e vanish. Evaluating f on x + 1 e therefore computes f(x) and   *
+ *  f'(x) together, with no truncation error, as long as f is built from      *
+ *  operations that obey this rule. This is forward mode automatic            *
+ *  differentiation.                                                          */
+#[derive(Clone, Copy, Debug)]
+struct Dual {
+    value: f64,
+    deriv: f64
+}
+
+impl Dual {
+
+    /*  A constant, c + 0 e, whose derivative is zero.                        */
+    fn constant(c: f64) -> Dual {
+        return Dual { value: c, deriv: 0.0 };
+    }
+    /*  End of constant.                                                      */
+
+    /*  The variable being differentiated, x + 1 e, since dx/dx = 1.          */
+    fn variable(x: f64) -> Dual {
+        return Dual { value: x, deriv: 1.0 };
+    }
+    /*  End of variable.                                                      */
+
+    /*  sin(a + b e) = sin(a) + cos(a) b e.                                   */
+    fn sin(self) -> Dual {
+        return Dual {
+            value: self.value.sin(),
+            deriv: self.value.cos() * self.deriv
+        };
+    }
+    /*  End of sin.                                                           */
+
+    /*  exp(a + b e) = exp(a) + exp(a) b e.                                   */
+    fn exp(self) -> Dual {
+        let exp_a: f64 = self.value.exp();
+        return Dual { value: exp_a, deriv: exp_a * self.deriv };
+    }
+    /*  End of exp.                                                           */
+}
+
+/*  Dual addition, done component-wise.                                       */
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value + other.value,
+            deriv: self.deriv + other.deriv
+        };
+    }
+}
+
+/*  Dual subtraction, also done component-wise.                               */
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value - other.value,
+            deriv: self.deriv - other.deriv
+        };
+    }
+}
+
+/*  (a + b e)(c + d e) = ac + (ad + bc) e, the product rule.                  */
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value * other.value,
+            deriv: self.value * other.deriv + self.deriv * other.value
+        };
+    }
+}
+
+/*  (a + b e) / (c + d e) = a / c + (bc - ad) / c^2 e, the quotient rule.     */
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        let numerator: f64 =
+            self.deriv * other.value - self.value * other.deriv;
+
+        return Dual {
+            value: self.value / other.value,
+            deriv: numerator / (other.value * other.value)
+        };
+    }
+}
+
+/*  Both methods stop once |f(x)| is below this value.                        */
+const TOLERANCE: f64 = 1.0E-14;
+
+/*  The convergence is quadratic, very few iterations are needed.             */
+const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+
+/*  Newton's method with the derivative supplied by hand.                     */
+fn newtons_method(f: RealFunc, f_prime: RealFunc, x0: f64) -> f64 {
+    let mut xn: f64 = x0;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+
+        if f_xn.abs() <= TOLERANCE {
+            break;
+        }
+
+        xn = xn - f_xn / f_prime(xn);
+    }
+
+    return xn;
+}
+/*  End of newtons_method.                                                    */
+
+/*  Newton's method with automatic differentiation. One call f(x + 1 e)       *
+ *  returns both f(x) and f'(x), so no derivative needs to be written, and    *
+ *  unlike a finite difference the slope is exact up to rounding. The steps   *
+ *  are the same as Newton's method with the analytic derivative.             */
+fn newton_autodiff(f: fn(Dual) -> Dual, x0: f64) -> f64 {
+    let mut xn: f64 = x0;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let y: Dual = f(Dual::variable(xn));
+
+        if y.value.abs() <= TOLERANCE {
+            break;
+        }
+
+        xn = xn - y.value / y.deriv;
+    }
+
+    return xn;
+}
+/*  End of newton_autodiff.                                                   */
+
+/*  x^2 - 2, whose positive root is sqrt(2).                                  */
+fn square_minus_two(x: Dual) -> Dual {
+    return x * x - Dual::constant(2.0);
+}
+
+/*  x^3 - 5, whose real root is cbrt(5).                                      */
+fn cube_minus_five(x: Dual) -> Dual {
+    return x * x * x - Dual::constant(5.0);
+}
+
+/*  x exp(x) - 1, whose root is the omega constant W(1). A quotient and a     *
+ *  transcendental function are included to exercise the other rules: (exp(x) *
+ *  - 1 / x) x.                                                               */
+fn omega(x: Dual) -> Dual {
+    return (x.exp() - Dual::constant(1.0) / x) * x;
+}
+
+/*  sin(x), whose root near 3 is pi.                                          */
+fn sine(x: Dual) -> Dual {
+    return x.sin();
+}
+
+/*  The same functions as plain f64, with derivatives written by hand.        */
+fn square_minus_two_f64(x: f64) -> f64 {
+    return x * x - 2.0;
+}
+
+fn square_minus_two_prime(x: f64) -> f64 {
+    return 2.0 * x;
+}
+
+fn cube_minus_five_f64(x: f64) -> f64 {
+    return x * x * x - 5.0;
+}
+
+fn cube_minus_five_prime(x: f64) -> f64 {
+    return 3.0 * x * x;
+}
+
+/*  Main routine used for testing Newton's method with dual numbers.          */
+fn main() {
+    let sqrt_two: f64 = newton_autodiff(square_minus_two, 1.0);
+    let analytic: f64 =
+        newtons_method(square_minus_two_f64, square_minus_two_prime, 1.0);
+
+    println!("sqrt(2): autodiff = {}, analytic = {}, std = {}",
+             sqrt_two, analytic, 2.0f64.sqrt());
+
+    let cbrt_five: f64 = newton_autodiff(cube_minus_five, 1.0);
+    let analytic_cbrt: f64 =
+        newtons_method(cube_minus_five_f64, cube_minus_five_prime, 1.0);
+
+    println!("cbrt(5): autodiff = {}, analytic = {}, std = {}",
+             cbrt_five, analytic_cbrt, 5.0f64.cbrt());
+
+    println!("same as analytic Newton: {}",
+             sqrt_two == analytic && cbrt_five == analytic_cbrt);
+
+    println!("within 1 ulp of std: {}",
+             (sqrt_two - 2.0f64.sqrt()).abs() <= f64::EPSILON * sqrt_two &&
+             (cbrt_five - 5.0f64.cbrt()).abs() <= f64::EPSILON * cbrt_five);
+
+    println!("W(1) = {}", newton_autodiff(omega, 1.0));
+    println!("pi = {}", newton_autodiff(sine, 3.0));
+}
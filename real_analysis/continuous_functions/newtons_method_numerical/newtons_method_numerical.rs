@@ -0,0 +1,147 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Newton's method with numerical derivatives, counting function         *
+ *      evaluations.                                                          *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
+
+/*  Both methods stop once |f(x)| is below this value.                        */
+const TOLERANCE: f64 = 1.0E-12;
+
+/*  The convergence is quadratic, or nearly so, very few iterations are       *
+ *  needed.                                                                   */
+const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+
+/*  Newton's method with the derivative replaced by a central difference:     *
+ *                                                                            *
+ *        f'(x) ~ (f(x + h) - f(x - h)) / 2h                                  *
+ *                                                                            *
+ *  Each step evaluates f at x, x + h, and x - h, three calls. The step h =   *
+ *  cbrt(eps) |x| balances the O(h^2) truncation error against rounding.      *
+ *  Returns the root and the number of evaluations of f.                      */
+fn newtons_method_numerical<F: Function>(f: F, x: f64) -> (f64, u32) {
+    const CBRT_EPSILON: f64 = 6.055454452393343E-06;
+    let mut xn: f64 = x;
+    let mut evals: u32 = 0;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+        evals = evals + 1;
+
+        if f_xn.abs() <= TOLERANCE {
+            break;
+        }
+
+        let h: f64 = CBRT_EPSILON * xn.abs().max(1.0);
+        let slope: f64 = (f(xn + h) - f(xn - h)) / (2.0 * h);
+        evals = evals + 2;
+
+        xn = xn - f_xn / slope;
+    }
+
+    return (xn, evals);
+}
+/*  End of newtons_method_numerical.                                          */
+
+/*  Newton's method with a forward difference that reuses f(x):               *
+ *                                                                            *
+ *        f'(x) ~ (f(x + h) - f(x)) / h                                       *
+ *                                                                            *
+ *  f(x) is needed for the Newton step anyway, so the derivative costs only   *
+ *  one new call and each step costs two instead of three. The forward        *
+ *  difference is only first order accurate, the error is O(h), so h =        *
+ *  sqrt(eps) |x| is used and the slope has about eight correct digits        *
+ *  instead of ten. Near the root this barely matters, an error of relative   *
+ *  size d in the slope changes quadratic convergence into linear convergence *
+ *  with ratio d, still about eight digits per step. Returns the root and the *
+ *  number of evaluations of f.                                               */
+fn newtons_method_numerical_cached<F: Function>(f: F,
+                                                x: f64) -> (f64, u32) {
+    const SQRT_EPSILON: f64 = 1.4901161193847656E-08;
+    let mut xn: f64 = x;
+    let mut evals: u32 = 0;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+        evals = evals + 1;
+
+        if f_xn.abs() <= TOLERANCE {
+            break;
+        }
+
+        let h: f64 = SQRT_EPSILON * xn.abs().max(1.0);
+        let slope: f64 = (f(xn + h) - f_xn) / h;
+        evals = evals + 1;
+
+        xn = xn - f_xn / slope;
+    }
+
+    return (xn, evals);
+}
+/*  End of newtons_method_numerical_cached.                                   */
+
+/*  Counter for the number of times counted_f is called.                      */
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/*  x^3 - 2x - 5, Newton's own example, with the root 2.0945514815423265.     *
+ *  Each call is counted.                                                     */
+fn counted_f(x: f64) -> f64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    return (x * x - 2.0) * x - 5.0;
+}
+
+/*  Main routine used for comparing the two variants.                         */
+fn main() {
+    CALLS.store(0, Ordering::SeqCst);
+    let (central, central_evals): (f64, u32) =
+        newtons_method_numerical(counted_f, 2.0);
+    let central_calls: usize = CALLS.load(Ordering::SeqCst);
+
+    CALLS.store(0, Ordering::SeqCst);
+    let (cached, cached_evals): (f64, u32) =
+        newtons_method_numerical_cached(counted_f, 2.0);
+    let cached_calls: usize = CALLS.load(Ordering::SeqCst);
+
+    println!("central: root = {:.16}, evals = {}, counted = {}",
+             central, central_evals, central_calls);
+    println!("cached:  root = {:.16}, evals = {}, counted = {}",
+             cached, cached_evals, cached_calls);
+    println!("same root: {}, fewer calls: {}",
+             (central - cached).abs() < 1.0E-12, cached_calls < central_calls);
+
+    /*  Closures that capture their environment work too. Here x^3 - c has    *
+     *  the root cbrt(c).                                                     */
+    let c: f64 = 5.0;
+    let cube = |x: f64| x * x * x - c;
+    let (central, _): (f64, u32) = newtons_method_numerical(cube, 2.0);
+    let (cached, _): (f64, u32) = newtons_method_numerical_cached(cube, 2.0);
+
+    println!("cbrt({}): central error = {:.3E}, cached error = {:.3E}",
+             c, (central - c.cbrt()).abs(), (cached - c.cbrt()).abs());
+}
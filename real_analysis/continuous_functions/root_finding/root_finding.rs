@@ -0,0 +1,298 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Root-finding methods that report the root, the residual f(root), the  *
+ *      number of iterations used, and whether or not the method converged.   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
+
+/*  The output of each solver. Reporting every method in the same form lets us*
+ *  compare them directly.                                                    */
+struct RootResult {
+
+    /*  The approximate root found by the method.                             */
+    root: f64,
+
+    /*  The value f(root). For an exact root this is zero.                    */
+    residual: f64,
+
+    /*  The number of iterations the method performed.                        */
+    iterations: u32,
+
+    /*  True if |residual| <= tolerance, false otherwise.                     */
+    converged: bool
+}
+
+/*  Creates a RootResult from the final point of one of the methods below.    */
+fn make_result<F: Function>(f: &F,
+                            root: f64,
+                            iterations: u32,
+                            tolerance: f64) -> RootResult {
+
+    /*  The residual is computed the same way for every method.               */
+    let residual: f64 = f(root);
+
+    return RootResult {
+        root: root,
+        residual: residual,
+        iterations: iterations,
+        converged: residual.abs() <= tolerance
+    };
+}
+/*  End of make_result.                                                       */
+
+/*  Bisection method. f(a) and f(b) must have opposite signs.                 */
+fn bisection_result<F: Function>(f: F,
+                                 a: f64,
+                                 b: f64,
+                                 tolerance: f64) -> RootResult {
+
+    /*  Same cap as bisection_method.rs, enough to exhaust double precision.  */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+
+    /*  Evaluate at the endpoints. We label the point with the negative       *
+     *  evaluation left and the positive one right.                           */
+    let a_eval: f64 = f(a);
+    let b_eval: f64 = f(b);
+    let mut left: f64;
+    let mut right: f64;
+    let mut midpoint: f64;
+    let mut iterations: u32 = 0;
+
+    /*  If both evaluations have the same sign, bisection does not apply.     *
+     *  Return NaN, and the NaN residual will be marked as not converged.     */
+    if (a_eval < 0.0 && b_eval < 0.0) || (a_eval > 0.0 && b_eval > 0.0) {
+        return make_result(&f, (a - a) / (a - a), 0, tolerance);
+    }
+
+    if a_eval < b_eval {
+        left = a;
+        right = b;
+    } else {
+        left = b;
+        right = a;
+    }
+
+    /*  Start the bisection method. Compute the midpoint of a and b.          */
+    midpoint = 0.5 * (a + b);
+
+    /*  Iteratively divide the range in half to find the root.                */
+    while iterations < MAXIMUM_NUMBER_OF_ITERATIONS {
+        let eval: f64 = f(midpoint);
+
+        if eval.abs() <= tolerance {
+            break;
+        }
+
+        /*  Keep the half of the interval where the sign changes.             */
+        if eval < 0.0 {
+            left = midpoint;
+        } else {
+            right = midpoint;
+        }
+
+        midpoint = 0.5 * (left + right);
+        iterations = iterations + 1;
+    }
+
+    return make_result(&f, midpoint, iterations, tolerance);
+}
+/*  End of bisection_result.                                                  */
+
+/*  Secant method, starting from the two guesses x0 and x1.                   */
+fn secant_result<F: Function>(f: F,
+                              x0: f64,
+                              x1: f64,
+                              tolerance: f64) -> RootResult {
+
+    /*  The convergence is superlinear, a small cap is plenty.                */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+
+    let mut previous: f64 = x0;
+    let mut current: f64 = x1;
+    let mut f_previous: f64 = f(previous);
+    let mut iterations: u32 = 0;
+
+    while iterations < MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_current: f64 = f(current);
+
+        if f_current.abs() <= tolerance {
+            break;
+        }
+
+        /*  The slope of the secant line replaces the derivative in Newton's  *
+         *  method.                                                           */
+        let slope: f64 = (f_current - f_previous) / (current - previous);
+
+        previous = current;
+        f_previous = f_current;
+        current = current - f_current / slope;
+        iterations = iterations + 1;
+    }
+
+    return make_result(&f, current, iterations, tolerance);
+}
+/*  End of secant_result.                                                     */
+
+/*  Newton's method, starting from the guess x. The derivative f' is given.   */
+fn newtons_method_result<F, G>(f: F,
+                               f_prime: G,
+                               x: f64,
+                               tolerance: f64) -> RootResult
+    where F: Function, G: Function {
+
+    /*  The convergence is quadratic, we need very few iterations.            */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+
+    let mut xn: f64 = x;
+    let mut iterations: u32 = 0;
+
+    while iterations < MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+
+        if f_xn.abs() <= tolerance {
+            break;
+        }
+
+        /*  Follow the tangent line down to the x axis.                       */
+        xn = xn - f_xn / f_prime(xn);
+        iterations = iterations + 1;
+    }
+
+    return make_result(&f, xn, iterations, tolerance);
+}
+/*  End of newtons_method_result.                                             */
+
+/*  Steffensen's method, starting from the guess x.                           */
+fn steffensens_method_result<F: Function>(f: F,
+                                          x: f64,
+                                          tolerance: f64) -> RootResult {
+
+    /*  Steffensen's method is iterative and converges very quickly.          */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+
+    let mut xn: f64 = x;
+    let mut iterations: u32 = 0;
+
+    while iterations < MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+
+        if f_xn.abs() <= tolerance {
+            break;
+        }
+
+        /*  g(x) = f(x + f(x)) / f(x) - 1 acts as the derivative of f.        */
+        let g_xn: f64 = f(xn + f_xn) / f_xn - 1.0;
+        xn = xn - f_xn / g_xn;
+        iterations = iterations + 1;
+    }
+
+    return make_result(&f, xn, iterations, tolerance);
+}
+/*  End of steffensens_method_result.                                         */
+
+/*  sqrt(2) is a root to the function f(x) = 2 - x^2. Provide this.           */
+fn f(x: f64) -> f64 {
+    return 2.0 - x*x;
+}
+
+/*  The derivative of f, used by Newton's method.                             */
+fn f_prime(x: f64) -> f64 {
+    return -2.0 * x;
+}
+
+/*  Prints a RootResult in a single line, prefixed by the name of the method. */
+fn print_result(name: &str, result: &RootResult) {
+    println!("{:<10}: root = {:.16}, residual = {:+.3E}",
+             name, result.root, result.residual);
+
+    println!("            iterations = {}, converged = {}",
+             result.iterations, result.converged);
+}
+
+/*  Main routine used for comparing the four methods.                         */
+fn main() {
+
+    /*  The methods stop once |f(x)| is below this value.                     */
+    const TOLERANCE: f64 = 1.0E-12;
+
+    /*  Each method is given sensible starting data for the root sqrt(2).     */
+    let results: [(&str, RootResult); 4] = [
+        ("Bisection", bisection_result(f, 1.0, 2.0, TOLERANCE)),
+        ("Secant", secant_result(f, 1.0, 2.0, TOLERANCE)),
+        ("Newton", newtons_method_result(f, f_prime, 2.0, TOLERANCE)),
+        ("Steffensen", steffensens_method_result(f, 2.0, TOLERANCE))
+    ];
+
+    let mut all_converged: bool = true;
+
+    for &(name, ref result) in results.iter() {
+        print_result(name, result);
+        all_converged = all_converged && result.converged;
+    }
+
+    println!("All methods converged: {}", all_converged);
+
+    /*  The same methods accept closures that capture their environment. Here *
+     *  the target value c is captured, giving the root cbrt(c).              */
+    let c: f64 = 5.0;
+    let cube = |x: f64| x * x * x - c;
+    let cube_prime = |x: f64| 3.0 * x * x;
+    let cbrt: f64 = c.cbrt();
+
+    let closures: [(&str, RootResult); 4] = [
+        ("Bisection", bisection_result(cube, 1.0, 2.0, TOLERANCE)),
+        ("Secant", secant_result(cube, 1.0, 2.0, TOLERANCE)),
+        ("Newton", newtons_method_result(cube, cube_prime, 2.0, TOLERANCE)),
+        ("Steffensen", steffensens_method_result(cube, 2.0, TOLERANCE))
+    ];
+
+    /*  And plain functions from the standard library, sin with the root pi.  */
+    let pi: f64 = std::f64::consts::PI;
+
+    let functions: [(&str, RootResult); 4] = [
+        ("Bisection", bisection_result(f64::sin, 3.0, 4.0, TOLERANCE)),
+        ("Secant", secant_result(f64::sin, 3.0, 4.0, TOLERANCE)),
+        ("Newton", newtons_method_result(f64::sin, f64::cos, 3.0, TOLERANCE)),
+        ("Steffensen", steffensens_method_result(f64::sin, 3.0, TOLERANCE))
+    ];
+
+    for &(name, ref result) in closures.iter() {
+        println!("{:<10}: closure root error = {:.3E}, converged = {}",
+                 name, (result.root - cbrt).abs(), result.converged);
+        all_converged = all_converged && result.converged;
+    }
+
+    for &(name, ref result) in functions.iter() {
+        println!("{:<10}: f64::sin root error = {:.3E}, converged = {}",
+                 name, (result.root - pi).abs(), result.converged);
+        all_converged = all_converged && result.converged;
+    }
+
+    println!("All methods converged for closures and f64::sin: {}",
+             all_converged);
+}
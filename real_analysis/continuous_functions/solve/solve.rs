@@ -0,0 +1,373 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      A single entry point for the root-finding methods, selected by name.  *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
+
+/*  The available root-finding methods.                                       */
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    Bisection,
+    Secant,
+    Newton,
+    Steffensen,
+    Brent
+}
+
+/*  The ways a solver can fail.                                               */
+#[derive(Debug)]
+enum SolveError {
+
+    /*  Bracketing methods need f(a) and f(b) to have opposite signs.         */
+    NoSignChange,
+
+    /*  The iteration hit a point with a zero or non-finite slope.            */
+    BadSlope,
+
+    /*  The iteration limit was reached before |f(x)| fell below TOLERANCE.   */
+    DidNotConverge
+}
+
+/*  The methods stop once |f(x)| is below this value, or, for the bracketing  *
+ *  methods, once the bracket is this small.                                  */
+const TOLERANCE: f64 = 1.0E-12;
+
+/*  Checks the final point of an open method, one that is not guaranteed to   *
+ *  converge.                                                                 */
+fn check<F: Function>(f: F, x: f64) -> Result<f64, SolveError> {
+    if f(x).abs() <= TOLERANCE {
+        return Ok(x);
+    }
+
+    return Err(SolveError::DidNotConverge);
+}
+/*  End of check.                                                             */
+
+/*  Bisection method. f(a) and f(b) must have opposite signs.                 */
+fn bisection<F: Function>(f: F,
+                          a: f64,
+                          b: f64) -> Result<f64, SolveError> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    let mut left: f64 = a;
+    let mut right: f64 = b;
+    let mut left_eval: f64 = f(a);
+
+    let right_eval: f64 = f(b);
+
+    if left_eval == 0.0 {
+        return Ok(a);
+    }
+
+    if right_eval == 0.0 {
+        return Ok(b);
+    }
+
+    if (left_eval < 0.0) == (right_eval < 0.0) {
+        return Err(SolveError::NoSignChange);
+    }
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let midpoint: f64 = 0.5 * (left + right);
+        let eval: f64 = f(midpoint);
+
+        if eval.abs() <= TOLERANCE || (right - left).abs() <= TOLERANCE {
+            return Ok(midpoint);
+        }
+
+        /*  Keep the half of the interval where the sign changes.             */
+        if (eval < 0.0) == (left_eval < 0.0) {
+            left = midpoint;
+            left_eval = eval;
+        } else {
+            right = midpoint;
+        }
+    }
+
+    return Ok(0.5 * (left + right));
+}
+/*  End of bisection.                                                         */
+
+/*  Secant method, starting from the two guesses x0 and x1.                   */
+fn secant<F: Function>(f: F,
+                       x0: f64,
+                       x1: f64) -> Result<f64, SolveError> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 32;
+    let mut previous: f64 = x0;
+    let mut current: f64 = x1;
+    let mut f_previous: f64 = f(previous);
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_current: f64 = f(current);
+
+        if f_current.abs() <= TOLERANCE {
+            return Ok(current);
+        }
+
+        /*  The slope of the secant line replaces the derivative in Newton's  *
+         *  method.                                                           */
+        let slope: f64 = (f_current - f_previous) / (current - previous);
+
+        if slope == 0.0 || !slope.is_finite() {
+            return Err(SolveError::BadSlope);
+        }
+
+        previous = current;
+        f_previous = f_current;
+        current = current - f_current / slope;
+    }
+
+    return check(&f, current);
+}
+/*  End of secant.                                                            */
+
+/*  Newton's method, starting from the guess x. Only f is given, so the       *
+ *  derivative is approximated with a central difference. The step h =        *
+ *  cbrt(eps) balances the O(h^2) truncation error against rounding.          */
+fn newton<F: Function>(f: F, x: f64) -> Result<f64, SolveError> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+    const H: f64 = 6.055454452393343E-06;
+    let mut xn: f64 = x;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+
+        if f_xn.abs() <= TOLERANCE {
+            return Ok(xn);
+        }
+
+        let slope: f64 = (f(xn + H) - f(xn - H)) / (2.0 * H);
+
+        if slope == 0.0 || !slope.is_finite() {
+            return Err(SolveError::BadSlope);
+        }
+
+        /*  Follow the tangent line down to the x axis.                       */
+        xn = xn - f_xn / slope;
+    }
+
+    return check(&f, xn);
+}
+/*  End of newton.                                                            */
+
+/*  Steffensen's method, starting from the guess x.                           */
+fn steffensen<F: Function>(f: F, x: f64) -> Result<f64, SolveError> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+    let mut xn: f64 = x;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        let f_xn: f64 = f(xn);
+
+        if f_xn.abs() <= TOLERANCE {
+            return Ok(xn);
+        }
+
+        /*  g(x) = f(x + f(x)) / f(x) - 1 acts as the derivative of f.        */
+        let g_xn: f64 = f(xn + f_xn) / f_xn - 1.0;
+
+        if g_xn == 0.0 || !g_xn.is_finite() {
+            return Err(SolveError::BadSlope);
+        }
+
+        xn = xn - f_xn / g_xn;
+    }
+
+    return check(&f, xn);
+}
+/*  End of steffensen.                                                        */
+
+/*  Brent's method. It keeps a bracket [b, c] with a sign change, like        *
+ *  bisection, so it always converges. At each step it tries inverse          *
+ *  quadratic interpolation through the last three points, or the secant step *
+ *  when only two are distinct. The interpolated step is accepted only if it  *
+ *  lands well inside the bracket and shrinks faster than the step before     *
+ *  last, otherwise the method bisects. On smooth functions this converges    *
+ *  superlinearly, and it is never much slower than bisection. This follows   *
+ *  the classic zbrent routine.                                               */
+fn brent<F: Function>(f: F,
+                      x0: f64,
+                      x1: f64) -> Result<f64, SolveError> {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 100;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    let (mut a, mut b, mut c): (f64, f64, f64) = (x0, x1, x1);
+    let (mut fa, mut fb): (f64, f64) = (f(a), f(b));
+    let mut fc: f64 = fb;
+    let (mut d, mut e): (f64, f64) = (0.0, 0.0);
+
+    if (fa > 0.0 && fb > 0.0) || (fa < 0.0 && fb < 0.0) {
+        return Err(SolveError::NoSignChange);
+    }
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+
+        /*  Keep the root between b and c. If they lost the sign change, c    *
+         *  restarts from a.                                                  */
+        if (fb > 0.0 && fc > 0.0) || (fb < 0.0 && fc < 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+
+        /*  b is always the best estimate, the end with the smaller |f|.      */
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol: f64 = 2.0 * EPSILON * b.abs() + 0.5 * TOLERANCE;
+        let half: f64 = 0.5 * (c - b);
+
+        if half.abs() <= tol || fb == 0.0 {
+            return Ok(b);
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+
+            /*  Interpolate, p / q is the proposed step from b.               */
+            let s: f64 = fb / fa;
+            let (mut p, mut q): (f64, f64);
+
+            if a == c {
+                p = 2.0 * half * s;
+                q = 1.0 - s;
+            } else {
+                let r: f64 = fb / fc;
+                q = fa / fc;
+                p = s * (2.0 * half * q * (q - r) - (b - a) * (r - 1.0));
+                q = (q - 1.0) * (r - 1.0) * (s - 1.0);
+            }
+
+            if p > 0.0 {
+                q = -q;
+            }
+
+            p = p.abs();
+            let inside: f64 = 3.0 * half * q - (tol * q).abs();
+
+            if 2.0 * p < inside.min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = half;
+                e = d;
+            }
+        } else {
+            d = half;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+
+        /*  Always move by at least tol, to make progress near convergence.   */
+        if d.abs() > tol {
+            b = b + d;
+        } else {
+            b = b + tol.copysign(half);
+        }
+
+        fb = f(b);
+    }
+
+    return Err(SolveError::DidNotConverge);
+}
+/*  End of brent.                                                             */
+
+/*  Finds a root of f with the chosen method. The bracketing methods,         *
+ *  bisection and Brent, need f(a) and f(b) to have opposite signs. The       *
+ *  secant method uses a and b as its two starting guesses. Newton's and      *
+ *  Steffensen's methods start from a and ignore b.                           */
+fn solve<F: Function>(method: Method,
+                      f: F,
+                      a: f64,
+                      b: f64) -> Result<f64, SolveError> {
+    return match method {
+        Method::Bisection => bisection(f, a, b),
+        Method::Secant => secant(f, a, b),
+        Method::Newton => newton(f, a),
+        Method::Steffensen => steffensen(f, a),
+        Method::Brent => brent(f, a, b)
+    };
+}
+/*  End of solve.                                                             */
+
+/*  Main routine used for comparing the methods.                              */
+fn main() {
+    let methods: [Method; 5] = [
+        Method::Bisection, Method::Secant, Method::Newton,
+        Method::Steffensen, Method::Brent
+    ];
+
+    let pi: f64 = std::f64::consts::PI;
+    let mut all_agree: bool = true;
+
+    /*  sin has the root pi in [3, 4].                                        */
+    for &method in methods.iter() {
+        let name: String = format!("{:?}", method);
+
+        match solve(method, f64::sin, 3.0, 4.0) {
+            Ok(root) => {
+                all_agree &= (root - pi).abs() <= 1.0E-8;
+                println!("{:<10}: root = {:.16}", name, root);
+            }
+            Err(error) => {
+                all_agree = false;
+                println!("{:<10}: failed with {:?}", name, error);
+            }
+        }
+    }
+
+    println!("All methods agree to within 1e-8: {}", all_agree);
+
+    /*  Closures that capture their environment work too. x^3 - c has the     *
+     *  root cbrt(c) in [1, 2].                                               */
+    let c: f64 = 5.0;
+    let mut closures_agree: bool = true;
+
+    for &method in methods.iter() {
+        match solve(method, |x: f64| x * x * x - c, 1.0, 2.0) {
+            Ok(root) => closures_agree &= (root - c.cbrt()).abs() <= 1.0E-8,
+            Err(_) => closures_agree = false
+        }
+    }
+
+    println!("All methods agree on cbrt({}): {}", c, closures_agree);
+
+    /*  cos(x) + 2 is never zero, the bracket has no sign change.             */
+    println!("Brent on cos(x) + 2: {:?}",
+             solve(Method::Brent, |x| x.cos() + 2.0, 0.0, 1.0));
+
+    /*  A root at the right endpoint is found directly.                       */
+    println!("Bisection on sin over [3, 0]: {:?}",
+             solve(Method::Bisection, f64::sin, 3.0, 0.0));
+}
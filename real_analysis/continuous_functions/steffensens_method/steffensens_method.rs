@@ -24,11 +24,13 @@
  *  Date:   2025/05/22                                                        *
  ******************************************************************************/
 
-/*  Type for a function of the form f: R -> R.                                */
-type RealFunc = fn(f64) -> f64;
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
 
 /*  Computes the root of a function using Steffensen's method.                */
-fn steffensens_method(f: RealFunc, x: f64) -> f64 {
+fn steffensens_method<F: Function>(f: F, x: f64) -> f64 {
 
     /*  Steffensen's method is iterative and converges very quickly.          *
      *  Because of this we may exit the function after a few iterations.      */
@@ -81,4 +83,10 @@ fn main() {
      *  written things correctly, we should get 1.414..., which is sqrt(2).   */
     let sqrt_x: f64 = steffensens_method(f, X);
     println!("sqrt({}) = {}", X, sqrt_x);
+
+    /*  Closures that capture their environment work too. Here c - x^2 has    *
+     *  the root sqrt(c).                                                     */
+    let c: f64 = 3.0;
+    let sqrt_c: f64 = steffensens_method(|x: f64| c - x*x, X);
+    println!("sqrt({}) = {}", c, sqrt_c);
 }
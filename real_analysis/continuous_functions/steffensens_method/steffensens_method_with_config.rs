@@ -0,0 +1,197 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Steffensen's method with a configurable tolerance and iteration cap,  *
+ *      and a variant that records every iterate.                             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Functions f: R -> R, plain functions and closures alike. See function.rs. */
+#[path = "../function/function.rs"]
+mod function;
+use function::Function;
+
+/*  Parameters controlling when Steffensen's method halts.                    */
+struct SteffensenConfig {
+
+    /*  The number of iterations performed before giving up.                  */
+    max_iterations: u32,
+
+    /*  The method stops once |f(x)| falls below this value.                  */
+    tolerance: f64
+}
+
+/*  The parameters used by the original steffensens_method.rs. 16 iterations  *
+ *  and a tolerance of 4x double precision epsilon.                           */
+const DEFAULT_CONFIG: SteffensenConfig = SteffensenConfig {
+    max_iterations: 16,
+    tolerance: 8.881784197001252E-16
+};
+
+/*  Performs a single step of Steffensen's method. Returns the new point and  *
+ *  the value f(x) computed along the way, which is used for the stopping     *
+ *  criterion.                                                                */
+fn steffensen_step<F: Function>(f: F, xn: f64) -> (f64, f64) {
+
+    /*  Steffensen's method needs the evaluations f(x) and f(x + f(x)), in    *
+     *  particular the denominator is f(x + f(x)) / f(x) - 1. Compute.        */
+    let f_xn: f64 = f(xn);
+    let g_xn: f64 = f(xn + f_xn) / f_xn - 1.0;
+
+    /*  Like Newton's method, the new point is obtained by subtracting the    *
+     *  ratio. g(x) acts as the derivative of f.                              */
+    return (xn - f_xn / g_xn, f_xn);
+}
+/*  End of steffensen_step.                                                   */
+
+/*  Computes the root of a function using Steffensen's method, with the       *
+ *  iteration cap and tolerance provided by the caller.                       */
+fn steffensens_method_with_config<F>(f: F,
+                                     x: f64,
+                                     config: &SteffensenConfig) -> f64
+    where F: Function {
+
+    /*  The method starts at the provided guess point and updates iteratively.*/
+    let mut xn: f64 = x;
+
+    /*  Iteratively apply Steffensen's method to find the root.               */
+    for _ in 0 .. config.max_iterations {
+
+        /*  Compute the next point, and f evaluated at the current one.       */
+        let (next, f_xn): (f64, f64) = steffensen_step(&f, xn);
+        xn = next;
+
+        /*  Same stopping rule as the original: once f(x) is small we are     *
+         *  close to a root, but the update just computed is still kept.      */
+        if f_xn.abs() < config.tolerance {
+            break;
+        }
+    }
+
+    return xn;
+}
+/*  End of steffensens_method_with_config.                                    */
+
+/*  Steffensen's method with the default parameters. This produces the same   *
+ *  output as steffensens_method.rs.                                          */
+fn steffensens_method<F: Function>(f: F, x: f64) -> f64 {
+    return steffensens_method_with_config(f, x, &DEFAULT_CONFIG);
+}
+/*  End of steffensens_method.                                                */
+
+/*  Same as steffensens_method_with_config, but returns every iterate. The    *
+ *  first entry is the initial guess, and the last entry is exactly the value *
+ *  that steffensens_method_with_config returns, the point computed on the    *
+ *  iteration where the early exit triggered.                                 */
+fn steffensens_method_trace<F: Function>(f: F,
+                                        x: f64,
+                                        config: &SteffensenConfig) -> Vec<f64> {
+
+    /*  The sequence of iterates, starting with the initial guess.            */
+    let mut trace: Vec<f64> = vec![x];
+    let mut xn: f64 = x;
+
+    /*  Identical loop to the one above, but we save each point.              */
+    for _ in 0 .. config.max_iterations {
+        let (next, f_xn): (f64, f64) = steffensen_step(&f, xn);
+        xn = next;
+        trace.push(xn);
+
+        if f_xn.abs() < config.tolerance {
+            break;
+        }
+    }
+
+    return trace;
+}
+/*  End of steffensens_method_trace.                                          */
+
+/*  sqrt(2) is a root to the function f(x) = 2 - x^2. Provide this.           */
+fn f(x: f64) -> f64 {
+    return 2.0 - x*x;
+}
+
+/*  Main routine used for testing the configurable Steffensen's method.       */
+fn main() {
+
+    /*  The initial guess point for Steffensen's method.                      */
+    const X: f64 = 2.0;
+
+    /*  The default configuration reproduces the original program.            */
+    let sqrt_x: f64 = steffensens_method(f, X);
+    println!("sqrt({}) = {}", X, sqrt_x);
+
+    /*  A loose tolerance stops early. A tight tolerance of one double        *
+     *  precision epsilon asks for as much accuracy as we can get.            */
+    let loose: SteffensenConfig = SteffensenConfig {
+        max_iterations: 16,
+        tolerance: 1.0E-4
+    };
+
+    let tight: SteffensenConfig = SteffensenConfig {
+        max_iterations: 16,
+        tolerance: 2.220446049250313E-16
+    };
+
+    let loose_root: f64 = steffensens_method_with_config(f, X, &loose);
+    let tight_root: f64 = steffensens_method_with_config(f, X, &tight);
+    println!("Loose tolerance: sqrt({}) = {}", X, loose_root);
+    println!("Tight tolerance: sqrt({}) = {}", X, tight_root);
+
+    /*  Print the iterates and their errors. Since the convergence is         *
+     *  quadratic, the number of correct digits roughly doubles at each step. *
+     *  The estimated order is ln(e_{n+1} / e_{n}) / ln(e_{n} / e_{n-1}), and *
+     *  it should approach 2.                                                 */
+    let trace: Vec<f64> = steffensens_method_trace(f, X, &DEFAULT_CONFIG);
+    let root: f64 = std::f64::consts::SQRT_2;
+    let mut previous_error: f64 = 0.0;
+    let mut current_error: f64 = 0.0;
+
+    for (n, xn) in trace.iter().enumerate() {
+        let error: f64 = (xn - root).abs();
+
+        if n >= 2 && error > 0.0 {
+            let order: f64 = (error / current_error).ln()
+                           / (current_error / previous_error).ln();
+
+            println!("x_{} = {:.16}, error = {:.3E}, order = {:.3}",
+                     n, xn, error, order);
+        } else {
+            println!("x_{} = {:.16}, error = {:.3E}", n, xn, error);
+        }
+
+        previous_error = current_error;
+        current_error = error;
+    }
+
+    /*  The last entry of the trace is the value steffensens_method returns.  */
+    println!("Trace ends at the returned value: {}",
+             trace[trace.len() - 1] == sqrt_x);
+
+    /*  Closures that capture their environment work too. Here c - x^2 has    *
+     *  the root sqrt(c).                                                     */
+    let c: f64 = 3.0;
+    let g = |x: f64| c - x*x;
+    let sqrt_c: f64 = steffensens_method_with_config(g, X, &tight);
+    println!("sqrt({}) = {}, error = {:.3E}",
+             c, sqrt_c, (sqrt_c - c.sqrt()).abs());
+}
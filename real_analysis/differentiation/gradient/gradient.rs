@@ -0,0 +1,187 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Gradients of functions of several variables with dual numbers.        *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for dual number arithmetic.                                     */
+use std::ops::{Add, Div, Mul, Sub};
+
+/*  Dual numbers a + b e with e^2 = 0. Expanding a function in a Taylor       *
+ *  series gives f(a + b e) = f(a) + f'(a) b e exactly, since all higher      *
+ *  powers of e vanish. Evaluating f on x + 1 e therefore computes f(x) and   *
+ *  f'(x) together, with no truncation error, as long as f is built from      *
+ *  operations that obey this rule. This is forward mode automatic            *
+ *  differentiation.                                                          */
+#[derive(Clone, Copy, Debug)]
+struct Dual {
+    value: f64,
+    deriv: f64
+}
+
+impl Dual {
+
+    /*  A constant, c + 0 e, whose derivative is zero.                        */
+    fn constant(c: f64) -> Dual {
+        return Dual { value: c, deriv: 0.0 };
+    }
+    /*  End of constant.                                                      */
+
+    /*  sin(a + b e) = sin(a) + cos(a) b e.                                   */
+    fn sin(self) -> Dual {
+        return Dual {
+            value: self.value.sin(),
+            deriv: self.value.cos() * self.deriv
+        };
+    }
+    /*  End of sin.                                                           */
+
+    /*  exp(a + b e) = exp(a) + exp(a) b e.                                   */
+    fn exp(self) -> Dual {
+        let exp_a: f64 = self.value.exp();
+        return Dual { value: exp_a, deriv: exp_a * self.deriv };
+    }
+    /*  End of exp.                                                           */
+}
+
+/*  Dual addition, done component-wise.                                       */
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value + other.value,
+            deriv: self.deriv + other.deriv
+        };
+    }
+}
+
+/*  Dual subtraction, also done component-wise.                               */
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value - other.value,
+            deriv: self.deriv - other.deriv
+        };
+    }
+}
+
+/*  (a + b e)(c + d e) = ac + (ad + bc) e, the product rule.                  */
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value * other.value,
+            deriv: self.value * other.deriv + self.deriv * other.value
+        };
+    }
+}
+
+/*  (a + b e) / (c + d e) = a / c + (bc - ad) / c^2 e, the quotient rule.     */
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        let numerator: f64 =
+            self.deriv * other.value - self.value * other.deriv;
+
+        return Dual {
+            value: self.value / other.value,
+            deriv: numerator / (other.value * other.value)
+        };
+    }
+}
+
+/*  Computes the gradient of f at x with forward mode automatic               *
+ *  differentiation. A dual number carries one derivative, so the partials    *
+ *  are found one at a time. For the k-th partial, component k is seeded with *
+ *  deriv = 1 and the rest with deriv = 0, so the dual part of f is the       *
+ *  directional derivative along the k-th axis. This needs n evaluations of f *
+ *  for n variables, the same count as forward differences, but the partials  *
+ *  are exact up to rounding.                                                 */
+fn gradient(f: fn(&[Dual]) -> Dual, x: &[f64]) -> Vec<f64> {
+    let mut grad: Vec<f64> = Vec::with_capacity(x.len());
+    let mut point: Vec<Dual> =
+        x.iter().map(|&xk| Dual::constant(xk)).collect();
+
+    for k in 0 .. x.len() {
+        point[k].deriv = 1.0;
+        grad.push(f(&point).deriv);
+        point[k].deriv = 0.0;
+    }
+
+    return grad;
+}
+/*  End of gradient.                                                          */
+
+/*  f(x, y) = x^2 + xy + y^2, with gradient (2x + y, x + 2y).                 */
+fn quadratic(v: &[Dual]) -> Dual {
+    let (x, y): (Dual, Dual) = (v[0], v[1]);
+    return x * x + x * y + y * y;
+}
+
+/*  The Rosenbrock function (1 - x)^2 + 100 (y - x^2)^2. Its gradient is (-2  *
+ *  (1 - x) - 400 x (y - x^2), 200 (y - x^2)), zero at the minimum (1, 1).    */
+fn rosenbrock(v: &[Dual]) -> Dual {
+    let (x, y): (Dual, Dual) = (v[0], v[1]);
+    let one: Dual = Dual::constant(1.0);
+    let hundred: Dual = Dual::constant(100.0);
+    return (one - x) * (one - x) + hundred * (y - x * x) * (y - x * x);
+}
+
+/*  f(x, y, z) = sin(xy) exp(z), three variables.                             */
+fn three_variables(v: &[Dual]) -> Dual {
+    return (v[0] * v[1]).sin() * v[2].exp();
+}
+
+/*  Main routine used for testing the gradient against analytic results.      */
+fn main() {
+    let points: [(f64, f64); 4] =
+        [(1.0, 2.0), (-3.0, 0.5), (0.0, 0.0), (2.5, -7.0)];
+    let mut agree: bool = true;
+
+    for &(x, y) in points.iter() {
+        let grad: Vec<f64> = gradient(quadratic, &[x, y]);
+        let exact: [f64; 2] = [2.0 * x + y, x + 2.0 * y];
+        println!("grad f({}, {}) = {:?}, exact = {:?}", x, y, grad, exact);
+        agree = agree && grad[0] == exact[0] && grad[1] == exact[1];
+    }
+
+    println!("matches [2x + y, x + 2y]: {}", agree);
+
+    /*  Rosenbrock at (-1.2, 1), the usual starting point, and at the         *
+     *  minimum.                                                              */
+    println!("grad rosenbrock(-1.2, 1) = {:?}",
+             gradient(rosenbrock, &[-1.2, 1.0]));
+    println!("grad rosenbrock(1, 1) = {:?}",
+             gradient(rosenbrock, &[1.0, 1.0]));
+
+    /*  (y cos(xy) e^z, x cos(xy) e^z, sin(xy) e^z) at (1, 2, 0).             */
+    let grad: Vec<f64> = gradient(three_variables, &[1.0, 2.0, 0.0]);
+    let exact: [f64; 3] = [2.0 * 2.0f64.cos(), 2.0f64.cos(), 2.0f64.sin()];
+    println!("grad sin(xy) exp(z) at (1, 2, 0) = {:?}", grad);
+    println!("exact = {:?}", exact);
+}
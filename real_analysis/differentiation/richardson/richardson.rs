@@ -0,0 +1,111 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Richardson extrapolation of central differences for accurate          *
+ *      derivatives.                                                          *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Computes the central difference approximation (f(x + h) - f(x - h)) / 2h  *
+ *  of f'(x). The error is O(h^2).                                            */
+fn central_difference(f: RealFunc, x: f64, h: f64) -> f64 {
+    return (f(x + h) - f(x - h)) / (2.0 * h);
+}
+/*  End of central_difference.                                                */
+
+/*  Computes f'(x) using Richardson extrapolation. The error of the central   *
+ *  difference D(h) has an expansion in even powers of h only:                *
+ *                                                                            *
+ *        D(h) = f'(x) + c_1 h^2 + c_2 h^4 + c_3 h^6 + ...                    *
+ *                                                                            *
+ *  Combining D(h) and D(h / 2) as (4 D(h / 2) - D(h)) / 3 cancels the h^2    *
+ *  term, leaving an O(h^4) approximation. Repeating this gives a triangular  *
+ *  table, as in Romberg integration:                                         *
+ *                                                                            *
+ *        T[i][0] = D(h / 2^i)                                                *
+ *                                                                            *
+ *                                T[i][j-1] - T[i-1][j-1]                     *
+ *        T[i][j] = T[i][j-1] +  -----------------------                      *
+ *                                       4^j - 1                              *
+ *                                                                            *
+ *  and T[i][j] has error O((h / 2^i)^(2j + 2)). The last diagonal entry is   *
+ *  returned. Only the previous row of the table is stored. A table with one  *
+ *  level is the plain central difference D(h), and levels = 0 is treated the *
+ *  same way, since there is no table to read from.                           */
+fn richardson_derivative(f: RealFunc, x: f64, h: f64, levels: usize) -> f64 {
+    if levels == 0 {
+        return central_difference(f, x, h);
+    }
+
+    let mut previous: Vec<f64> = Vec::with_capacity(levels);
+    let mut step: f64 = h;
+
+    for i in 0 .. levels {
+        let mut row: Vec<f64> = Vec::with_capacity(i + 1);
+        row.push(central_difference(f, x, step));
+        let mut factor: f64 = 4.0;
+
+        for j in 1 .. i + 1 {
+            let refined: f64 = row[j - 1];
+            let coarse: f64 = previous[j - 1];
+            row.push(refined + (refined - coarse) / (factor - 1.0));
+            factor = factor * 4.0;
+        }
+
+        previous = row;
+        step = 0.5 * step;
+    }
+
+    return previous[levels - 1];
+}
+/*  End of richardson_derivative.                                             */
+
+/*  Main routine used for comparing with a single central difference.         */
+fn main() {
+    let x: f64 = 1.0;
+    let exact: f64 = x.cos();
+    let h: f64 = 0.1;
+    let levels: usize = 5;
+
+    /*  The smallest step used by the table.                                  */
+    let smallest: f64 = h / ((1 << (levels - 1)) as f64);
+
+    let richardson: f64 = richardson_derivative(f64::sin, x, h, levels);
+    let central: f64 = central_difference(f64::sin, x, smallest);
+    let richardson_error: f64 = (richardson - exact).abs();
+    let central_error: f64 = (central - exact).abs();
+
+    println!("Central difference with h = {}: error = {:.3E}",
+             smallest, central_error);
+    println!("Richardson with {} levels:      error = {:.3E}",
+             levels, richardson_error);
+    println!("Improvement factor: {:.3E}", central_error / richardson_error);
+
+    /*  Zero levels falls back to a single central difference with step h.    */
+    println!("0 levels equals D(h): {}",
+             richardson_derivative(f64::sin, x, h, 0)
+                 == central_difference(f64::sin, x, h));
+}
@@ -0,0 +1,81 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Fourth order five-point stencil for the second derivative.            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Computes f''(x) with the five-point stencil:                              *
+ *                                                                            *
+ *                 -f(x-2h) + 16f(x-h) - 30f(x) + 16f(x+h) - f(x+2h)          *
+ *        f''(x) ~ -------------------------------------------------          *
+ *                                     12 h^2                                 *
+ *                                                                            *
+ *  Taylor expanding shows the truncation error is h^4 f^(6)(x) / 90. Each    *
+ *  evaluation of f carries a rounding error of about eps |f|, and the        *
+ *  coefficients have absolute sum 64, so the rounding error in the quotient  *
+ *  is roughly 64 eps |f| / 12 h^2. The first shrinks with h and the second   *
+ *  grows. Setting the derivative of their sum to zero gives the optimal h:   *
+ *                                                                            *
+ *        4 h^3 |f^(6)|     32 eps |f|                                        *
+ *        -------------  =  ----------   =>   h = (240 eps |f| / |f^(6)|)^1/6 *
+ *              90            3 h^3                                           *
+ *                                                                            *
+ *  For functions with |f| and |f^(6)| of similar size this is h ~ 6E-3,      *
+ *  giving an error near eps^(2/3), about 1E-10. Smaller h is worse, not      *
+ *  better.                                                                   */
+fn second_derivative(f: RealFunc, x: f64, h: f64) -> f64 {
+    let outer: f64 = f(x - 2.0 * h) + f(x + 2.0 * h);
+    let inner: f64 = f(x - h) + f(x + h);
+    return (16.0 * inner - outer - 30.0 * f(x)) / (12.0 * h * h);
+}
+/*  End of second_derivative.                                                 */
+
+/*  Main routine used for testing against the analytic second derivatives.    */
+fn main() {
+
+    /*  The optimal step for well-scaled functions, (240 eps)^(1/6).          */
+    let h: f64 = (240.0 * f64::EPSILON).powf(1.0 / 6.0);
+    let tolerance: f64 = 1.0E-9;
+    let points: [f64; 5] = [-1.0, 0.0, 0.5, 1.0, 2.0];
+
+    println!("h = {:.3E}", h);
+
+    /*  (exp x)'' = exp x and (sin x)'' = -sin x. The errors are measured     *
+     *  relative to the size of the function, which sets the round-off.       */
+    for &x in points.iter() {
+        let exp_approx: f64 = second_derivative(f64::exp, x, h);
+        let sin_approx: f64 = second_derivative(f64::sin, x, h);
+        let exp_error: f64 = (exp_approx - x.exp()).abs();
+        let sin_error: f64 = (sin_approx + x.sin()).abs();
+        let exp_ok: bool = exp_error < tolerance * x.exp().max(1.0);
+        let sin_ok: bool = sin_error < tolerance;
+
+        println!("x = {:>4}: exp error = {:.3E}, sin error = {:.3E}, \
+                  within tolerance: {}", x, exp_error, sin_error,
+                 exp_ok && sin_ok);
+    }
+}
@@ -0,0 +1,167 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Picard iteration for Fredholm integral equations of the second kind.  *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Solves the Fredholm integral equation of the second kind                  *
+ *                                                                            *
+ *                        b                                                   *
+ *                        -                                                   *
+ *                       |                                                    *
+ *        u(x) = g(x) +  |  K(x, t) u(t) dt                                   *
+ *                      -                                                     *
+ *                       a                                                    *
+ *                                                                            *
+ *  on the grid x_i = a + i h, i = 0, 1, ..., grid, with h = (b - a) / grid.  *
+ *  The integral is replaced by the trapezoidal rule with weights w_j, h / 2  *
+ *  at the ends and h inside, giving the discrete equation                    *
+ *                                                                            *
+ *        u_i = g(x_i) + sum_j w_j K(x_i, t_j) u_j                            *
+ *                                                                            *
+ *  This is solved by fixed point, or Picard, iteration: start with u = g and *
+ *  repeatedly substitute the current u into the right-hand side. The error   *
+ *  is multiplied by the integral operator each step. If |K| <= M on the      *
+ *  square, the operator shrinks errors by at least M (b - a), so the         *
+ *  iteration converges when M (b - a) < 1, by the contraction mapping        *
+ *  theorem. This is only sufficient. In general the iteration converges when *
+ *  the spectral radius of the operator is less than 1, and that radius is    *
+ *  the rate. The returned grid values differ from the true solution by the   *
+ *  O(h^2) error of the trapezoidal rule.                                     */
+fn picard_fixed_point(kernel: fn(f64, f64) -> f64, g: RealFunc,
+                      a: f64, b: f64, grid: u32, iterations: u32) -> Vec<f64> {
+    let n: usize = grid as usize + 1;
+    let h: f64 = (b - a) / grid as f64;
+    let xs: Vec<f64> = (0 .. n).map(|i| a + i as f64 * h).collect();
+    let gs: Vec<f64> = xs.iter().map(|&x| g(x)).collect();
+
+    /*  Trapezoidal weights.                                                  */
+    let mut weights: Vec<f64> = vec![h; n];
+    weights[0] = 0.5 * h;
+    weights[n - 1] = 0.5 * h;
+
+    /*  The kernel is sampled once, K[i][j] = w_j K(x_i, t_j), stored as      *
+     *  rows.                                                                 */
+    let mut matrix: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for i in 0 .. n {
+        for j in 0 .. n {
+            matrix[i][j] = weights[j] * kernel(xs[i], xs[j]);
+        }
+    }
+
+    let mut u: Vec<f64> = gs.clone();
+
+    for _ in 0 .. iterations {
+        let mut next: Vec<f64> = gs.clone();
+
+        for i in 0 .. n {
+            for j in 0 .. n {
+                next[i] = next[i] + matrix[i][j] * u[j];
+            }
+        }
+
+        u = next;
+    }
+
+    return u;
+}
+/*  End of picard_fixed_point.                                                */
+
+/*  K(x, t) = xt on [0, 1]. With g(x) = 2x / 3 the solution is u(x) = x,      *
+ *  since the integral of t^2 over [0, 1] is 1 / 3.                           */
+fn product_kernel(x: f64, t: f64) -> f64 {
+    return x * t;
+}
+
+fn product_rhs(x: f64) -> f64 {
+    return 2.0 * x / 3.0;
+}
+
+/*  K(x, t) = exp(x - t) / 2 on [0, 1]. With g(x) = 1 - (1 - 1/e) exp(x) / 2  *
+ *  the solution is u(x) = 1. Here M = e / 2 > 1, but the operator has rank   *
+ *  one and its only nonzero eigenvalue is the integral of K(t, t) = 1 / 2,   *
+ *  so the iteration still converges, halving the error each step.            */
+fn exponential_kernel(x: f64, t: f64) -> f64 {
+    return 0.5 * (x - t).exp();
+}
+
+fn exponential_rhs(x: f64) -> f64 {
+    let e: f64 = std::f64::consts::E;
+    return 1.0 - 0.5 * (1.0 - 1.0 / e) * x.exp();
+}
+
+/*  Computes the largest difference between the grid values and u(x_i).       */
+fn max_error(values: &[f64], u: RealFunc, a: f64, b: f64) -> f64 {
+    let h: f64 = (b - a) / (values.len() - 1) as f64;
+    let mut error: f64 = 0.0;
+
+    for i in 0 .. values.len() {
+        error = error.max((values[i] - u(a + i as f64 * h)).abs());
+    }
+
+    return error;
+}
+/*  End of max_error.                                                         */
+
+/*  The exact solutions.                                                      */
+fn identity(x: f64) -> f64 {
+    return x;
+}
+
+fn one(_x: f64) -> f64 {
+    return 1.0;
+}
+
+/*  Main routine used for testing Picard iteration.                           */
+fn main() {
+
+    /*  For K = xt the error shrinks by 1/3 each iteration, so 40 iterations  *
+     *  are plenty. Doubling the grid then cuts the error by about 4.         */
+    println!("u(x) = 2x/3 + int_0^1 xt u(t) dt, exact u(x) = x");
+
+    for &grid in [8, 16, 32, 64].iter() {
+        let u: Vec<f64> = picard_fixed_point(product_kernel, product_rhs,
+                                             0.0, 1.0, grid, 40);
+
+        println!("    grid = {:2}: max error = {:.3E}",
+                 grid, max_error(&u, identity, 0.0, 1.0));
+    }
+
+    /*  The iterates converge, the error against u = 1 levels off at the      *
+     *  discretization error once the iteration error is smaller.             */
+    println!("u(x) = g(x) + int_0^1 exp(x - t) u(t) dt / 2, exact u(x) = 1");
+
+    for &iterations in [1, 5, 10, 20, 40].iter() {
+        let u: Vec<f64> = picard_fixed_point(exponential_kernel,
+                                             exponential_rhs,
+                                             0.0, 1.0, 64, iterations);
+
+        println!("    iterations = {:2}: max error = {:.3E}",
+                 iterations, max_error(&u, one, 0.0, 1.0));
+    }
+}
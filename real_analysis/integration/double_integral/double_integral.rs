@@ -0,0 +1,122 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Double integrals over a rectangle with Simpson's rule in each         *
+ *      variable.                                                             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Returns the nodes and weights of the composite Simpson's rule on [a, b]   *
+ *  with n intervals. The weights are h / 3 times 1, 4, 2, 4, ..., 2, 4, 1.   *
+ *  Simpson's rule needs an even number of intervals, so odd n is rounded up. */
+fn simpson_rule(a: f64, b: f64, n: u32) -> (Vec<f64>, Vec<f64>) {
+    let intervals: u32 = if n % 2 == 0 { n.max(2) } else { n + 1 };
+    let h: f64 = (b - a) / intervals as f64;
+    let mut nodes: Vec<f64> = Vec::with_capacity(intervals as usize + 1);
+    let mut weights: Vec<f64> = Vec::with_capacity(intervals as usize + 1);
+
+    for k in 0 .. intervals + 1 {
+        let factor: f64 = if k == 0 || k == intervals {
+            1.0
+        } else if k % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+
+        nodes.push(a + k as f64 * h);
+        weights.push(factor * h / 3.0);
+    }
+
+    return (nodes, weights);
+}
+/*  End of simpson_rule.                                                      */
+
+/*  Integrates f over [ax, bx] x [ay, by]. Writing the double integral as an  *
+ *  iterated one,                                                             *
+ *                                                                            *
+ *         b_x  b_y                      b_x                                  *
+ *          -    -                        -                                   *
+ *         |    |                        |                                    *
+ *         |    |  f(x, y) dy dx   =     |  g(x) dx,   g(x) = int f(x, y) dy  *
+ *        -    -                        -                                     *
+ *         a_x  a_y                      a_x                                  *
+ *                                                                            *
+ *  and applying Simpson's rule to both the inner and outer integrals gives   *
+ *  the tensor product rule, a weighted sum of f over the grid of nodes (x_i, *
+ *  y_j) with weights w_i w_j. The error is O(h^4) in each direction, and     *
+ *  polynomials of degree 3 in each variable are integrated exactly.          *
+ *                                                                            *
+ *  The catch is the cost. With n nodes per axis, a d dimensional integral    *
+ *  needs n^d evaluations, while the error is still O(n^-4). For a fixed      *
+ *  budget N of evaluations, the error is O(N^(-4/d)), which is hopeless for  *
+ *  large d. This curse of dimensionality is what motivates Monte Carlo       *
+ *  integration, whose O(N^(-1/2)) error does not depend on d.                */
+fn double_integral(f: fn(f64, f64) -> f64, ax: f64, bx: f64,
+                   ay: f64, by: f64, nx: u32, ny: u32) -> f64 {
+    let (xs, wx): (Vec<f64>, Vec<f64>) = simpson_rule(ax, bx, nx);
+    let (ys, wy): (Vec<f64>, Vec<f64>) = simpson_rule(ay, by, ny);
+    let mut sum: f64 = 0.0;
+
+    for i in 0 .. xs.len() {
+
+        /*  Simpson's rule for the inner integral, g(x_i).                    */
+        let mut inner: f64 = 0.0;
+
+        for j in 0 .. ys.len() {
+            inner = inner + wy[j] * f(xs[i], ys[j]);
+        }
+
+        sum = sum + wx[i] * inner;
+    }
+
+    return sum;
+}
+/*  End of double_integral.                                                   */
+
+/*  f(x, y) = xy.                                                             */
+fn product(x: f64, y: f64) -> f64 {
+    return x * y;
+}
+
+/*  f(x, y) = sin(x) sin(y).                                                  */
+fn sine_product(x: f64, y: f64) -> f64 {
+    return x.sin() * y.sin();
+}
+
+/*  Main routine used for testing the double integral.                        */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+
+    /*  xy is a polynomial of degree 1 in each variable, exact with any grid. */
+    let value: f64 = double_integral(product, 0.0, 1.0, 0.0, 1.0, 2, 2);
+    println!("xy over [0, 1]^2 = {} (exact 0.25)", value);
+    println!("exact to rounding: {}", (value - 0.25).abs() < 1.0E-15);
+
+    /*  sin(x) sin(y) over [0, pi]^2 is 2 x 2 = 4. Doubling n cuts the error  *
+     *  by 16.                                                                */
+    for &n in [4, 8, 16, 32, 64].iter() {
+        let value: f64 = double_integral(sine_product, 0.0, pi, 0.0, pi, n, n);
+        println!("n = {:2}: integral = {:.12}, error = {:.3E}",
+                 n, value, (value - 4.0).abs());
+    }
+}
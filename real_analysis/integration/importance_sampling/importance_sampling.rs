@@ -0,0 +1,186 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Importance sampling, Monte Carlo integration with a non-uniform       *
+ *      proposal.                                                             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform double in [0, 1). The top 53 bits fill the mantissa *
+     *  exactly, scaled by 2^-53.                                             */
+    fn next_f64(&mut self) -> f64 {
+        return ((self.next_u64() >> 11) as f64) * (1.0 / 9007199254740992.0);
+    }
+}
+
+/*  Returns a standard normal random number with the Box-Muller transform.    *
+ *  Only one of the two values is used. 1 - next_f64() is never zero, so the  *
+ *  logarithm is finite.                                                      */
+fn standard_normal(rng: &mut Xorshift64) -> f64 {
+    let u1: f64 = 1.0 - rng.next_f64();
+    let u2: f64 = rng.next_f64();
+    let r: f64 = (-2.0 * u1.ln()).sqrt();
+    return r * (2.0 * std::f64::consts::PI * u2).cos();
+}
+/*  End of standard_normal.                                                   */
+
+/*  Estimates the integral of f by importance sampling. If X has density p,   *
+ *  positive wherever f is nonzero, then                                      *
+ *                                                                            *
+ *        -                  -                                                *
+ *       |                  |   f(x)            -      -                      *
+ *       |  f(x) dx    =    |   ---- p(x) dx = E| f(X) |                      *
+ *      -                  -    p(x)            | ---- |                      *
+ *                                              - p(X) -                      *
+ *                                                                            *
+ *  so the average of f(X) / p(X) over n draws from the proposal p estimates  *
+ *  the integral. The choice of p does not change the expected value, only    *
+ *  the variance. Plain Monte Carlo is the special case of a uniform p, and   *
+ *  wastes most samples where f is nearly zero. If p is roughly proportional  *
+ *  to |f|, the ratio f / p is nearly constant and the variance is small. The *
+ *  ideal p = |f| / int |f| would give zero variance for positive f, but      *
+ *  needs the answer to normalize it.                                         */
+fn importance_sample(f: RealFunc, sample: impl Fn(&mut Xorshift64) -> f64,
+                     pdf: RealFunc, n: u64, rng: &mut Xorshift64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for _ in 0 .. n {
+        let x: f64 = sample(rng);
+        sum = sum + f(x) / pdf(x);
+    }
+
+    return sum / n as f64;
+}
+/*  End of importance_sample.                                                 */
+
+/*  Width of the peak in the integrand.                                       */
+const WIDTH: f64 = 0.02;
+
+/*  Width of the Gaussian proposal, a little wider than the peak so that the  *
+ *  ratio f / p stays bounded.                                                */
+const PROPOSAL_WIDTH: f64 = 0.025;
+
+/*  A sharp peak at x = 0.5, exp(-(x - 0.5)^2 / (2 WIDTH^2)), restricted to   *
+ *  [0, 1]. The integral is WIDTH sqrt(2 pi), up to tails smaller than        *
+ *  1E-100.                                                                   */
+fn peak(x: f64) -> f64 {
+    if !(0.0 ..= 1.0).contains(&x) {
+        return 0.0;
+    }
+
+    let z: f64 = (x - 0.5) / WIDTH;
+    return (-0.5 * z * z).exp();
+}
+
+/*  The density of the uniform distribution on [0, 1].                        */
+fn uniform_pdf(x: f64) -> f64 {
+    return if (0.0 .. 1.0).contains(&x) { 1.0 } else { 0.0 };
+}
+
+/*  The density of the normal distribution with mean 0.5 and standard         *
+ *  deviation PROPOSAL_WIDTH.                                                 */
+fn gaussian_pdf(x: f64) -> f64 {
+    let z: f64 = (x - 0.5) / PROPOSAL_WIDTH;
+    let scale: f64 = PROPOSAL_WIDTH * (2.0 * std::f64::consts::PI).sqrt();
+    return (-0.5 * z * z).exp() / scale;
+}
+
+/*  Computes the mean and the sample variance of a list of estimates.         */
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let n: f64 = values.len() as f64;
+    let mean: f64 = values.iter().sum::<f64>() / n;
+    let squares: f64 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    return (mean, squares / (n - 1.0));
+}
+/*  End of mean_and_variance.                                                 */
+
+/*  Main routine comparing uniform and importance sampling.                   */
+fn main() {
+    let exact: f64 = WIDTH * (2.0 * std::f64::consts::PI).sqrt();
+    let mut rng: Xorshift64 = Xorshift64::new(2026);
+    let trials: usize = 200;
+    let n: u64 = 1000;
+
+    let mut uniform_estimates: Vec<f64> = Vec::with_capacity(trials);
+    let mut gaussian_estimates: Vec<f64> = Vec::with_capacity(trials);
+
+    for _ in 0 .. trials {
+        let uniform: f64 = importance_sample(peak, |r| r.next_f64(),
+                                             uniform_pdf, n, &mut rng);
+
+        let gaussian: f64 = importance_sample(
+            peak, |r| 0.5 + PROPOSAL_WIDTH * standard_normal(r),
+            gaussian_pdf, n, &mut rng
+        );
+
+        uniform_estimates.push(uniform);
+        gaussian_estimates.push(gaussian);
+    }
+
+    let (uniform_mean, uniform_variance): (f64, f64) =
+        mean_and_variance(&uniform_estimates);
+
+    let (gaussian_mean, gaussian_variance): (f64, f64) =
+        mean_and_variance(&gaussian_estimates);
+
+    println!("exact integral = {:.8}", exact);
+    println!("uniform:  mean = {:.8}, variance = {:.3E}",
+             uniform_mean, uniform_variance);
+    println!("gaussian: mean = {:.8}, variance = {:.3E}",
+             gaussian_mean, gaussian_variance);
+    println!("variance reduced by a factor of {:.0}",
+             uniform_variance / gaussian_variance);
+    println!("at least 100 times lower variance: {}",
+             100.0 * gaussian_variance < uniform_variance);
+}
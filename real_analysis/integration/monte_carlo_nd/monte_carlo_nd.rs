@@ -0,0 +1,160 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Monte Carlo integration over a box in any number of dimensions.       *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform double in [0, 1). The top 53 bits fill the mantissa *
+     *  exactly, scaled by 2^-53.                                             */
+    fn next_f64(&mut self) -> f64 {
+        return ((self.next_u64() >> 11) as f64) * (1.0 / 9007199254740992.0);
+    }
+}
+
+/*  Estimates the integral of f over the box [lows[0], highs[0]] x ... x      *
+ *  [lows[d-1], highs[d-1]]. If X is uniform in the box B, then E[f(X)] is    *
+ *  the integral divided by vol(B), so the average of f over N random points, *
+ *  times vol(B), estimates the integral.                                     *
+ *                                                                            *
+ *  By the central limit theorem the error is about sigma / sqrt(N) times     *
+ *  vol(B), where sigma is the standard deviation of f(X). The rate 1 /       *
+ *  sqrt(N) is slow, but it does not depend on the dimension d at all. A      *
+ *  tensor product rule with error O(h^p) needs N = n^d points for n per      *
+ *  axis, giving error O(N^(-p/d)), which is worse than Monte Carlo once d >  *
+ *  2p. In high dimensions Monte Carlo wins. The seed makes the estimate      *
+ *  reproducible.                                                             */
+fn monte_carlo_nd(f: fn(&[f64]) -> f64, lows: &[f64], highs: &[f64],
+                  samples: u64, seed: u64) -> f64 {
+    let dimension: usize = lows.len();
+    let mut rng: Xorshift64 = Xorshift64::new(seed);
+    let mut point: Vec<f64> = vec![0.0; dimension];
+    let mut volume: f64 = 1.0;
+    let mut sum: f64 = 0.0;
+
+    for k in 0 .. dimension {
+        volume = volume * (highs[k] - lows[k]);
+    }
+
+    for _ in 0 .. samples {
+        for k in 0 .. dimension {
+            point[k] = lows[k] + (highs[k] - lows[k]) * rng.next_f64();
+        }
+
+        sum = sum + f(&point);
+    }
+
+    return volume * sum / samples as f64;
+}
+/*  End of monte_carlo_nd.                                                    */
+
+/*  The indicator function of the unit ball, 1 if |x| <= 1 and 0 otherwise.   *
+ *  Its integral over a box containing the ball is the volume of the ball.    */
+fn unit_ball(x: &[f64]) -> f64 {
+    let norm_squared: f64 = x.iter().map(|xk| xk * xk).sum();
+    return if norm_squared <= 1.0 { 1.0 } else { 0.0 };
+}
+
+/*  The volume of the unit ball in d dimensions, pi^(d/2) / Gamma(d/2 + 1),   *
+ *  computed with the recursion V(d) = 2 pi V(d - 2) / d.                     */
+fn ball_volume(d: usize) -> f64 {
+    if d == 0 {
+        return 1.0;
+    }
+
+    if d == 1 {
+        return 2.0;
+    }
+
+    return 2.0 * std::f64::consts::PI * ball_volume(d - 2) / d as f64;
+}
+/*  End of ball_volume.                                                       */
+
+/*  Main routine used for testing Monte Carlo integration.                    */
+fn main() {
+    let samples: u64 = 1000000;
+    let mut accurate: bool = true;
+
+    /*  The ball fills less and less of the cube [-1, 1]^d as d grows, which  *
+     *  increases the relative error, but the method works unchanged.         */
+    for d in 2 .. 7 {
+        let lows: Vec<f64> = vec![-1.0; d];
+        let highs: Vec<f64> = vec![1.0; d];
+        let estimate: f64 =
+            monte_carlo_nd(unit_ball, &lows, &highs, samples, 12345);
+
+        let exact: f64 = ball_volume(d);
+        let relative: f64 = (estimate - exact).abs() / exact;
+
+        println!("d = {}: estimate = {:.6}, exact = {:.6}, error = {:.2E}",
+                 d, estimate, exact, relative);
+
+        accurate = accurate && relative < 0.02;
+    }
+
+    println!("within 2%: {}", accurate);
+
+    /*  The 1 / sqrt(N) rate. 100 times the samples gives about 10 times      *
+     *  smaller error, here in the d = 3 case, 4/3 pi.                        */
+    let exact: f64 = 4.0 * std::f64::consts::PI / 3.0;
+
+    for &n in [100, 10000, 1000000].iter() {
+        let mut mean_error: f64 = 0.0;
+
+        for seed in 1 .. 21 {
+            let estimate: f64 =
+                monte_carlo_nd(unit_ball, &[-1.0; 3], &[1.0; 3], n, seed);
+
+            mean_error = mean_error + (estimate - exact).abs() / 20.0;
+        }
+
+        println!("N = {:7}: mean error over 20 seeds = {:.3E}", n, mean_error);
+    }
+}
@@ -0,0 +1,151 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Stratified sampling, Monte Carlo integration with one sample set per  *
+ *      stratum.                                                              *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Marsaglia's xorshift64 generator. The state is a single non-zero 64-bit   *
+ *  word, scrambled by three shift-and-xor steps per call. The period is 2^64 *
+ *  - 1, every non-zero state appears exactly once. It is fast and simple,    *
+ *  but not suitable for cryptography.                                        */
+struct Xorshift64 {
+    state: u64
+}
+
+impl Xorshift64 {
+
+    /*  Creates a generator from a seed. Zero is a fixed point of the         *
+     *  xorshift steps, so a zero seed is replaced by an arbitrary non-zero   *
+     *  constant.                                                             */
+    fn new(seed: u64) -> Xorshift64 {
+        let state: u64 = if seed == 0 {0x9E3779B97F4A7C15} else {seed};
+        return Xorshift64 {state: state};
+    }
+
+    /*  Returns the next 64 random bits.                                      */
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        return x;
+    }
+
+    /*  Returns a uniform double in [0, 1). The top 53 bits fill the mantissa *
+     *  exactly, scaled by 2^-53.                                             */
+    fn next_f64(&mut self) -> f64 {
+        return ((self.next_u64() >> 11) as f64) * (1.0 / 9007199254740992.0);
+    }
+}
+
+/*  Plain Monte Carlo on [a, b] with n uniform samples.                       */
+fn monte_carlo(f: RealFunc, a: f64, b: f64, n: u32,
+               rng: &mut Xorshift64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for _ in 0 .. n {
+        sum = sum + f(a + (b - a) * rng.next_f64());
+    }
+
+    return (b - a) * sum / n as f64;
+}
+/*  End of monte_carlo.                                                       */
+
+/*  Stratified Monte Carlo. [a, b] is split into equal subintervals, the      *
+ *  strata, and plain Monte Carlo is applied in each with per_stratum         *
+ *  samples. The estimate is the sum over the strata.                         *
+ *                                                                            *
+ *  The variance of plain Monte Carlo has two parts, the variation of f       *
+ *  inside the strata and the variation of the averages between strata.       *
+ *  Sampling every stratum a fixed number of times removes the second part,   *
+ *  since no stratum can be over or under represented by chance, so the       *
+ *  variance is never larger and usually much smaller. For smooth f and one   *
+ *  sample per stratum, the variance drops from O(1/N) to O(1/N^3).           *
+ *                                                                            *
+ *  With one sample per stratum this is jittered sampling, as used in         *
+ *  computer graphics: a regular grid with each point moved randomly within   *
+ *  its cell. It keeps the even coverage of a grid, without the aliasing a    *
+ *  perfectly regular pattern causes.                                         */
+fn stratified_monte_carlo(f: RealFunc, a: f64, b: f64, strata: u32,
+                          per_stratum: u32, rng: &mut Xorshift64) -> f64 {
+    let width: f64 = (b - a) / strata as f64;
+    let mut sum: f64 = 0.0;
+
+    for k in 0 .. strata {
+        let left: f64 = a + k as f64 * width;
+        sum = sum + monte_carlo(f, left, left + width, per_stratum, rng);
+    }
+
+    return sum;
+}
+/*  End of stratified_monte_carlo.                                            */
+
+/*  Computes the mean and the sample variance of a list of estimates.         */
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let n: f64 = values.len() as f64;
+    let mean: f64 = values.iter().sum::<f64>() / n;
+    let squares: f64 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    return (mean, squares / (n - 1.0));
+}
+/*  End of mean_and_variance.                                                 */
+
+/*  Main routine comparing plain and stratified Monte Carlo.                  */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+    let mut rng: Xorshift64 = Xorshift64::new(1024);
+    let trials: usize = 500;
+
+    /*  The integral of sin over [0, pi] is 2. Each estimate uses N = 1000    *
+     *  samples: plain, 10 strata of 100, and 1000 strata of 1 (jittered).    */
+    let mut plain: Vec<f64> = Vec::with_capacity(trials);
+    let mut coarse: Vec<f64> = Vec::with_capacity(trials);
+    let mut jittered: Vec<f64> = Vec::with_capacity(trials);
+
+    for _ in 0 .. trials {
+        let f: RealFunc = f64::sin;
+        plain.push(monte_carlo(f, 0.0, pi, 1000, &mut rng));
+        coarse.push(stratified_monte_carlo(f, 0.0, pi, 10, 100, &mut rng));
+        jittered.push(stratified_monte_carlo(f, 0.0, pi, 1000, 1, &mut rng));
+    }
+
+    let (plain_mean, plain_variance): (f64, f64) = mean_and_variance(&plain);
+    let (coarse_mean, coarse_variance): (f64, f64) = mean_and_variance(&coarse);
+    let (jittered_mean, jittered_variance): (f64, f64) =
+        mean_and_variance(&jittered);
+
+    println!("plain:         mean = {:.8}, variance = {:.3E}",
+             plain_mean, plain_variance);
+    println!("10 strata:     mean = {:.8}, variance = {:.3E}",
+             coarse_mean, coarse_variance);
+    println!("1000 strata:   mean = {:.8}, variance = {:.3E}",
+             jittered_mean, jittered_variance);
+    println!("stratified variance lower: {}",
+             coarse_variance < plain_variance &&
+             jittered_variance < coarse_variance);
+}
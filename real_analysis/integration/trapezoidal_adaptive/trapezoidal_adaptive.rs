@@ -0,0 +1,113 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Trapezoidal rule with interval doubling until successive estimates    *
+ *      agree.                                                                *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Integrates f over [a, b] with the trapezoidal rule, doubling the number   *
+ *  of intervals until two successive estimates differ by less than tol. When *
+ *  the intervals are halved, the old points are all still sample points, so  *
+ *  the new estimate is:                                                      *
+ *                                                                            *
+ *                   1             h  ---                                     *
+ *        T(h / 2) = - T(h)  +    --- \    f(x_k + h / 2)                     *
+ *                   2             2  /                                       *
+ *                                    ---                                     *
+ *                                     k                                      *
+ *                                                                            *
+ *  and only the new midpoints need to be evaluated. Going from n to 2n       *
+ *  intervals costs n evaluations, not 2n + 1. Returns the estimate and the   *
+ *  number of refinements.                                                    */
+fn trapezoidal_adaptive(f: RealFunc, a: f64, b: f64, tol: f64) -> (f64, u32) {
+
+    /*  Stop after this many doublings, 2^30 intervals, even if tol is not    *
+     *  met.                                                                  */
+    const MAXIMUM_NUMBER_OF_REFINEMENTS: u32 = 30;
+
+    let mut intervals: u64 = 1;
+    let mut h: f64 = b - a;
+    let mut estimate: f64 = 0.5 * h * (f(a) + f(b));
+
+    for refinement in 1 .. MAXIMUM_NUMBER_OF_REFINEMENTS + 1 {
+        let mut sum: f64 = 0.0;
+
+        for k in 0 .. intervals {
+            sum = sum + f(a + ((k as f64) + 0.5) * h);
+        }
+
+        let next: f64 = 0.5 * estimate + 0.5 * h * sum;
+        intervals = 2 * intervals;
+        h = 0.5 * h;
+
+        if (next - estimate).abs() < tol {
+            return (next, refinement);
+        }
+
+        estimate = next;
+    }
+
+    return (estimate, MAXIMUM_NUMBER_OF_REFINEMENTS);
+}
+/*  End of trapezoidal_adaptive.                                              */
+
+/*  Counter for the number of times counted_exp is called.                    */
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/*  The exponential function, counting each call.                             */
+fn counted_exp(x: f64) -> f64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    return x.exp();
+}
+
+/*  Main routine used for testing the adaptive trapezoidal rule.              */
+fn main() {
+    let exact: f64 = 1.0f64.exp() - 1.0;
+
+    /*  The error is O(h^2), each doubling cuts the difference by about 4, so *
+     *  a tolerance of 1E-8 takes around a dozen refinements.                 */
+    CALLS.store(0, Ordering::SeqCst);
+    let (value, refinements): (f64, u32) =
+        trapezoidal_adaptive(counted_exp, 0.0, 1.0, 1.0E-8);
+    let calls: usize = CALLS.load(Ordering::SeqCst);
+
+    /*  Without reuse every level would cost 2^k + 1 evaluations from         *
+     *  scratch.                                                              */
+    let final_points: usize = (1 << refinements) + 1;
+    let mut without_reuse: usize = 0;
+
+    for k in 0 .. refinements + 1 {
+        without_reuse = without_reuse + (1 << k) + 1;
+    }
+
+    println!("integral = {:.12}, error = {:.3E}", value, (value - exact).abs());
+    println!("refinements = {}", refinements);
+    println!("evaluations = {}, points in final grid = {}",
+             calls, final_points);
+    println!("evaluations without reuse = {}", without_reuse);
+}
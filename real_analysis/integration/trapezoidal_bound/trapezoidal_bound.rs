@@ -0,0 +1,98 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The trapezoidal rule together with a guaranteed bound on its error.   *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Function pointer notation is a little confusing. Create a typedef for it  *
+ *  so we do not need to explicitly use it later.                             */
+type RealFunc = fn(f64) -> f64;
+
+/*  Integrates f over [a, b] with the composite trapezoidal rule on n equal   *
+ *  intervals, and also returns a rigorous bound on the error. On one         *
+ *  interval of width h, the error of the trapezoid is -h^3 f''(c) / 12 for   *
+ *  some c in the interval. Summing over the n intervals, with h = (b - a) /  *
+ *  n, and using |f''| <= M, gives                                            *
+ *                                                                            *
+ *                               3                                            *
+ *                        (b - a)  M                                          *
+ *        |error|  <=    -----------                                          *
+ *                               2                                            *
+ *                           12 n                                             *
+ *                                                                            *
+ *  The caller supplies M = fpp_max, any upper bound for |f''| on [a, b]. The *
+ *  bound is only as good as M, but it is guaranteed, unlike the estimate     *
+ *  from comparing two step sizes. Doubling n divides the bound by 4.         */
+fn trapezoidal_with_bound(f: RealFunc, fpp_max: f64,
+                          a: f64, b: f64, n: u32) -> (f64, f64) {
+    let h: f64 = (b - a) / n as f64;
+    let mut sum: f64 = 0.5 * (f(a) + f(b));
+
+    for k in 1 .. n {
+        sum = sum + f(a + k as f64 * h);
+    }
+
+    /*  n * n overflows a u32 for n >= 65536, so square n as a double.        */
+    let width: f64 = b - a;
+    let n_squared: f64 = (n as f64) * (n as f64);
+    let bound: f64 = width * width * width * fpp_max / (12.0 * n_squared);
+    return (h * sum, bound);
+}
+/*  End of trapezoidal_with_bound.                                            */
+
+/*  Main routine used for testing the error bound.                            */
+fn main() {
+
+    /*  The integral of sin over [0, pi] is 2, and |sin''| = |sin| <= 1.      */
+    let pi: f64 = std::f64::consts::PI;
+    let mut within: bool = true;
+
+    for &n in [1, 2, 4, 8, 16, 32, 64, 128, 1024].iter() {
+        let (estimate, bound): (f64, f64) =
+            trapezoidal_with_bound(f64::sin, 1.0, 0.0, pi, n);
+
+        let error: f64 = (estimate - 2.0).abs();
+        within = within && error <= bound;
+
+        println!("n = {:4}: estimate = {:.12}, error = {:.3E}, bound = {:.3E}",
+                 n, estimate, error, bound);
+    }
+
+    println!("true error within the bound: {}", within);
+
+    /*  exp on [0, 1], with |exp''| <= e. The error is about 63% of the       *
+     *  bound, the ratio (e - 1) / e of the average of exp'' to its maximum.  */
+    let exact: f64 = 1.0f64.exp() - 1.0;
+    let (estimate, bound): (f64, f64) =
+        trapezoidal_with_bound(f64::exp, 1.0f64.exp(), 0.0, 1.0, 100);
+
+    println!("exp: error = {:.3E}, bound = {:.3E}",
+             (estimate - exact).abs(), bound);
+
+    /*  n = 100000 is past the point where n^2 overflows a u32.               */
+    let (estimate, bound): (f64, f64) =
+        trapezoidal_with_bound(f64::sin, 1.0, 0.0, pi, 100000);
+
+    println!("n = 100000: error = {:.3E}, bound = {:.3E}",
+             (estimate - 2.0).abs(), bound);
+}
@@ -0,0 +1,167 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Barycentric Lagrange interpolation, with weights computed once and    *
+ *      reused.                                                               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*  Evaluates the Lagrange interpolating polynomial through the points        *
+ *  (xs[j], ys[j]) at x:                                                      *
+ *                                                                            *
+ *                 ---              ---   x - x_k                             *
+ *        p(x) =   \     y_j        | |  ---------                            *
+ *                 /                | |  x_j - x_k                            *
+ *                 ---              k != j                                    *
+ *                  j                                                         *
+ *                                                                            */
+fn lagrange_eval(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for j in 0 .. xs.len() {
+        let mut basis: f64 = 1.0;
+
+        for k in 0 .. xs.len() {
+            if k != j {
+                basis = basis * (x - xs[k]) / (xs[j] - xs[k]);
+            }
+        }
+
+        sum = sum + ys[j] * basis;
+    }
+
+    return sum;
+}
+/*  End of lagrange_eval.                                                     */
+
+/*  Computes the n Chebyshev nodes on [a, b]. On [-1, 1] these are the roots  *
+ *  of the Chebyshev polynomial T_n, cos((2k + 1) pi / 2n), the projections   *
+ *  onto the x axis of equally spaced points on the unit semicircle. They     *
+ *  cluster near the endpoints, which is exactly where equally spaced         *
+ *  interpolation goes wrong. An affine map moves them to [a, b].             */
+fn chebyshev_nodes(a: f64, b: f64, n: usize) -> Vec<f64> {
+    let center: f64 = 0.5 * (a + b);
+    let radius: f64 = 0.5 * (b - a);
+    let pi: f64 = std::f64::consts::PI;
+
+    return (0 .. n).map(|k| {
+        let theta: f64 = pi * ((2 * k + 1) as f64) / ((2 * n) as f64);
+        return center + radius * theta.cos();
+    }).collect();
+}
+/*  End of chebyshev_nodes.                                                   */
+
+/*  Counter for the number of times the weights are computed.                 */
+static WEIGHT_COMPUTATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/*  Computes the barycentric weights for the nodes xs:                        *
+ *                                                                            *
+ *                       1                                                    *
+ *        w_j = -----------------                                             *
+ *               ---                                                          *
+ *               | |  (x_j - x_k)                                             *
+ *               | |                                                          *
+ *              k != j                                                        *
+ *                                                                            *
+ *  This is the only O(n^2) part of the computation. It depends only on the   *
+ *  nodes, not the data, so the same weights may be used for many different   *
+ *  ys and any number of evaluation points.                                   */
+fn barycentric_weights(xs: &[f64]) -> Vec<f64> {
+    let mut weights: Vec<f64> = vec![1.0; xs.len()];
+    WEIGHT_COMPUTATIONS.fetch_add(1, Ordering::SeqCst);
+
+    for j in 0 .. xs.len() {
+        for k in 0 .. xs.len() {
+            if k != j {
+                weights[j] = weights[j] / (xs[j] - xs[k]);
+            }
+        }
+    }
+
+    return weights;
+}
+/*  End of barycentric_weights.                                               */
+
+/*  Evaluates the interpolating polynomial with the second (true) barycentric *
+ *  formula:                                                                  *
+ *                                                                            *
+ *                ---  w_j y_j      /  ---    w_j                             *
+ *                \   ---------    /   \   ---------                          *
+ *        p(x) =  /    x - x_j    /    /    x - x_j                           *
+ *                ---            /     ---                                    *
+ *                 j                    j                                     *
+ *                                                                            *
+ *  This costs O(n) per point. It is also numerically stable, the errors in   *
+ *  the weights largely cancel between the numerator and the denominator. The *
+ *  formula divides by zero if x is one of the nodes, in which case the data  *
+ *  value is returned directly.                                               */
+fn barycentric_eval(xs: &[f64], ys: &[f64], weights: &[f64], x: f64) -> f64 {
+    let mut numerator: f64 = 0.0;
+    let mut denominator: f64 = 0.0;
+
+    for j in 0 .. xs.len() {
+        let diff: f64 = x - xs[j];
+
+        if diff == 0.0 {
+            return ys[j];
+        }
+
+        let term: f64 = weights[j] / diff;
+        numerator = numerator + term * ys[j];
+        denominator = denominator + term;
+    }
+
+    return numerator / denominator;
+}
+/*  End of barycentric_eval.                                                  */
+
+/*  Main routine used for comparing with the naive Lagrange evaluator.        */
+fn main() {
+    let xs: Vec<f64> = chebyshev_nodes(-1.0, 1.0, 16);
+    let ys: Vec<f64> = xs.iter().map(|x| (3.0 * x).sin() + x.exp()).collect();
+
+    /*  The weights are computed once, before any evaluation.                 */
+    let weights: Vec<f64> = barycentric_weights(&xs);
+    let mut max_difference: f64 = 0.0;
+
+    for k in 0 .. 1001 {
+        let x: f64 = -1.0 + 0.002 * (k as f64);
+        let naive: f64 = lagrange_eval(&xs, &ys, x);
+        let fast: f64 = barycentric_eval(&xs, &ys, &weights, x);
+        max_difference = max_difference.max((naive - fast).abs());
+    }
+
+    /*  At the nodes themselves the data must be reproduced exactly.          */
+    let mut exact_at_nodes: bool = true;
+
+    for j in 0 .. xs.len() {
+        exact_at_nodes &= barycentric_eval(&xs, &ys, &weights, xs[j]) == ys[j];
+    }
+
+    let computations: usize = WEIGHT_COMPUTATIONS.load(Ordering::SeqCst);
+
+    println!("Max difference from Lagrange: {:.3E}, within 1e-10: {}",
+             max_difference, max_difference < 1.0E-10);
+    println!("Exact at the nodes: {}", exact_at_nodes);
+    println!("Weight computations for 1001 evaluations: {}", computations);
+}
@@ -0,0 +1,126 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Compares polynomial interpolation on equally spaced and Chebyshev     *
+ *      nodes for the Runge function.                                         *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Evaluates the Lagrange interpolating polynomial through the points        *
+ *  (xs[j], ys[j]) at x:                                                      *
+ *                                                                            *
+ *                 ---              ---   x - x_k                             *
+ *        p(x) =   \     y_j        | |  ---------                            *
+ *                 /                | |  x_j - x_k                            *
+ *                 ---              k != j                                    *
+ *                  j                                                         *
+ *                                                                            */
+fn lagrange_eval(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for j in 0 .. xs.len() {
+        let mut basis: f64 = 1.0;
+
+        for k in 0 .. xs.len() {
+            if k != j {
+                basis = basis * (x - xs[k]) / (xs[j] - xs[k]);
+            }
+        }
+
+        sum = sum + ys[j] * basis;
+    }
+
+    return sum;
+}
+/*  End of lagrange_eval.                                                     */
+
+/*  Computes n equally spaced nodes on [a, b], including both endpoints.      */
+fn equally_spaced_nodes(a: f64, b: f64, n: usize) -> Vec<f64> {
+    if n == 1 {
+        return vec![0.5 * (a + b)];
+    }
+
+    let h: f64 = (b - a) / ((n - 1) as f64);
+    return (0 .. n).map(|k| a + (k as f64) * h).collect();
+}
+/*  End of equally_spaced_nodes.                                              */
+
+/*  Computes the n Chebyshev nodes on [a, b]. On [-1, 1] these are the roots  *
+ *  of the Chebyshev polynomial T_n, cos((2k + 1) pi / 2n), the projections   *
+ *  onto the x axis of equally spaced points on the unit semicircle. They     *
+ *  cluster near the endpoints, which is exactly where equally spaced         *
+ *  interpolation goes wrong. An affine map moves them to [a, b].             */
+fn chebyshev_nodes(a: f64, b: f64, n: usize) -> Vec<f64> {
+    let center: f64 = 0.5 * (a + b);
+    let radius: f64 = 0.5 * (b - a);
+    let pi: f64 = std::f64::consts::PI;
+
+    return (0 .. n).map(|k| {
+        let theta: f64 = pi * ((2 * k + 1) as f64) / ((2 * n) as f64);
+        return center + radius * theta.cos();
+    }).collect();
+}
+/*  End of chebyshev_nodes.                                                   */
+
+/*  The Runge function, 1 / (1 + 25 x^2). It is perfectly smooth on the real  *
+ *  line, but has poles at x = +/- i/5, close to [-1, 1], and this is what    *
+ *  causes the trouble for equally spaced interpolation.                      */
+fn runge(x: f64) -> f64 {
+    return 1.0 / (1.0 + 25.0 * x * x);
+}
+
+/*  Computes the maximum interpolation error over a fine grid on [-1, 1].     */
+fn max_error(nodes: &[f64]) -> f64 {
+    let values: Vec<f64> = nodes.iter().map(|x| runge(*x)).collect();
+    let mut error: f64 = 0.0;
+
+    for x in equally_spaced_nodes(-1.0, 1.0, 2001).iter() {
+        let y: f64 = lagrange_eval(nodes, &values, *x);
+        error = error.max((y - runge(*x)).abs());
+    }
+
+    return error;
+}
+/*  End of max_error.                                                         */
+
+/*  Main routine used for comparing the two choices of nodes.                 */
+fn main() {
+
+    /*  With equally spaced nodes the error grows without bound as the degree *
+     *  increases, wild oscillations appear near the endpoints (Runge's       *
+     *  phenomenon). With Chebyshev nodes the error decreases steadily.       */
+    for &degree in [5, 10, 15, 20].iter() {
+        let n: usize = degree + 1;
+        let equal: f64 = max_error(&equally_spaced_nodes(-1.0, 1.0, n));
+        let chebyshev: f64 = max_error(&chebyshev_nodes(-1.0, 1.0, n));
+
+        println!("degree {:>2}: equal nodes error = {:.3E}, \
+                  Chebyshev nodes error = {:.3E}", degree, equal, chebyshev);
+    }
+
+    /*  For degree 15 the error is reduced by more than an order of           *
+     *  magnitude.                                                            */
+    let equal: f64 = max_error(&equally_spaced_nodes(-1.0, 1.0, 16));
+    let chebyshev: f64 = max_error(&chebyshev_nodes(-1.0, 1.0, 16));
+    println!("degree 15 ratio: {:.3E}, Chebyshev dramatically better: {}",
+             equal / chebyshev, chebyshev * 10.0 < equal);
+}
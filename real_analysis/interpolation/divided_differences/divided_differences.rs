@@ -0,0 +1,163 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Newton's divided-difference form of the interpolating polynomial.     *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Evaluates the Lagrange interpolating polynomial through the points        *
+ *  (xs[j], ys[j]) at x:                                                      *
+ *                                                                            *
+ *                 ---              ---   x - x_k                             *
+ *        p(x) =   \     y_j        | |  ---------                            *
+ *                 /                | |  x_j - x_k                            *
+ *                 ---              k != j                                    *
+ *                  j                                                         *
+ *                                                                            */
+fn lagrange_eval(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for j in 0 .. xs.len() {
+        let mut basis: f64 = 1.0;
+
+        for k in 0 .. xs.len() {
+            if k != j {
+                basis = basis * (x - xs[k]) / (xs[j] - xs[k]);
+            }
+        }
+
+        sum = sum + ys[j] * basis;
+    }
+
+    return sum;
+}
+/*  End of lagrange_eval.                                                     */
+
+/*  Computes the coefficients of the Newton form of the interpolating         *
+ *  polynomial, the divided differences f[x_0, ..., x_k] for k = 0, 1, ...,   *
+ *  n. These are defined recursively by:                                      *
+ *                                                                            *
+ *                             f[x_1, ..., x_k] - f[x_0, ..., x_k-1]          *
+ *        f[x_0, ..., x_k]  =  -------------------------------------          *
+ *                                           x_k - x_0                        *
+ *                                                                            *
+ *  The table is computed in place, column by column from the bottom up, so   *
+ *  only O(n) storage is needed.                                              */
+fn divided_differences(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n: usize = xs.len();
+    let mut coeffs: Vec<f64> = ys.to_vec();
+
+    for k in 1 .. n {
+        for j in (k .. n).rev() {
+            coeffs[j] = (coeffs[j] - coeffs[j - 1]) / (xs[j] - xs[j - k]);
+        }
+    }
+
+    return coeffs;
+}
+/*  End of divided_differences.                                               */
+
+/*  Evaluates the Newton form at x using nested multiplication:               *
+ *                                                                            *
+ *        p(x) = c_0 + (x - x_0)(c_1 + (x - x_1)(c_2 + ... (c_n) ... ))       *
+ *                                                                            */
+fn newton_eval(xs: &[f64], coeffs: &[f64], x: f64) -> f64 {
+    let n: usize = coeffs.len();
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    let mut sum: f64 = coeffs[n - 1];
+
+    for k in (0 .. n - 1).rev() {
+        sum = coeffs[k] + (x - xs[k]) * sum;
+    }
+
+    return sum;
+}
+/*  End of newton_eval.                                                       */
+
+/*  Appends a new node to an existing interpolant. The existing coefficients  *
+ *  do not change, only the new one, f[x_0, ..., x_n], needs to be computed.  *
+ *  This is done by building the new bottom row of the table, which takes     *
+ *  O(n) operations. The row is kept between calls, it holds the last entry   *
+ *  of each column: f[x_n], f[x_n-1, x_n], ..., f[x_0, ..., x_n].             */
+fn add_node(xs: &mut Vec<f64>, coeffs: &mut Vec<f64>,
+            last_row: &mut Vec<f64>, x: f64, y: f64) {
+    let n: usize = xs.len();
+    let mut entry: f64 = y;
+    xs.push(x);
+
+    for k in 0 .. n {
+        let next: f64 = (entry - last_row[k]) / (x - xs[n - k - 1]);
+        last_row[k] = entry;
+        entry = next;
+    }
+
+    last_row.push(entry);
+    coeffs.push(entry);
+}
+/*  End of add_node.                                                          */
+
+/*  Main routine used for testing the Newton form.                            */
+fn main() {
+    let f = |x: f64| (2.0 * x).cos() + x * x;
+    let xs: Vec<f64> = (0 .. 10).map(|k| -1.0 + 0.2 * (k as f64)).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+    let coeffs: Vec<f64> = divided_differences(&xs, &ys);
+    let mut max_difference: f64 = 0.0;
+
+    for k in 0 .. 1001 {
+        let x: f64 = -1.0 + 0.002 * (k as f64);
+        let naive: f64 = lagrange_eval(&xs, &ys, x);
+        let newton: f64 = newton_eval(&xs, &coeffs, x);
+        max_difference = max_difference.max((naive - newton).abs());
+    }
+
+    println!("Max difference from Lagrange: {:.3E}, within 1e-10: {}",
+             max_difference, max_difference < 1.0E-10);
+
+    /*  Build the same interpolant one node at a time. The coefficients       *
+     *  computed so far never change, each new node adds exactly one.         */
+    let mut inc_xs: Vec<f64> = Vec::new();
+    let mut inc_coeffs: Vec<f64> = Vec::new();
+    let mut last_row: Vec<f64> = Vec::new();
+    let mut prefix_unchanged: bool = true;
+
+    for k in 0 .. xs.len() {
+        let before: Vec<f64> = inc_coeffs.clone();
+        add_node(&mut inc_xs, &mut inc_coeffs, &mut last_row, xs[k], ys[k]);
+        prefix_unchanged &= inc_coeffs.len() == k + 1;
+        prefix_unchanged &= inc_coeffs[.. k] == before[..];
+    }
+
+    let mut coeff_difference: f64 = 0.0;
+
+    for k in 0 .. coeffs.len() {
+        let diff: f64 = (coeffs[k] - inc_coeffs[k]).abs();
+        coeff_difference = coeff_difference.max(diff);
+    }
+
+    println!("Incremental coefficients match: {:.3E}", coeff_difference);
+    println!("Each node added one coefficient only: {}", prefix_unchanged);
+}
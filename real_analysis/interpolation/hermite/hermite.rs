@@ -0,0 +1,172 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Hermite interpolation, matching both values and derivatives at the    *
+ *      nodes.                                                                *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the Hermite interpolating polynomial in Newton form. Given n     *
+ *  distinct nodes x_i, values y_i, and derivatives dy_i, there is a unique   *
+ *  polynomial p of degree at most 2n - 1 with p(x_i) = y_i and p'(x_i) =     *
+ *  dy_i. It is computed with Newton's divided differences on the node        *
+ *  sequence z = (x_0, x_0, x_1, x_1, ..., x_n-1, x_n-1), where each node     *
+ *  appears twice. The first-order difference of a repeated node would be     *
+ *  0 / 0, it is replaced by its limit:                                       *
+ *                                                                            *
+ *                                  f(z_j) - f(z_i)                           *
+ *        f[z_i, z_j] =      lim    ---------------  =  f'(z_i)               *
+ *                       z_j -> z_i   z_j - z_i                               *
+ *                                                                            *
+ *  which is the supplied derivative. All higher differences involve distinct *
+ *  endpoints and use the usual recursion. Returns the node sequence z and    *
+ *  the coefficients, p(x) = c_0 + c_1 (x - z_0) + c_2 (x - z_0)(x - z_1) +   *
+ *  ...                                                                       */
+fn hermite_coefficients(xs: &[f64],
+                        ys: &[f64],
+                        dys: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let m: usize = 2 * xs.len();
+    let z: Vec<f64> = (0 .. m).map(|j| xs[j / 2]).collect();
+    let mut coeffs: Vec<f64> = (0 .. m).map(|j| ys[j / 2]).collect();
+
+    /*  First order differences. Odd j pairs a node with its own copy.        */
+    for j in (1 .. m).rev() {
+        if j % 2 == 1 {
+            coeffs[j] = dys[j / 2];
+        } else {
+            coeffs[j] = (coeffs[j] - coeffs[j - 1]) / (z[j] - z[j - 1]);
+        }
+    }
+
+    /*  Higher order differences, computed in place from the bottom up.       */
+    for k in 2 .. m {
+        for j in (k .. m).rev() {
+            coeffs[j] = (coeffs[j] - coeffs[j - 1]) / (z[j] - z[j - k]);
+        }
+    }
+
+    return (z, coeffs);
+}
+/*  End of hermite_coefficients.                                              */
+
+/*  Computes the Hermite interpolating polynomial at x, evaluating the Newton *
+ *  form with nested multiplication.                                          */
+fn hermite_interpolate(xs: &[f64], ys: &[f64], dys: &[f64], x: f64) -> f64 {
+    if xs.is_empty() {
+        return 0.0;
+    }
+
+    let (z, coeffs): (Vec<f64>, Vec<f64>) = hermite_coefficients(xs, ys, dys);
+    let m: usize = coeffs.len();
+    let mut sum: f64 = coeffs[m - 1];
+
+    for k in (0 .. m - 1).rev() {
+        sum = coeffs[k] + (x - z[k]) * sum;
+    }
+
+    return sum;
+}
+/*  End of hermite_interpolate.                                               */
+
+/*  Computes the derivative of the Hermite interpolating polynomial at x.     *
+ *  Nested multiplication computes p_k(x) = c_k + (x - z_k) p_k+1(x), and     *
+ *  differentiating gives p_k'(x) = p_k+1(x) + (x - z_k) p_k+1'(x), so the    *
+ *  derivative is carried along with the value.                               */
+fn hermite_derivative(xs: &[f64], ys: &[f64], dys: &[f64], x: f64) -> f64 {
+    if xs.is_empty() {
+        return 0.0;
+    }
+
+    let (z, coeffs): (Vec<f64>, Vec<f64>) = hermite_coefficients(xs, ys, dys);
+    let m: usize = coeffs.len();
+    let mut sum: f64 = coeffs[m - 1];
+    let mut derivative: f64 = 0.0;
+
+    for k in (0 .. m - 1).rev() {
+        derivative = sum + (x - z[k]) * derivative;
+        sum = coeffs[k] + (x - z[k]) * sum;
+    }
+
+    return derivative;
+}
+/*  End of hermite_derivative.                                                */
+
+/*  The cubic x^3 - 2x^2 + x - 3, used for testing.                           */
+fn cubic(x: f64) -> f64 {
+    return ((x - 2.0) * x + 1.0) * x - 3.0;
+}
+
+/*  The derivative of the cubic, 3x^2 - 4x + 1.                               */
+fn cubic_derivative(x: f64) -> f64 {
+    return (3.0 * x - 4.0) * x + 1.0;
+}
+
+/*  Main routine used for testing Hermite interpolation.                      */
+fn main() {
+
+    /*  Two nodes give a polynomial of degree at most 3, which must be the    *
+     *  cubic itself.                                                         */
+    let xs: [f64; 2] = [-1.0, 2.0];
+    let ys: Vec<f64> = xs.iter().map(|x| cubic(*x)).collect();
+    let dys: Vec<f64> = xs.iter().map(|x| cubic_derivative(*x)).collect();
+    let mut max_error: f64 = 0.0;
+
+    for k in 0 .. 101 {
+        let x: f64 = -2.0 + 0.05 * (k as f64);
+        let y: f64 = hermite_interpolate(&xs, &ys, &dys, x);
+        max_error = max_error.max((y - cubic(x)).abs());
+    }
+
+    println!("Max error reproducing the cubic: {:.3E}", max_error);
+
+    /*  The interpolant must also match the supplied slopes at the nodes, and *
+     *  since it is the cubic, its derivative matches the cubic's everywhere. */
+    for j in 0 .. xs.len() {
+        let slope: f64 = hermite_derivative(&xs, &ys, &dys, xs[j]);
+        println!("x = {:.1}: slope = {}, supplied = {}", xs[j], slope, dys[j]);
+    }
+
+    let mut max_slope_error: f64 = 0.0;
+
+    for k in 0 .. 101 {
+        let x: f64 = -2.0 + 0.05 * (k as f64);
+        let slope: f64 = hermite_derivative(&xs, &ys, &dys, x);
+        let error: f64 = (slope - cubic_derivative(x)).abs();
+        max_slope_error = max_slope_error.max(error);
+    }
+
+    println!("Max error in the derivative: {:.3E}", max_slope_error);
+
+    /*  For sin on three nodes, check the value and derivative of the         *
+     *  interpolant at the nodes.                                             */
+    let xs: [f64; 3] = [0.0, 0.7, 1.5];
+    let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    let dys: Vec<f64> = xs.iter().map(|x| x.cos()).collect();
+
+    for j in 0 .. xs.len() {
+        let value: f64 = hermite_interpolate(&xs, &ys, &dys, xs[j]);
+        let slope: f64 = hermite_derivative(&xs, &ys, &dys, xs[j]);
+
+        println!("x = {:.1}: value error = {:.3E}, slope error = {:.3E}",
+                 xs[j], (value - ys[j]).abs(), (slope - dys[j]).abs());
+    }
+}
@@ -0,0 +1,214 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Fits a polynomial to data in the least-squares sense.                 *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Matrices are stored as a vector of rows, a[i][j] being the entry in row i *
+ *  and column j.                                                             */
+
+/*  Computes the QR decomposition A = QR of an m x n matrix with m >= n. Q is *
+ *  an m x m orthogonal matrix and R is an m x n upper triangular matrix.     *
+ *                                                                            *
+ *  A Householder reflection H = I - 2 v v^T, with v a unit vector, reflects  *
+ *  across the plane perpendicular to v. Given a vector x, choosing v         *
+ *  parallel to x - alpha e_1 with |alpha| = ||x|| gives Hx = alpha e_1,      *
+ *  zeroing every entry but the first. Applying one reflection per column     *
+ *  zeroes everything below the diagonal, giving R = H_n ... H_1 A, and Q =   *
+ *  H_1 ... H_n since each H is its own inverse. An empty matrix has empty    *
+ *  factors.                                                                  */
+fn qr_householder(a: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    if a.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let m: usize = a.len();
+    let n: usize = a[0].len();
+
+    /*  R starts as A and is reduced column by column. Q starts as the        *
+     *  identity and accumulates the reflections.                             */
+    let mut r: Vec<Vec<f64>> = a.to_vec();
+    let mut q: Vec<Vec<f64>> = vec![vec![0.0; m]; m];
+
+    for i in 0 .. m {
+        q[i][i] = 1.0;
+    }
+
+    /*  The last row of a square matrix has nothing below the diagonal.       */
+    let steps: usize = if m > n { n } else { m - 1 };
+
+    for k in 0 .. steps {
+
+        /*  The part of column k on and below the diagonal.                   */
+        let mut v: Vec<f64> = (k .. m).map(|i| r[i][k]).collect();
+        let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        /*  Nothing to zero out in this column.                               */
+        if norm == 0.0 {
+            continue;
+        }
+
+        /*  Pick the sign of alpha opposite to x_1. Then x_1 - alpha is a sum *
+         *  of two numbers with the same sign, avoiding cancellation.         */
+        let alpha: f64 = if v[0] > 0.0 { -norm } else { norm };
+        v[0] = v[0] - alpha;
+
+        let v_norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        for x in v.iter_mut() {
+            *x = *x / v_norm;
+        }
+
+        /*  Apply H to R from the left, R -> R - 2 v (v^T R).                 */
+        for column in 0 .. n {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + v[i - k] * r[i][column];
+            }
+
+            for i in k .. m {
+                r[i][column] = r[i][column] - 2.0 * v[i - k] * dot;
+            }
+        }
+
+        /*  Apply H to Q from the right, Q -> Q - 2 (Q v) v^T.                */
+        for row in 0 .. m {
+            let mut dot: f64 = 0.0;
+
+            for i in k .. m {
+                dot = dot + q[row][i] * v[i - k];
+            }
+
+            for i in k .. m {
+                q[row][i] = q[row][i] - 2.0 * dot * v[i - k];
+            }
+        }
+    }
+
+    return (q, r);
+}
+/*  End of qr_householder.                                                    */
+
+/*  Solves the upper triangular system formed by the top-left n x n part of   *
+ *  u, working up from the last row.                                          */
+fn back_substitution(u: &[Vec<f64>], b: &[f64], n: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for i in (0 .. n).rev() {
+        let mut sum: f64 = b[i];
+
+        for j in i + 1 .. n {
+            sum = sum - u[i][j] * x[j];
+        }
+
+        x[i] = sum / u[i][i];
+    }
+
+    return x;
+}
+/*  End of back_substitution.                                                 */
+
+/*  Computes the x minimizing ||Ax - b|| using A = QR. Q is orthogonal, so    *
+ *  ||Ax - b|| = ||Rx - Q^T b||. The bottom rows of R are zero, and the best  *
+ *  we can do is solve the top n rows exactly. Orthogonal transformations do  *
+ *  not amplify errors, so this only loses digits according to the condition  *
+ *  number of A itself.                                                       */
+fn lstsq_qr(a: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n: usize = a[0].len();
+    let (q, r): (Vec<Vec<f64>>, Vec<Vec<f64>>) = qr_householder(a);
+
+    /*  Compute Q^T b, the entries are the dot products of b with the columns *
+     *  of Q.                                                                 */
+    let q_transpose_b: Vec<f64> = (0 .. q.len()).map(|j| {
+        return (0 .. b.len()).map(|i| q[i][j] * b[i]).sum::<f64>();
+    }).collect();
+
+    return back_substitution(&r, &q_transpose_b, n);
+}
+/*  End of lstsq_qr.                                                          */
+
+/*  Builds the Vandermonde matrix whose row i is [1, x_i, x_i^2, ...,         *
+ *  x_i^degree].                                                              */
+fn vandermonde(xs: &[f64], degree: usize) -> Vec<Vec<f64>> {
+    let mut matrix: Vec<Vec<f64>> = Vec::with_capacity(xs.len());
+
+    for x in xs.iter() {
+        let mut row: Vec<f64> = Vec::with_capacity(degree + 1);
+        let mut power: f64 = 1.0;
+
+        for _ in 0 ..= degree {
+            row.push(power);
+            power = power * x;
+        }
+
+        matrix.push(row);
+    }
+
+    return matrix;
+}
+/*  End of vandermonde.                                                       */
+
+/*  Computes the coefficients c_0, ..., c_d of the polynomial of degree d     *
+ *  minimizing the sum of (p(x_i) - y_i)^2. There are degree + 1             *
+ *  coefficients, so at least degree + 1 points are needed, with one y value  *
+ *  for each x value. Otherwise the function panics. The coefficients are     *
+ *  returned in increasing order of degree.                                   *
+ *                                                                            *
+ *  Evaluating p at every x_i is multiplying the coefficient vector by the    *
+ *  Vandermonde matrix V, so we want the least-squares solution of Vc = y.    *
+ *  For high degrees the columns 1, x, x^2, ... become nearly parallel on an  *
+ *  interval, V is badly conditioned, and the coefficients lose accuracy.     *
+ *  Even QR cannot fix this, it only avoids making it worse. Keep the degree  *
+ *  modest, or center and scale the data to [-1, 1] first.                    */
+fn polyfit(xs: &[f64], ys: &[f64], degree: usize) -> Vec<f64> {
+    assert!(xs.len() == ys.len(), "polyfit: xs and ys differ in length");
+    assert!(xs.len() > degree, "polyfit: need at least degree + 1 points");
+
+    let matrix: Vec<Vec<f64>> = vandermonde(xs, degree);
+    return lstsq_qr(&matrix, ys);
+}
+/*  End of polyfit.                                                           */
+
+/*  Main routine used for testing the polynomial fit.                         */
+fn main() {
+
+    /*  Sample the parabola y = 1 - 2x + 3x^2 exactly. The fit should recover *
+     *  [1, -2, 3].                                                           */
+    let xs: Vec<f64> = (0 .. 11).map(|k| -1.0 + 0.25 * (k as f64)).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| 1.0 - 2.0 * x + 3.0 * x * x).collect();
+    let coefficients: Vec<f64> = polyfit(&xs, &ys, 2);
+    let expected: [f64; 3] = [1.0, -2.0, 3.0];
+    let mut error: f64 = 0.0;
+
+    for (c, e) in coefficients.iter().zip(expected.iter()) {
+        error = error.max((c - e).abs());
+    }
+
+    println!("Fitted coefficients: {:?}", coefficients);
+    println!("Max error = {:.3E}, within 1e-8: {}", error, error < 1.0E-8);
+
+    /*  Fitting a line to the same data gives the best linear approximation,  *
+     *  the x^2 term is absorbed as well as possible.                         */
+    println!("Degree 1 fit: {:?}", polyfit(&xs, &ys, 1));
+}
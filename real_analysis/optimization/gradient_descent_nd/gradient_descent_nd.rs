@@ -0,0 +1,269 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Gradient descent, with and without momentum, using dual number        *
+ *      gradients.                                                            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Operators for dual number arithmetic, and a counter for the steps taken.  */
+use std::ops::{Add, Div, Mul, Sub};
+
+/*  Dual numbers a + b e with e^2 = 0. Expanding a function in a Taylor       *
+ *  series gives f(a + b e) = f(a) + f'(a) b e exactly, since all higher      *
+ *  powers of e vanish. Evaluating f on x + 1 e therefore computes f(x) and   *
+ *  f'(x) together, with no truncation error, as long as f is built from      *
+ *  operations that obey this rule. This is forward mode automatic            *
+ *  differentiation.                                                          */
+#[derive(Clone, Copy, Debug)]
+struct Dual {
+    value: f64,
+    deriv: f64
+}
+
+impl Dual {
+
+    /*  A constant, c + 0 e, whose derivative is zero.                        */
+    fn constant(c: f64) -> Dual {
+        return Dual { value: c, deriv: 0.0 };
+    }
+    /*  End of constant.                                                      */
+}
+
+/*  Dual addition, done component-wise.                                       */
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value + other.value,
+            deriv: self.deriv + other.deriv
+        };
+    }
+}
+
+/*  Dual subtraction, also done component-wise.                               */
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value - other.value,
+            deriv: self.deriv - other.deriv
+        };
+    }
+}
+
+/*  (a + b e)(c + d e) = ac + (ad + bc) e, the product rule.                  */
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        return Dual {
+            value: self.value * other.value,
+            deriv: self.value * other.deriv + self.deriv * other.value
+        };
+    }
+}
+
+/*  (a + b e) / (c + d e) = a / c + (bc - ad) / c^2 e, the quotient rule.     */
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        let numerator: f64 =
+            self.deriv * other.value - self.value * other.deriv;
+
+        return Dual {
+            value: self.value / other.value,
+            deriv: numerator / (other.value * other.value)
+        };
+    }
+}
+
+/*  Computes the gradient of f at x with forward mode automatic               *
+ *  differentiation. A dual number carries one derivative, so the partials    *
+ *  are found one at a time. For the k-th partial, component k is seeded with *
+ *  deriv = 1 and the rest with deriv = 0, so the dual part of f is the       *
+ *  directional derivative along the k-th axis. This needs n evaluations of f *
+ *  for n variables, the same count as forward differences, but the partials  *
+ *  are exact up to rounding.                                                 */
+fn gradient(f: fn(&[Dual]) -> Dual, x: &[f64]) -> Vec<f64> {
+    let mut grad: Vec<f64> = Vec::with_capacity(x.len());
+    let mut point: Vec<Dual> =
+        x.iter().map(|&xk| Dual::constant(xk)).collect();
+
+    for k in 0 .. x.len() {
+        point[k].deriv = 1.0;
+        grad.push(f(&point).deriv);
+        point[k].deriv = 0.0;
+    }
+
+    return grad;
+}
+/*  End of gradient.                                                          */
+
+/*  Both methods stop early once the gradient is smaller than this.           */
+const TOLERANCE: f64 = 1.0E-10;
+
+/*  Computes the Euclidean norm of a vector.                                  */
+fn norm(v: &[f64]) -> f64 {
+    return v.iter().map(|vk| vk * vk).sum::<f64>().sqrt();
+}
+/*  End of norm.                                                              */
+
+/*  Minimizes f by gradient descent, repeatedly stepping downhill:            *
+ *                                                                            *
+ *        x <- x - r grad f(x)                                                *
+ *                                                                            *
+ *  with learning rate r. Stops after the given number of iterations, or once *
+ *  |grad f| < TOLERANCE. Returns the final point and the number of steps.   *
+ *                                                                            *
+ *  For a quadratic f = x^T A x / 2 each step multiplies the error along an   *
+ *  eigenvector of A with eigenvalue, or curvature, c by 1 - r c. If L is the *
+ *  largest curvature, the method converges only if |1 - r L| < 1, that is r  *
+ *  < 2 / L. Past that the error along the steepest direction grows each step *
+ *  and the iterates diverge. Even below it, the slowest direction shrinks by *
+ *  only 1 - r m, m the smallest curvature, so steep narrow valleys are slow. */
+fn minimize_with_steps(f: fn(&[Dual]) -> Dual, x0: &[f64], learning_rate: f64,
+                       iterations: u32) -> (Vec<f64>, usize) {
+    let mut x: Vec<f64> = x0.to_vec();
+    let mut steps: usize = 0;
+
+    for _ in 0 .. iterations {
+        let grad: Vec<f64> = gradient(f, &x);
+
+        if norm(&grad) < TOLERANCE {
+            break;
+        }
+
+        for k in 0 .. x.len() {
+            x[k] = x[k] - learning_rate * grad[k];
+        }
+
+        steps = steps + 1;
+    }
+
+    return (x, steps);
+}
+/*  End of minimize_with_steps.                                               */
+
+/*  Minimizes f by gradient descent, returning only the final point.          */
+fn minimize(f: fn(&[Dual]) -> Dual, x0: &[f64],
+            learning_rate: f64, iterations: u32) -> Vec<f64> {
+    let (x, _): (Vec<f64>, usize) =
+        minimize_with_steps(f, x0, learning_rate, iterations);
+
+    return x;
+}
+/*  End of minimize.                                                          */
+
+/*  Minimizes f by gradient descent with momentum, Polyak's heavy ball        *
+ *  method. The step v remembers a fraction of the previous step:             *
+ *                                                                            *
+ *        v <- momentum v - r grad f(x)                                       *
+ *        x <- x + v                                                          *
+ *                                                                            *
+ *  Along a narrow valley the gradients across it alternate in sign and       *
+ *  cancel in v, while the small gradients along it add up, so the iterates   *
+ *  pick up speed where plain descent crawls. With momentum near 1 - sqrt(m / *
+ *  L) the error shrinks by about 1 - sqrt(m / L) per step instead of 1 - m / *
+ *  L. Returns the final point and the number of steps.                      */
+fn minimize_momentum_with_steps(f: fn(&[Dual]) -> Dual, x0: &[f64],
+                                learning_rate: f64, momentum: f64,
+                                iterations: u32) -> (Vec<f64>, usize) {
+    let mut x: Vec<f64> = x0.to_vec();
+    let mut v: Vec<f64> = vec![0.0; x.len()];
+    let mut steps: usize = 0;
+
+    for _ in 0 .. iterations {
+        let grad: Vec<f64> = gradient(f, &x);
+
+        if norm(&grad) < TOLERANCE {
+            break;
+        }
+
+        for k in 0 .. x.len() {
+            v[k] = momentum * v[k] - learning_rate * grad[k];
+            x[k] = x[k] + v[k];
+        }
+
+        steps = steps + 1;
+    }
+
+    return (x, steps);
+}
+/*  End of minimize_momentum_with_steps.                                      */
+
+/*  Minimizes f by gradient descent with momentum, returning only the final   *
+ *  point.                                                                    */
+fn minimize_momentum(f: fn(&[Dual]) -> Dual, x0: &[f64], learning_rate: f64,
+                     momentum: f64, iterations: u32) -> Vec<f64> {
+    let (x, _): (Vec<f64>, usize) = minimize_momentum_with_steps(
+        f, x0, learning_rate, momentum, iterations
+    );
+
+    return x;
+}
+/*  End of minimize_momentum.                                                 */
+
+/*  A quadratic bowl (x - 1)^2 + 10 (y + 2)^2 with minimum at (1, -2). The    *
+ *  curvatures, the eigenvalues of the Hessian, are m = 2 and L = 20, so      *
+ *  plain descent converges for r < 2 / L = 0.1.                              */
+fn bowl(v: &[Dual]) -> Dual {
+    let x: Dual = v[0] - Dual::constant(1.0);
+    let y: Dual = v[1] + Dual::constant(2.0);
+    return x * x + Dual::constant(10.0) * y * y;
+}
+
+/*  Main routine used for testing the two methods.                            */
+fn main() {
+    let start: [f64; 2] = [5.0, 3.0];
+
+    let (plain, plain_steps): (Vec<f64>, usize) =
+        minimize_with_steps(bowl, &start, 0.05, 10000);
+
+    let (heavy, heavy_steps): (Vec<f64>, usize) =
+        minimize_momentum_with_steps(bowl, &start, 0.05, 0.5, 10000);
+
+    println!("gradient descent: {:?} after {} steps", plain, plain_steps);
+    println!("with momentum:    {:?} after {} steps", heavy, heavy_steps);
+
+    let error: f64 = norm(&[plain[0] - 1.0, plain[1] + 2.0])
+        .max(norm(&[heavy[0] - 1.0, heavy[1] + 2.0]));
+
+    println!("both converge to (1, -2): {}", error < 1.0E-9);
+    println!("momentum needs fewer steps: {}", heavy_steps < plain_steps);
+
+    /*  The plain versions return the same points, without the step count.    */
+    let same: bool = minimize(bowl, &start, 0.05, 10000) == plain
+                  && minimize_momentum(bowl, &start, 0.05, 0.5, 10000) == heavy;
+    println!("minimize and minimize_momentum agree: {}", same);
+
+    /*  Just above 2 / L = 0.1 the y error is multiplied by 1 - 0.11 x 20 =   *
+     *  -1.2 each step, and descent diverges.                                 */
+    for &rate in [0.09, 0.11].iter() {
+        let x: Vec<f64> = minimize(bowl, &start, rate, 100);
+        println!("learning rate {}: error after 100 steps = {:.3E}",
+                 rate, norm(&[x[0] - 1.0, x[1] + 2.0]));
+    }
+}
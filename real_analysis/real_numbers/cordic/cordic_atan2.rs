@@ -0,0 +1,180 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      CORDIC in both modes: rotation for sine and cosine, vectoring for     *
+ *      atan2 and hypot.                                                      *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Builds the table of angles atan(2^-k), k = 0, 1, ..., iterations - 1. In  *
+ *  hardware this table is precomputed and stored, here it is simply computed *
+ *  with the standard library.                                                */
+fn angle_table(iterations: u32) -> Vec<f64> {
+    let mut table: Vec<f64> = Vec::with_capacity(iterations as usize);
+    let mut power: f64 = 1.0;
+
+    for _ in 0 .. iterations {
+        table.push(power.atan());
+        power = 0.5 * power;
+    }
+
+    return table;
+}
+/*  End of angle_table.                                                       */
+
+/*  Each CORDIC step rotates by +/- atan(2^-k) without the cos factor, which  *
+ *  stretches the vector by sqrt(1 + 2^-2k). After n steps the total stretch  *
+ *  is the gain:                                                              *
+ *                                                                            *
+ *              n-1                                                           *
+ *              | |                                                           *
+ *        K  =  | |  sqrt(1 + 2^-2k)  ~=  1.6467602581...                     *
+ *              k=0                                                           *
+ *                                                                            */
+fn cordic_gain(iterations: u32) -> f64 {
+    let mut gain: f64 = 1.0;
+    let mut power: f64 = 1.0;
+
+    for _ in 0 .. iterations {
+        gain = gain * (1.0 + power * power).sqrt();
+        power = 0.5 * power;
+    }
+
+    return gain;
+}
+/*  End of cordic_gain.                                                       */
+
+/*  Rotation mode. Starting with (1 / K, 0), rotate towards the angle theta,  *
+ *  at each step choosing the direction that reduces the remaining angle z.   *
+ *  The final vector is (cos(theta), sin(theta)). This converges for |theta|  *
+ *  <= sum of the table, about 1.7433, so theta is first reduced to [-pi/2,   *
+ *  pi/2] by a half turn if needed.                                           */
+fn cordic_sincos(theta: f64, iterations: u32) -> (f64, f64) {
+    let pi: f64 = std::f64::consts::PI;
+    let table: Vec<f64> = angle_table(iterations);
+
+    /*  Reduce to (-pi, pi], then fold into [-pi/2, pi/2], remembering the    *
+     *  sign flip that a half turn introduces.                                */
+    let mut z: f64 = theta - 2.0 * pi * (theta / (2.0 * pi)).round();
+    let mut sign: f64 = 1.0;
+
+    if z > 0.5 * pi {
+        z = z - pi;
+        sign = -1.0;
+    } else if z < -0.5 * pi {
+        z = z + pi;
+        sign = -1.0;
+    }
+
+    let mut x: f64 = 1.0 / cordic_gain(iterations);
+    let mut y: f64 = 0.0;
+    let mut power: f64 = 1.0;
+
+    for k in 0 .. iterations as usize {
+        let d: f64 = if z >= 0.0 {1.0} else {-1.0};
+        let x_next: f64 = x - d * y * power;
+        y = y + d * x * power;
+        x = x_next;
+        z = z - d * table[k];
+        power = 0.5 * power;
+    }
+
+    return (sign * y, sign * x);
+}
+/*  End of cordic_sincos.                                                     */
+
+/*  Vectoring mode. Starting with (x, y), rotate towards the positive x axis, *
+ *  at each step choosing the direction that reduces |y|, and accumulate the  *
+ *  angles used. When y reaches zero the accumulated angle is atan2(y, x) and *
+ *  the vector has been stretched to (K hypot(x, y), 0). This uses the same   *
+ *  angle table and gain as rotation mode. Vectoring only converges for x >=  *
+ *  0, so points in the left half plane are first rotated by a half turn, (x, *
+ *  y) -> (-x, -y), adding +/- pi to the angle. Returns (atan2(y, x),         *
+ *  hypot(x, y)).                                                             */
+fn cordic_atan2(y: f64, x: f64, iterations: u32) -> (f64, f64) {
+    let pi: f64 = std::f64::consts::PI;
+    let table: Vec<f64> = angle_table(iterations);
+
+    /*  The origin has no direction. Match the standard library here.         */
+    if x == 0.0 && y == 0.0 {
+        return (y.atan2(x), 0.0);
+    }
+
+    let mut u: f64 = x;
+    let mut v: f64 = y;
+    let mut z: f64 = 0.0;
+
+    if x < 0.0 {
+        u = -x;
+        v = -y;
+        z = if y >= 0.0 {pi} else {-pi};
+    }
+
+    let mut power: f64 = 1.0;
+
+    for k in 0 .. iterations as usize {
+        let d: f64 = if v < 0.0 {1.0} else {-1.0};
+        let u_next: f64 = u - d * v * power;
+        v = v + d * u * power;
+        u = u_next;
+        z = z - d * table[k];
+        power = 0.5 * power;
+    }
+
+    return (z, u / cordic_gain(iterations));
+}
+/*  End of cordic_atan2.                                                      */
+
+/*  Main routine used for testing against the standard library.               */
+fn main() {
+    const ITERATIONS: u32 = 40;
+    const TOLERANCE: f64 = 1.0E-9;
+
+    /*  Points in all four quadrants and on all four axes.                    */
+    let points: [(f64, f64); 8] = [
+        (1.0, 2.0), (-3.0, 0.5), (-0.25, -4.0), (2.5, -1.5),
+        (1.0, 0.0), (0.0, 3.0), (-2.0, 0.0), (0.0, -0.5)
+    ];
+
+    let mut all_ok: bool = true;
+
+    for &(x, y) in points.iter() {
+        let (angle, radius): (f64, f64) = cordic_atan2(y, x, ITERATIONS);
+        let angle_error: f64 = (angle - y.atan2(x)).abs();
+        let radius_error: f64 = (radius - x.hypot(y)).abs();
+        all_ok &= angle_error < TOLERANCE && radius_error < TOLERANCE;
+
+        println!("({:>5}, {:>4}): atan2 error = {:.3E}, hypot error = {:.3E}",
+                 x, y, angle_error, radius_error);
+    }
+
+    println!("All within {:.0E}: {}", TOLERANCE, all_ok);
+
+    /*  Rotation mode with the same table, then vectoring recovers the angle. */
+    let theta: f64 = 2.5;
+    let (s, c): (f64, f64) = cordic_sincos(theta, ITERATIONS);
+    let (angle, _): (f64, f64) = cordic_atan2(s, c, ITERATIONS);
+
+    println!("sin error = {:.3E}, cos error = {:.3E}, round trip = {:.3E}",
+             (s - theta.sin()).abs(), (c - theta.cos()).abs(),
+             (angle - theta).abs());
+}
@@ -0,0 +1,166 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Exponential and natural logarithm via hyperbolic CORDIC.              *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the shift sequence for hyperbolic CORDIC. Unlike the circular    *
+ *  case, the angles atanh(2^-k) do not shrink fast enough for the iteration  *
+ *  to converge if each k is used once: the sum of the remaining angles can   *
+ *  be smaller than the current one. Repeating the steps k = 4, 13, 40, ...,  *
+ *  where each index is 3k + 1 for the previous one, fixes this. The sequence *
+ *  starts at k = 1, since atanh(1) is infinite.                              */
+fn shift_sequence(iterations: u32) -> Vec<u32> {
+    let mut shifts: Vec<u32> = Vec::with_capacity(iterations as usize);
+    let mut k: u32 = 1;
+    let mut repeat: u32 = 4;
+
+    while shifts.len() < iterations as usize {
+        shifts.push(k);
+
+        if k == repeat && shifts.len() < iterations as usize {
+            shifts.push(k);
+            repeat = 3 * repeat + 1;
+        }
+
+        k = k + 1;
+    }
+
+    return shifts;
+}
+/*  End of shift_sequence.                                                    */
+
+/*  Runs the hyperbolic CORDIC iteration on (x, y, z). In rotation mode the   *
+ *  direction is chosen to drive z to zero, in vectoring mode to drive y to   *
+ *  zero. Each step applies the hyperbolic rotation:                          *
+ *                                                                            *
+ *        x <- x + d y 2^-k,    y <- y + d x 2^-k,    z <- z - d atanh(2^-k)  *
+ *                                                                            *
+ *  which scales the vector by sqrt(1 - 2^-2k).                               */
+fn cordic_hyperbolic(x: f64, y: f64, z: f64, iterations: u32,
+                     rotation: bool) -> (f64, f64, f64) {
+    let (mut u, mut v, mut w): (f64, f64, f64) = (x, y, z);
+
+    for k in shift_sequence(iterations) {
+        let power: f64 = 0.5f64.powi(k as i32);
+        let positive: bool = if rotation {w >= 0.0} else {v < 0.0};
+        let d: f64 = if positive {1.0} else {-1.0};
+        let u_next: f64 = u + d * v * power;
+        v = v + d * u * power;
+        u = u_next;
+        w = w - d * power.atanh();
+    }
+
+    return (u, v, w);
+}
+/*  End of cordic_hyperbolic.                                                 */
+
+/*  The product of the scale factors sqrt(1 - 2^-2k) over the shift sequence, *
+ *  about 0.8281593609602.                                                    */
+fn hyperbolic_gain(iterations: u32) -> f64 {
+    let mut gain: f64 = 1.0;
+
+    for k in shift_sequence(iterations) {
+        let power: f64 = 0.5f64.powi(k as i32);
+        gain = gain * (1.0 - power * power).sqrt();
+    }
+
+    return gain;
+}
+/*  End of hyperbolic_gain.                                                   */
+
+/*  Computes exp(x). Rotation mode starting from (1 / K, 0, r) ends at        *
+ *  (cosh(r), sinh(r), 0), and exp(r) = cosh(r) + sinh(r). This only          *
+ *  converges for |r| <= 1.1182, the sum of the angles, so x is first reduced *
+ *  as x = n ln(2) + r with |r| <= ln(2) / 2, and exp(x) = 2^n exp(r).        */
+fn cordic_exp(x: f64, iterations: u32) -> f64 {
+    let ln_2: f64 = std::f64::consts::LN_2;
+    let n: f64 = (x / ln_2).round();
+    let r: f64 = x - n * ln_2;
+    let start: f64 = 1.0 / hyperbolic_gain(iterations);
+    let (c, s, _): (f64, f64, f64) =
+        cordic_hyperbolic(start, 0.0, r, iterations, true);
+
+    return (c + s) * 2.0f64.powi(n as i32);
+}
+/*  End of cordic_exp.                                                        */
+
+/*  Computes ln(x). Vectoring mode starting from (m + 1, m - 1, 0) drives y   *
+ *  to zero and ends with z = atanh((m - 1) / (m + 1)) = ln(m) / 2. This      *
+ *  converges only for m in roughly [0.107, 9.36], so x is first written as x *
+ *  = m 2^e with m in [0.5, 1), and ln(x) = ln(m) + e ln(2). Non-positive     *
+ *  inputs have no real logarithm, NaN is returned.                           */
+fn cordic_ln(x: f64, iterations: u32) -> f64 {
+    if x.is_nan() || x <= 0.0 {
+        return (x - x) / (x - x);
+    }
+
+    if x.is_infinite() {
+        return x;
+    }
+
+    let mut m: f64 = x;
+    let mut e: i32 = 0;
+
+    while m >= 1.0 {
+        m = 0.5 * m;
+        e = e + 1;
+    }
+
+    while m < 0.5 {
+        m = 2.0 * m;
+        e = e - 1;
+    }
+
+    let (_, _, z): (f64, f64, f64) =
+        cordic_hyperbolic(m + 1.0, m - 1.0, 0.0, iterations, false);
+
+    return 2.0 * z + (e as f64) * std::f64::consts::LN_2;
+}
+/*  End of cordic_ln.                                                         */
+
+/*  Main routine used for testing against the standard library.               */
+fn main() {
+    const ITERATIONS: u32 = 40;
+    const TOLERANCE: f64 = 1.0E-8;
+    let mut all_ok: bool = true;
+
+    /*  The errors are relative, exp grows quickly over this range.           */
+    for k in 0 .. 21 {
+        let x: f64 = -5.0 + 0.5 * (k as f64);
+        let error: f64 = (cordic_exp(x, ITERATIONS) / x.exp() - 1.0).abs();
+        all_ok &= error < TOLERANCE;
+    }
+
+    let inputs: [f64; 7] = [1.0E-3, 0.1, 0.5, 1.0, 2.0, 10.0, 1.0E3];
+
+    for &x in inputs.iter() {
+        let error: f64 = (cordic_ln(x, ITERATIONS) - x.ln()).abs();
+        all_ok &= error < TOLERANCE;
+        println!("ln({}) error = {:.3E}", x, error);
+    }
+
+    println!("exp(1) = {}", cordic_exp(1.0, ITERATIONS));
+    println!("ln(-1) = {}", cordic_ln(-1.0, ITERATIONS));
+    println!("All within {:.0E}: {}", TOLERANCE, all_ok);
+}
@@ -0,0 +1,120 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Heron's method seeded with an exponent-halving bit hack for large     *
+ *      inputs.                                                               *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Runs Heron's method for sqrt(x) from the given guess, for at most         *
+ *  max_iterations steps, stopping early once (x - a^2) / x is within         *
+ *  epsilon, the same test as herons_method. Returns the approximation and    *
+ *  the number of steps taken.                                                */
+fn heron_steps(x: f64, guess: f64, max_iterations: u32) -> (f64, u32) {
+
+    /*  The maximum allowed error. This is double precision epsilon.          */
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    let mut approximate_root: f64 = guess;
+    let mut steps: u32 = 0;
+
+    while steps < max_iterations {
+        let error: f64 = (x - approximate_root * approximate_root) / x;
+
+        if error.abs() <= EPSILON {
+            break;
+        }
+
+        approximate_root = 0.5 * (approximate_root + x / approximate_root);
+        steps = steps + 1;
+    }
+
+    return (approximate_root, steps);
+}
+/*  End of heron_steps.                                                       */
+
+/*  Computes a starting guess for sqrt(x), x > 0, by halving the exponent of  *
+ *  x in its IEEE 754 representation. A positive double is stored as the bits *
+ *  of its biased exponent E, followed by 52 bits of mantissa m, meaning x =  *
+ *  2^(E - 1023) (1 + m). Read as an integer the bit pattern is roughly 2^52  *
+ *  (E + m), a piecewise linear approximation of 2^52 (log2(x) + 1023).       *
+ *  Halving log2(x) halves the square root's logarithm, so halving the        *
+ *  integer and adding back half the bias, 1023 2^51, gives the bits of a     *
+ *  number close to sqrt(x):                                                  *
+ *                                                                            *
+ *        bits(guess) = bits(x) / 2 + 0x1FF8000000000000                      *
+ *                                                                            *
+ *  The guess is within about 6% of sqrt(x), well inside a factor of two, for *
+ *  every normal x. This is the same trick as the famous fast inverse square  *
+ *  root, without the minus sign.                                             */
+fn sqrt_initial_guess(x: f64) -> f64 {
+    return f64::from_bits((x.to_bits() >> 1) + 0x1FF8000000000000);
+}
+/*  End of sqrt_initial_guess.                                                */
+
+/*  Checks if a is sqrt(x) to double precision. Rounding can leave Heron's    *
+ *  method stuck one unit in the last place away from the correctly rounded   *
+ *  root, where the residual test in heron_steps never quite passes, so the   *
+ *  comparison is made with sqrt directly.                                    */
+fn converged(x: f64, a: f64) -> bool {
+    const EPSILON: f64 = 2.220446049250313E-16;
+    let root: f64 = x.sqrt();
+    return (a - root).abs() <= EPSILON * root;
+}
+
+/*  Main routine used for comparing the two starting points.                  */
+fn main() {
+
+    /*  The iteration cap used by herons_method.                              */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+
+    let mut worst_ratio: f64 = 1.0;
+    let mut all_bit_hack: bool = true;
+    let mut all_default: bool = true;
+
+    /*  x = 10^k for k = -300, -290, ..., 300.                                */
+    for k in -30 .. 31 {
+        let x: f64 = 10.0f64.powi(10 * k);
+        let guess: f64 = sqrt_initial_guess(x);
+        let ratio: f64 = guess / x.sqrt();
+        worst_ratio = worst_ratio.max(ratio.max(1.0 / ratio));
+
+        let (a, _): (f64, u32) =
+            heron_steps(x, guess, MAXIMUM_NUMBER_OF_ITERATIONS);
+        let (b, _): (f64, u32) =
+            heron_steps(x, x, MAXIMUM_NUMBER_OF_ITERATIONS);
+
+        all_bit_hack &= converged(x, a);
+        all_default &= converged(x, b);
+    }
+
+    println!("worst guess / sqrt(x) ratio: {:.4}", worst_ratio);
+    println!("bit hack seed converges in 16 steps up to 1e300: {}",
+             all_bit_hack);
+    println!("x seed converges in 16 steps up to 1e300: {}", all_default);
+
+    let x: f64 = 1.0E300;
+    let (_, steps): (f64, u32) = heron_steps(x, sqrt_initial_guess(x), 64);
+    let (_, default_steps): (f64, u32) = heron_steps(x, x, 2000);
+    println!("x = 1e300: {} steps with the bit hack, {} seeded with x",
+             steps, default_steps);
+}
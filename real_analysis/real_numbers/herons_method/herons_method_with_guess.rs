@@ -0,0 +1,98 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Heron's method with a caller-chosen iteration limit or starting guess.*
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Runs Heron's method for sqrt(x) from the given guess, for at most         *
+ *  max_iterations steps, stopping early once (x - a^2) / x is within         *
+ *  epsilon, the same test as herons_method. Returns the approximation and    *
+ *  the number of steps taken.                                                */
+fn heron_steps(x: f64, guess: f64, max_iterations: u32) -> (f64, u32) {
+
+    /*  The maximum allowed error. This is double precision epsilon.          */
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    let mut approximate_root: f64 = guess;
+    let mut steps: u32 = 0;
+
+    while steps < max_iterations {
+        let error: f64 = (x - approximate_root * approximate_root) / x;
+
+        if error.abs() <= EPSILON {
+            break;
+        }
+
+        approximate_root = 0.5 * (approximate_root + x / approximate_root);
+        steps = steps + 1;
+    }
+
+    return (approximate_root, steps);
+}
+/*  End of heron_steps.                                                       */
+
+/*  Computes sqrt(x) starting from the guess x, like herons_method, but with  *
+ *  the caller choosing the maximum number of iterations. Large inputs need   *
+ *  more than the default 16: far from the root each step roughly halves the  *
+ *  guess, so starting at x = 1E12 it takes about 20 steps just to get near   *
+ *  sqrt(x) = 1E6, before quadratic convergence begins.                       */
+fn herons_method_iterations(x: f64, iterations: u32) -> f64 {
+    return heron_steps(x, x, iterations).0;
+}
+/*  End of herons_method_iterations.                                          */
+
+/*  Computes sqrt(x) starting from a caller-supplied guess, with the usual    *
+ *  limit of 16 iterations. Quadratic convergence doubles the number of       *
+ *  correct digits each step, so a guess already within a factor of two of    *
+ *  sqrt(x) reaches full precision in about five or six steps, regardless of  *
+ *  how large x is. Any positive guess converges.                             */
+fn herons_method_from_guess(x: f64, guess: f64) -> f64 {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 16;
+    return heron_steps(x, guess, MAXIMUM_NUMBER_OF_ITERATIONS).0;
+}
+/*  End of herons_method_from_guess.                                          */
+
+/*  Main routine used for comparing the starting points.                      */
+fn main() {
+    let x: f64 = 1.0E12;
+
+    /*  Seeded with x itself, count the steps to full precision.              */
+    let (_, default_steps): (f64, u32) = heron_steps(x, x, 64);
+
+    /*  A rough guess within 20% of the root, sqrt(1E12) = 1E6.               */
+    let guess: f64 = 1.2E6;
+    let (_, guess_steps): (f64, u32) = heron_steps(x, guess, 64);
+
+    println!("x = {:E}: seeded with x, {} steps; seeded with {:E}, {} steps",
+             x, default_steps, guess, guess_steps);
+
+    /*  With the default budget of 16 steps, seeding with x falls short.      */
+    let short: f64 = herons_method_iterations(x, 16);
+    let enough: f64 = herons_method_iterations(x, default_steps);
+    let seeded: f64 = herons_method_from_guess(x, guess);
+
+    println!("seeded with x, 16 steps:   {}", short);
+    println!("seeded with x, {} steps:   {}", default_steps, enough);
+    println!("seeded with guess:         {}", seeded);
+    println!("guess used fewer steps: {}", guess_steps < default_steps);
+}
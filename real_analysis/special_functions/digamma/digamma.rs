@@ -0,0 +1,94 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The digamma function, the logarithmic derivative of the gamma         *
+ *      function.                                                             *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes psi(x) = Gamma'(x) / Gamma(x). Three steps are used.             *
+ *                                                                            *
+ *  For x <= 0 the reflection formula psi(1 - x) - psi(x) = pi cot(pi x)      *
+ *  moves the problem to 1 - x >= 1. The poles of Gamma at 0, -1, -2, ... are *
+ *  poles of psi as well, NaN is returned there.                              *
+ *                                                                            *
+ *  The recurrence psi(x + 1) = psi(x) + 1 / x shifts x up until x >= 10,     *
+ *  subtracting the 1 / x terms along the way.                                *
+ *                                                                            *
+ *  For large x the asymptotic expansion, with Bernoulli number coefficients, *
+ *  is:                                                                       *
+ *                                                                            *
+ *                          1       1        1        1        1              *
+ *        psi(x) ~ ln(x) - -- - ----- + ------ - ------ + ------ - ...        *
+ *                         2x   12x^2   120x^4   252x^6   240x^8              *
+ *                                                                            *
+ *  The series is summed through the 1 / 132x^10 term. At x >= 10 the first   *
+ *  omitted term, 691 / 32760x^12, is at most 2.1E-14, well below 1E-12, and  *
+ *  the remaining error is a few units of rounding.                           */
+fn digamma(x: f64) -> f64 {
+    if x <= 0.0 {
+        if x == x.floor() {
+            return f64::NAN;
+        }
+
+        let pi: f64 = std::f64::consts::PI;
+        return digamma(1.0 - x) - pi / (pi * x).tan();
+    }
+
+    let mut t: f64 = x;
+    let mut shift: f64 = 0.0;
+
+    while t < 10.0 {
+        shift = shift - 1.0 / t;
+        t = t + 1.0;
+    }
+
+    let r: f64 = 1.0 / (t * t);
+    let series: f64 = r * (1.0 / 12.0 - r * (1.0 / 120.0 - r * (1.0 / 252.0
+                    - r * (1.0 / 240.0 - r / 132.0))));
+
+    return shift + t.ln() - 0.5 / t - series;
+}
+/*  End of digamma.                                                           */
+
+/*  Main routine used for testing the digamma function.                       */
+fn main() {
+
+    /*  The Euler-Mascheroni constant, psi(1) = -gamma.                       */
+    const EULER_GAMMA: f64 = 0.5772156649015329;
+    println!("psi(1) + gamma = {:.3E}", digamma(1.0) + EULER_GAMMA);
+
+    /*  psi(1/2) = -gamma - 2 ln(2).                                          */
+    let half: f64 = -EULER_GAMMA - 2.0 * 2.0f64.ln();
+    println!("psi(1/2) error = {:.3E}", (digamma(0.5) - half).abs());
+
+    /*  The recurrence must hold everywhere, including negative x.            */
+    let mut max_error: f64 = 0.0;
+
+    for &x in [0.1, 0.75, 1.0, 3.3, 9.5, 10.5, 42.0, -0.5, -2.7].iter() {
+        let error: f64 = (digamma(x + 1.0) - digamma(x) - 1.0 / x).abs();
+        max_error = max_error.max(error);
+    }
+
+    println!("max error in psi(x + 1) - psi(x) = 1/x: {:.3E}", max_error);
+    println!("psi(0) = {}, psi(-3) = {}", digamma(0.0), digamma(-3.0));
+}
@@ -0,0 +1,180 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Complete elliptic integrals of the first and second kind via the AGM. *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes the arithmetic-geometric mean of a and b. Replace the pair by    *
+ *  its arithmetic and geometric means:                                       *
+ *                                                                            *
+ *        a_n+1 = (a_n + b_n) / 2,    b_n+1 = sqrt(a_n b_n)                   *
+ *                                                                            *
+ *  By the AM-GM inequality b_n <= b_n+1 <= a_n+1 <= a_n, and the gap shrinks *
+ *  quadratically, (a_n+1 - b_n+1) ~ (a_n - b_n)^2 / 8a, so the number of     *
+ *  correct digits doubles each step. Iterate until the two means agree to    *
+ *  within double precision epsilon. The geometric mean is not real for       *
+ *  negative inputs, NaN is returned.                                         */
+fn agm(a: f64, b: f64) -> f64 {
+
+    /*  Quadratic convergence makes this limit generous.                      */
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    if a < 0.0 || b < 0.0 {
+        return (a - a) / (a - a);
+    }
+
+    /*  If either input is zero, every geometric mean is zero and the         *
+     *  arithmetic means halve forever towards zero. Return the limit         *
+     *  directly.                                                             */
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+
+    let mut arithmetic: f64 = a;
+    let mut geometric: f64 = b;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        if (arithmetic - geometric).abs() <= EPSILON * arithmetic {
+            break;
+        }
+
+        let next: f64 = 0.5 * (arithmetic + geometric);
+
+        /*  sqrt(a) sqrt(b) rather than sqrt(a b), so that the product can    *
+         *  not overflow or underflow for very large or very small inputs.    */
+        geometric = arithmetic.sqrt() * geometric.sqrt();
+        arithmetic = next;
+    }
+
+    return arithmetic;
+}
+/*  End of agm.                                                               */
+
+/*  Convention: the argument m is the parameter, m = k^2, where k is the      *
+ *  modulus. This matches Abramowitz and Stegun and most software             *
+ *  (scipy.special.ellipk, for example), while some texts write K(k) in terms *
+ *  of the modulus instead. With this convention:                             *
+ *                                                                            *
+ *                    pi/2                                                    *
+ *                     -                                                      *
+ *                    | |           dt                                        *
+ *        K(m)  =     |     -------------------                               *
+ *                  | |     sqrt(1 - m sin^2 t)                               *
+ *                   -                                                        *
+ *                   0                                                        *
+ *                                                                            *
+ *  Gauss showed K(m) = pi / (2 agm(1, sqrt(1 - m))). K is defined for m < 1  *
+ *  and diverges logarithmically as m -> 1, infinity is returned at m = 1 and *
+ *  NaN for m > 1.                                                            */
+fn elliptic_k(m: f64) -> f64 {
+    if m > 1.0 {
+        return (m - m) / (m - m);
+    }
+
+    if m == 1.0 {
+        return f64::INFINITY;
+    }
+
+    return std::f64::consts::PI / (2.0 * agm(1.0, (1.0 - m).sqrt()));
+}
+/*  End of elliptic_k.                                                        */
+
+/*  Computes the complete elliptic integral of the second kind, with the same *
+ *  parameter convention:                                                     *
+ *                                                                            *
+ *                    pi/2                                                    *
+ *                     -                                                      *
+ *                    | |                                                     *
+ *        E(m)  =     |     sqrt(1 - m sin^2 t) dt                            *
+ *                  | |                                                       *
+ *                   -                                                        *
+ *                   0                                                        *
+ *                                                                            *
+ *  Run the AGM on a_0 = 1, b_0 = sqrt(1 - m), and also track c_0 = sqrt(m),  *
+ *  c_n+1 = (a_n - b_n) / 2. Then:                                            *
+ *                                                                            *
+ *                            ---                                             *
+ *        E(m) = K(m) ( 1  -  \    2^(n-1) c_n^2 )                            *
+ *                            /                                               *
+ *                            ---                                             *
+ *                           n >= 0                                           *
+ *                                                                            *
+ *  E(1) = 1 is finite, and NaN is returned for m > 1.                        */
+fn elliptic_e(m: f64) -> f64 {
+    const MAXIMUM_NUMBER_OF_ITERATIONS: u32 = 64;
+    const EPSILON: f64 = 2.220446049250313E-16;
+
+    if m > 1.0 {
+        return (m - m) / (m - m);
+    }
+
+    if m == 1.0 {
+        return 1.0;
+    }
+
+    let mut a: f64 = 1.0;
+    let mut b: f64 = (1.0 - m).sqrt();
+    let mut sum: f64 = 0.5 * m;
+    let mut power: f64 = 0.5;
+
+    for _ in 0 .. MAXIMUM_NUMBER_OF_ITERATIONS {
+        if (a - b).abs() <= EPSILON * a {
+            break;
+        }
+
+        let c: f64 = 0.5 * (a - b);
+        let next: f64 = 0.5 * (a + b);
+        b = a.sqrt() * b.sqrt();
+        a = next;
+        power = 2.0 * power;
+        sum = sum + power * c * c;
+    }
+
+    return std::f64::consts::PI / (2.0 * a) * (1.0 - sum);
+}
+/*  End of elliptic_e.                                                        */
+
+/*  Main routine used for testing against tabulated values.                   */
+fn main() {
+    let half_pi: f64 = 0.5 * std::f64::consts::PI;
+
+    /*  Values of (m, K(m), E(m)) from standard tables.                       */
+    let table: [(f64, f64, f64); 4] = [
+        (0.0, half_pi, half_pi),
+        (0.5, 1.8540746773013719, 1.3506438810476755),
+        (0.9, 2.5780921133481733, 1.1047747327040733),
+        (-1.0, 1.3110287771460600, 1.9100988945138560)
+    ];
+
+    for &(m, k, e) in table.iter() {
+        println!("m = {:>4}: K error = {:.3E}, E error = {:.3E}",
+                 m, (elliptic_k(m) - k).abs(), (elliptic_e(m) - e).abs());
+    }
+
+    /*  K grows like ln(4 / sqrt(1 - m)) as m -> 1, while E tends to 1.       */
+    for &m in [0.99, 0.9999, 0.999999, 1.0].iter() {
+        println!("m = {}: K = {:.6}, E = {:.6}",
+                 m, elliptic_k(m), elliptic_e(m));
+    }
+}
@@ -0,0 +1,78 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The unnormalized sinc function, sin(x) / x, evaluated safely near     *
+ *      zero.                                                                 *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes sinc(x) = sin(x) / x, with sinc(0) = 1 by continuity. Naively    *
+ *  sin(0) / 0 is 0 / 0, which is NaN. For small |x| the Taylor series is     *
+ *  used instead:                                                             *
+ *                                                                            *
+ *                      x^2     x^4                                           *
+ *        sinc(x) = 1 - ---  +  ---  - ...                                    *
+ *                       6      120                                           *
+ *                                                                            *
+ *  Below 1E-4 the next term, x^6 / 5040, is under 1E-27 and the two terms    *
+ *  kept are exact to double precision, so there is no need to call sin or    *
+ *  divide.                                                                   */
+fn sinc(x: f64) -> f64 {
+    const THRESHOLD: f64 = 1.0E-4;
+
+    if x == 0.0 {
+        return 1.0;
+    }
+
+    if x.abs() < THRESHOLD {
+        let x_squared: f64 = x * x;
+        return 1.0 - x_squared / 6.0 * (1.0 - x_squared / 20.0);
+    }
+
+    return x.sin() / x;
+}
+/*  End of sinc.                                                              */
+
+/*  Main routine used for testing sinc.                                       */
+fn main() {
+    println!("sinc(0) = {}, exactly one: {}", sinc(0.0), sinc(0.0) == 1.0);
+
+    /*  Approaching zero from both sides, and across the threshold, the       *
+     *  values must increase steadily to 1.                                   */
+    let mut previous: f64 = sinc(1.0);
+    let mut monotone: bool = true;
+    let mut x: f64 = 1.0;
+
+    while x > 1.0E-12 {
+        x = 0.5 * x;
+        let value: f64 = sinc(x);
+        monotone &= value >= previous && value <= 1.0 && sinc(-x) == value;
+        previous = value;
+    }
+
+    println!("sinc(x) -> 1 monotonically as x -> 0: {}", monotone);
+    println!("sinc(1E-12) = {}", sinc(1.0E-12));
+
+    /*  pi is not exactly representable, so sinc(pi) is only zero to within   *
+     *  the rounding of pi, about 1E-16 / pi.                                 */
+    println!("sinc(pi) = {:.3E}", sinc(std::f64::consts::PI));
+}
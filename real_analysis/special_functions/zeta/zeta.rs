@@ -0,0 +1,111 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is part of mitx_mathematics_programming_examples.               *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is free software: you can           *
+ *  redistribute it and/or modify it under the terms of the GNU General       *
+ *  Public License as published by the Free Software Foundation, either       *
+ *  version 3 of the License, or (at your option) any later version.          *
+ *                                                                            *
+ *  mitx_mathematics_programming_examples is distributed in the hope that     *
+ *  it will be useful but WITHOUT ANY WARRANTY; without even the implied      *
+ *  warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.          *
+ *  See the GNU General Public License for more details.                      *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with mitx_mathematics_programming_examples. If not, see             *
+ *  <https://www.gnu.org/licenses/>.                                          *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      The Riemann zeta function for real s > 1, via Euler-Maclaurin         *
+ *      summation.                                                            *
+ ******************************************************************************
+ *  Author: Ryan Maguire                                                      *
+ *  Date:   2026/10/15                                                        *
+ ******************************************************************************/
+
+/*  Computes zeta(s) = 1 + 1/2^s + 1/3^s + ... for real s > 1. Summing the    *
+ *  series directly is hopeless near s = 1: the tail after N terms is about   *
+ *  N^(1 - s) / (s - 1), so zeta(2) needs 10^8 terms for eight digits, and    *
+ *  zeta(1.1) would need far more terms than any computer can add. Instead,   *
+ *  the first N - 1 terms are added directly and the tail is replaced by the  *
+ *  Euler-Maclaurin formula:                                                  *
+ *                                                                            *
+ *        ---            N^(1-s)    N^-s    ---   B_2j                        *
+ *        \     k^-s  =  -------  +  ---- +  \    ----- (s)_2j-1 N^(1-s-2j)   *
+ *        /               s - 1       2     /    (2j)!                        *
+ *        ---                               ---                               *
+ *       k >= N                            j >= 1                             *
+ *                                                                            *
+ *  where B_2j are the Bernoulli numbers and (s)_m = s(s+1)...(s+m-1) is the  *
+ *  rising factorial. With N = 10 and six correction terms the truncation     *
+ *  error is smaller than the rounding error for the s used here. At s = 1    *
+ *  the series is the harmonic series, which diverges, and infinity is        *
+ *  returned. For s < 1 the series diverges as well. zeta does extend to      *
+ *  these s by analytic continuation, but that is not this series, so NaN is  *
+ *  returned.                                                                 */
+fn zeta(s: f64) -> f64 {
+    const N: u32 = 10;
+
+    /*  B_2j / (2j)! for j = 1, ..., 6.                                       */
+    const COEFFICIENTS: [f64; 6] = [
+        1.0 / 12.0,
+        -1.0 / 720.0,
+        1.0 / 30240.0,
+        -1.0 / 1209600.0,
+        1.0 / 47900160.0,
+        -691.0 / 1307674368000.0
+    ];
+
+    if s == 1.0 {
+        return f64::INFINITY;
+    }
+
+    if s.is_nan() || s <= 1.0 {
+        return f64::NAN;
+    }
+
+    let mut sum: f64 = 0.0;
+
+    /*  Add the head smallest first.                                          */
+    for k in (1 .. N).rev() {
+        sum = sum + (k as f64).powf(-s);
+    }
+
+    let n: f64 = N as f64;
+    let n_power: f64 = n.powf(-s);
+    sum = sum + n * n_power / (s - 1.0) + 0.5 * n_power;
+
+    /*  term holds (s)_2j-1 N^(1-s-2j), updated by two factors of the rising  *
+     *  product and 1 / N^2 each step.                                        */
+    let mut term: f64 = s * n_power / n;
+
+    for j in 0 .. COEFFICIENTS.len() {
+        sum = sum + COEFFICIENTS[j] * term;
+        let next: f64 = s + (2 * j + 1) as f64;
+        term = term * next * (next + 1.0) / (n * n);
+    }
+
+    return sum;
+}
+/*  End of zeta.                                                              */
+
+/*  Main routine used for testing zeta.                                       */
+fn main() {
+    let pi: f64 = std::f64::consts::PI;
+    let pi_squared: f64 = pi * pi;
+
+    /*  Euler's solution to the Basel problem, and its cousin for s = 4.      */
+    let e2: f64 = (zeta(2.0) - pi_squared / 6.0).abs();
+    let e4: f64 = (zeta(4.0) - pi_squared * pi_squared / 90.0).abs();
+
+    println!("zeta(2) = {:.16}, error = {:.3E}", zeta(2.0), e2);
+    println!("zeta(4) = {:.16}, error = {:.3E}", zeta(4.0), e4);
+    println!("within 1e-8: {}", e2 < 1.0E-8 && e4 < 1.0E-8);
+
+    /*  Apery's constant, zeta(3), and a value close to the pole.             */
+    println!("zeta(3) = {:.16} (1.2020569031595943)", zeta(3.0));
+    println!("zeta(1.01) = {:.12} (100.577943338497)", zeta(1.01));
+    println!("zeta(1) = {}, zeta(0.5) = {}", zeta(1.0), zeta(0.5));
+}